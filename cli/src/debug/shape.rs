@@ -1,6 +1,7 @@
 use boa_engine::{
     Context, JsArgs, JsNativeError, JsObject, JsResult, JsValue, NativeFunction, js_string,
-    object::ObjectInitializer,
+    object::{ObjectInitializer, builtins::JsArray},
+    property::{Attribute, PropertyKey},
 };
 
 fn get_object(args: &[JsValue], position: usize) -> JsResult<JsObject> {
@@ -60,10 +61,83 @@ fn same(_: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
     Ok(JsValue::new(lhs_shape_ptr == rhs_shape_ptr))
 }
 
+/// Returns the object's own property keys in shape order, with their attributes.
+///
+/// Array index keys come first in ascending order, followed by the string and symbol keys stored
+/// in the shape, in insertion order.
+fn properties(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let object = get_object(args, 0)?;
+
+    let properties = {
+        let object = object.borrow();
+        let property_map = object.properties();
+
+        let mut indices = property_map.index_property_keys().collect::<Vec<_>>();
+        indices.sort_unstable();
+
+        indices
+            .into_iter()
+            .map(PropertyKey::from)
+            .chain(object.shape().keys())
+            .filter_map(|key| {
+                let descriptor = property_map.get(&key)?;
+                Some((key, descriptor))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut elements = Vec::with_capacity(properties.len());
+    for (key, descriptor) in properties {
+        let key = match key {
+            PropertyKey::Index(index) => JsValue::new(index.get()),
+            PropertyKey::String(string) => string.into(),
+            PropertyKey::Symbol(symbol) => symbol.into(),
+        };
+        let writable = descriptor
+            .writable()
+            .map_or_else(JsValue::undefined, JsValue::new);
+
+        let element = ObjectInitializer::new(context)
+            .property(js_string!("key"), key, Attribute::all())
+            .property(js_string!("writable"), writable, Attribute::all())
+            .property(
+                js_string!("enumerable"),
+                descriptor.expect_enumerable(),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("configurable"),
+                descriptor.expect_configurable(),
+                Attribute::all(),
+            )
+            .build();
+        elements.push(element.into());
+    }
+
+    Ok(JsArray::from_iter(elements, context).into())
+}
+
+/// Transitions the object to a unique shape and returns it.
+fn force_unique(_: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+    let object = get_object(args, 0)?;
+    object.borrow_mut().force_unique_shape();
+    Ok(object.into())
+}
+
 pub(super) fn create_object(context: &mut Context) -> JsObject {
     ObjectInitializer::new(context)
         .function(NativeFunction::from_fn_ptr(id), js_string!("id"), 1)
         .function(NativeFunction::from_fn_ptr(r#type), js_string!("type"), 1)
         .function(NativeFunction::from_fn_ptr(same), js_string!("same"), 2)
+        .function(
+            NativeFunction::from_fn_ptr(properties),
+            js_string!("properties"),
+            1,
+        )
+        .function(
+            NativeFunction::from_fn_ptr(force_unique),
+            js_string!("forceUnique"),
+            1,
+        )
         .build()
 }
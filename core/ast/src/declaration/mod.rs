@@ -18,7 +18,8 @@ use super::function::{
     AsyncFunctionDeclaration, AsyncGeneratorDeclaration, FunctionDeclaration, GeneratorDeclaration,
 };
 use crate::{
-    function::ClassDeclaration,
+    expression::Identifier,
+    function::{ClassDeclaration, FormalParameterList, FunctionBody},
     visitor::{VisitWith, Visitor, VisitorMut},
 };
 use boa_interner::{Interner, Sym, ToIndentedString, ToInternedString};
@@ -58,6 +59,28 @@ pub enum Declaration {
     Lexical(LexicalDeclaration),
 }
 
+impl Declaration {
+    /// Returns a reference to the declaration if it is a [hoistable declaration][spec], which is
+    /// any kind of function declaration.
+    ///
+    /// This allows accessing the name, parameters and body of all kinds of function
+    /// declarations uniformly. Class and lexical declarations are not hoistable, so `None` is
+    /// returned for them.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-HoistableDeclaration
+    #[inline]
+    #[must_use]
+    pub const fn as_hoistable(&self) -> Option<HoistableRef<'_>> {
+        match self {
+            Self::FunctionDeclaration(f) => Some(HoistableRef::Function(f)),
+            Self::GeneratorDeclaration(g) => Some(HoistableRef::Generator(g)),
+            Self::AsyncFunctionDeclaration(af) => Some(HoistableRef::AsyncFunction(af)),
+            Self::AsyncGeneratorDeclaration(ag) => Some(HoistableRef::AsyncGenerator(ag)),
+            Self::ClassDeclaration(_) | Self::Lexical(_) => None,
+        }
+    }
+}
+
 impl ToIndentedString for Declaration {
     fn to_indented_string(&self, interner: &Interner, indentation: usize) -> String {
         match self {
@@ -107,6 +130,74 @@ impl VisitWith for Declaration {
     }
 }
 
+/// A reference to a hoistable declaration, as returned by [`Declaration::as_hoistable`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HoistableRef<'a> {
+    /// See [`FunctionDeclaration`]
+    Function(&'a FunctionDeclaration),
+
+    /// See [`GeneratorDeclaration`]
+    Generator(&'a GeneratorDeclaration),
+
+    /// See [`AsyncFunctionDeclaration`]
+    AsyncFunction(&'a AsyncFunctionDeclaration),
+
+    /// See [`AsyncGeneratorDeclaration`]
+    AsyncGenerator(&'a AsyncGeneratorDeclaration),
+}
+
+impl<'a> HoistableRef<'a> {
+    /// Gets the name of the declared function.
+    #[inline]
+    #[must_use]
+    pub const fn name(self) -> Identifier {
+        match self {
+            Self::Function(f) => f.name(),
+            Self::Generator(g) => g.name(),
+            Self::AsyncFunction(af) => af.name(),
+            Self::AsyncGenerator(ag) => ag.name(),
+        }
+    }
+
+    /// Gets the list of parameters of the declared function.
+    #[inline]
+    #[must_use]
+    pub const fn parameters(self) -> &'a FormalParameterList {
+        match self {
+            Self::Function(f) => f.parameters(),
+            Self::Generator(g) => g.parameters(),
+            Self::AsyncFunction(af) => af.parameters(),
+            Self::AsyncGenerator(ag) => ag.parameters(),
+        }
+    }
+
+    /// Gets the body of the declared function.
+    #[inline]
+    #[must_use]
+    pub const fn body(self) -> &'a FunctionBody {
+        match self {
+            Self::Function(f) => f.body(),
+            Self::Generator(g) => g.body(),
+            Self::AsyncFunction(af) => af.body(),
+            Self::AsyncGenerator(ag) => ag.body(),
+        }
+    }
+
+    /// Returns `true` if the declared function is an async function or an async generator.
+    #[inline]
+    #[must_use]
+    pub const fn is_async(self) -> bool {
+        matches!(self, Self::AsyncFunction(_) | Self::AsyncGenerator(_))
+    }
+
+    /// Returns `true` if the declared function is a generator or an async generator.
+    #[inline]
+    #[must_use]
+    pub const fn is_generator(self) -> bool {
+        matches!(self, Self::Generator(_) | Self::AsyncGenerator(_))
+    }
+}
+
 /// Module specifier.
 ///
 /// This is equivalent to the [`ModuleSpecifier`] production.
@@ -156,3 +247,60 @@ impl VisitWith for ModuleSpecifier {
         visitor.visit_sym_mut(&mut self.module)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Declaration, LexicalDeclaration, Variable, VariableList};
+    use crate::{
+        LinearSpan, Span, StatementList,
+        expression::Identifier,
+        function::{
+            AsyncFunctionDeclaration, AsyncGeneratorDeclaration, FormalParameterList, FunctionBody,
+            FunctionDeclaration, GeneratorDeclaration,
+        },
+    };
+    use boa_interner::{Interner, ToInternedString};
+
+    #[test]
+    fn as_hoistable() {
+        let mut interner = Interner::default();
+        let mut ident = |name: &str| Identifier::new(interner.get_or_intern(name), Span::EMPTY);
+        let (f, g, af, ag) = (ident("f"), ident("g"), ident("af"), ident("ag"));
+        let params = FormalParameterList::default;
+        let body = || FunctionBody::new(StatementList::default(), Span::EMPTY);
+        let linear_span = LinearSpan::default;
+
+        let declarations: [Declaration; 4] = [
+            FunctionDeclaration::new(f, params(), body(), linear_span()).into(),
+            GeneratorDeclaration::new(g, params(), body(), linear_span()).into(),
+            AsyncFunctionDeclaration::new(af, params(), body(), linear_span()).into(),
+            AsyncGeneratorDeclaration::new(ag, params(), body(), linear_span()).into(),
+        ];
+        let hoistables = declarations
+            .iter()
+            .map(|declaration| declaration.as_hoistable().expect("must be hoistable"))
+            .map(|hoistable| {
+                (
+                    hoistable.name().to_interned_string(&interner),
+                    hoistable.is_async(),
+                    hoistable.is_generator(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            hoistables,
+            [
+                ("f".to_owned(), false, false),
+                ("g".to_owned(), false, true),
+                ("af".to_owned(), true, false),
+                ("ag".to_owned(), true, true),
+            ]
+        );
+
+        let lexical = Declaration::Lexical(LexicalDeclaration::Let(
+            VariableList::try_from(vec![Variable::from_identifier(f, None)])
+                .expect("non-empty variable list"),
+        ));
+        assert!(lexical.as_hoistable().is_none());
+    }
+}
@@ -23,14 +23,21 @@ use boa_interner::{Interner, ToIndentedString, ToInternedString};
 #[derive(Clone, Debug, PartialEq)]
 pub struct Await {
     target: Box<Expression>,
+    keyword_span: Span,
     span: Span,
 }
 
 impl Await {
     /// Create a new [`Await`] node.
+    ///
+    /// `keyword_span` is the span of the `await` keyword, while `span` covers the whole expression.
     #[must_use]
-    pub const fn new(target: Box<Expression>, span: Span) -> Self {
-        Self { target, span }
+    pub const fn new(target: Box<Expression>, keyword_span: Span, span: Span) -> Self {
+        Self {
+            target,
+            keyword_span,
+            span,
+        }
     }
 
     /// Return the target expression that should be awaited.
@@ -39,6 +46,32 @@ impl Await {
     pub const fn target(&self) -> &Expression {
         &self.target
     }
+
+    /// Return the span of the `await` keyword, excluding the awaited expression.
+    #[inline]
+    #[must_use]
+    pub const fn keyword_span(&self) -> Span {
+        self.keyword_span
+    }
+
+    /// Returns `true` if the awaited expression is a function call, like `await f()`.
+    ///
+    /// Parentheses around the target are ignored. Calls to `super` and `import`, as well as
+    /// optional calls, are not considered function calls.
+    #[inline]
+    #[must_use]
+    pub const fn target_is_call(&self) -> bool {
+        matches!(self.target.flatten(), Expression::Call(_))
+    }
+
+    /// Returns `true` if the awaited expression is an identifier, like `await promise`.
+    ///
+    /// Parentheses around the target are ignored.
+    #[inline]
+    #[must_use]
+    pub const fn target_is_identifier(&self) -> bool {
+        matches!(self.target.flatten(), Expression::Identifier(_))
+    }
 }
 
 impl Spanned for Await {
@@ -51,10 +84,30 @@ impl Spanned for Await {
 impl ToInternedString for Await {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
-        format!("await {}", self.target.to_indented_string(interner, 0))
+        let target = self.target.to_indented_string(interner, 0);
+        if needs_parentheses(&self.target) {
+            format!("await ({target})")
+        } else {
+            format!("await {target}")
+        }
     }
 }
 
+/// Checks if the operand of `await` has a lower precedence than unary expressions, meaning it
+/// has to be wrapped in parentheses to be printed as the target of the `await`.
+const fn needs_parentheses(target: &Expression) -> bool {
+    matches!(
+        target,
+        Expression::Assign(_)
+            | Expression::Binary(_)
+            | Expression::BinaryInPrivate(_)
+            | Expression::Conditional(_)
+            | Expression::Yield(_)
+            | Expression::ArrowFunction(_)
+            | Expression::AsyncArrowFunction(_)
+    )
+}
+
 impl From<Await> for Expression {
     #[inline]
     fn from(awaitexpr: Await) -> Self {
@@ -77,3 +130,81 @@ impl VisitWith for Await {
         visitor.visit_expression_mut(&mut self.target)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Await;
+    use crate::{
+        Expression, Span,
+        expression::{
+            Call, Identifier, Parenthesized,
+            access::{PropertyAccess, SimplePropertyAccess},
+            operator::{
+                Binary, Conditional,
+                binary::{ArithmeticOp, BinaryOp},
+            },
+        },
+    };
+    use boa_interner::{Interner, ToInternedString};
+
+    const SPAN: Span = Span::synthetic();
+
+    fn print_await(target: Expression, interner: &Interner) -> String {
+        Await::new(Box::new(target), SPAN, SPAN).to_interned_string(interner)
+    }
+
+    /// Checks that only operands with a lower precedence than unary expressions are
+    /// parenthesized.
+    #[test]
+    fn await_operand_parentheses() {
+        let mut interner = Interner::default();
+        let a: Expression = Identifier::new(interner.get_or_intern("a"), SPAN).into();
+        let b: Expression = Identifier::new(interner.get_or_intern("b"), SPAN).into();
+        let c: Expression = Identifier::new(interner.get_or_intern("c"), SPAN).into();
+        let f: Expression = Identifier::new(interner.get_or_intern("f"), SPAN).into();
+
+        assert_eq!(print_await(a.clone(), &interner), "await a");
+        assert_eq!(
+            print_await(Call::new(f, Box::new([a.clone()]), SPAN).into(), &interner),
+            "await f(a)"
+        );
+        assert_eq!(
+            print_await(Conditional::new(a.clone(), b.clone(), c).into(), &interner),
+            "await (a ? b : c)"
+        );
+        assert_eq!(
+            print_await(
+                Binary::new(BinaryOp::Arithmetic(ArithmeticOp::Add), a, b).into(),
+                &interner
+            ),
+            "await (a + b)"
+        );
+    }
+
+    #[test]
+    fn target_is_call() {
+        let mut interner = Interner::default();
+        let f: Expression = Identifier::new(interner.get_or_intern("f"), SPAN).into();
+        let call: Expression = Call::new(f.clone(), Box::default(), SPAN).into();
+        let await_of = |target: Expression| Await::new(Box::new(target), SPAN, SPAN);
+
+        assert!(await_of(call.clone()).target_is_call());
+        assert!(await_of(Parenthesized::new(call, SPAN).into()).target_is_call());
+        assert!(!await_of(f).target_is_call());
+    }
+
+    #[test]
+    fn target_is_identifier() {
+        let mut interner = Interner::default();
+        let promise: Expression = Identifier::new(interner.get_or_intern("promise"), SPAN).into();
+        let then = Identifier::new(interner.get_or_intern("then"), SPAN);
+        let access: Expression =
+            PropertyAccess::Simple(SimplePropertyAccess::new(promise.clone(), then)).into();
+        let await_of = |target: Expression| Await::new(Box::new(target), SPAN, SPAN);
+
+        assert!(await_of(promise.clone()).target_is_identifier());
+        assert!(await_of(Parenthesized::new(promise.clone(), SPAN).into()).target_is_identifier());
+        assert!(!await_of(access).target_is_identifier());
+        assert!(!await_of(Call::new(promise, Box::default(), SPAN).into()).target_is_identifier());
+    }
+}
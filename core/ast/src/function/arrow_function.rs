@@ -78,6 +78,13 @@ impl ArrowFunction {
         &self.parameters
     }
 
+    /// Gets the number of arguments expected by the arrow function.
+    #[inline]
+    #[must_use]
+    pub const fn expected_arg_count(&self) -> usize {
+        self.parameters.length() as usize
+    }
+
     /// Gets the body of the arrow function.
     #[inline]
     #[must_use]
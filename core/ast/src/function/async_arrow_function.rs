@@ -79,6 +79,13 @@ impl AsyncArrowFunction {
         &self.parameters
     }
 
+    /// Gets the number of arguments expected by the async arrow function.
+    #[inline]
+    #[must_use]
+    pub const fn expected_arg_count(&self) -> usize {
+        self.parameters.length() as usize
+    }
+
     /// Gets the body of the async arrow function.
     #[inline]
     #[must_use]
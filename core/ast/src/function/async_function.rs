@@ -1,6 +1,6 @@
 //! Async Function Expression.
 
-use super::{FormalParameterList, FunctionBody};
+use super::{FormalParameterList, FunctionBody, FunctionSignature};
 use crate::{
     Declaration, LinearSpan, LinearSpanIgnoreEq, Span, Spanned, block_to_string,
     expression::{Expression, Identifier},
@@ -70,6 +70,13 @@ impl AsyncFunctionDeclaration {
         &self.parameters
     }
 
+    /// Gets the number of arguments expected by the async function declaration.
+    #[inline]
+    #[must_use]
+    pub const fn expected_arg_count(&self) -> usize {
+        self.parameters.length() as usize
+    }
+
     /// Gets the body of the async function declaration.
     #[inline]
     #[must_use]
@@ -206,6 +213,13 @@ impl AsyncFunctionExpression {
         &self.parameters
     }
 
+    /// Gets the number of arguments expected by the async function expression.
+    #[inline]
+    #[must_use]
+    pub const fn expected_arg_count(&self) -> usize {
+        self.parameters.length() as usize
+    }
+
     /// Gets the body of the async function expression.
     #[inline]
     #[must_use]
@@ -247,6 +261,15 @@ impl AsyncFunctionExpression {
     pub const fn contains_direct_eval(&self) -> bool {
         self.contains_direct_eval
     }
+
+    /// Gets the signature of the async function expression, which is its name, parameters and kind.
+    ///
+    /// Unlike cloning the whole async function expression, this doesn't clone its body.
+    #[inline]
+    #[must_use]
+    pub fn signature_only(&self) -> FunctionSignature {
+        FunctionSignature::new(self.name, self.parameters.clone(), true, false)
+    }
 }
 
 impl Spanned for AsyncFunctionExpression {
@@ -1,6 +1,6 @@
 //! Async Generator Expression
 
-use super::{FormalParameterList, FunctionBody};
+use super::{FormalParameterList, FunctionBody, FunctionSignature};
 use crate::operations::{ContainsSymbol, contains};
 use crate::scope::{FunctionScopes, Scope};
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
@@ -71,6 +71,13 @@ impl AsyncGeneratorDeclaration {
         &self.parameters
     }
 
+    /// Gets the number of arguments expected by the async generator declaration.
+    #[inline]
+    #[must_use]
+    pub const fn expected_arg_count(&self) -> usize {
+        self.parameters.length() as usize
+    }
+
     /// Gets the body of the async generator declaration.
     #[inline]
     #[must_use]
@@ -207,6 +214,13 @@ impl AsyncGeneratorExpression {
         &self.parameters
     }
 
+    /// Gets the number of arguments expected by the async generator expression.
+    #[inline]
+    #[must_use]
+    pub const fn expected_arg_count(&self) -> usize {
+        self.parameters.length() as usize
+    }
+
     /// Gets the body of the async generator expression.
     #[inline]
     #[must_use]
@@ -248,6 +262,15 @@ impl AsyncGeneratorExpression {
     pub const fn contains_direct_eval(&self) -> bool {
         self.contains_direct_eval
     }
+
+    /// Gets the signature of the async generator expression, which is its name, parameters and kind.
+    ///
+    /// Unlike cloning the whole async generator expression, this doesn't clone its body.
+    #[inline]
+    #[must_use]
+    pub fn signature_only(&self) -> FunctionSignature {
+        FunctionSignature::new(self.name, self.parameters.clone(), true, true)
+    }
 }
 
 impl Spanned for AsyncGeneratorExpression {
@@ -1,4 +1,4 @@
-use super::{FormalParameterList, FunctionBody};
+use super::{FormalParameterList, FunctionBody, FunctionSignature};
 use crate::{
     Declaration, LinearSpan, LinearSpanIgnoreEq, Span, Spanned, block_to_string,
     expression::{Expression, Identifier},
@@ -68,6 +68,13 @@ impl GeneratorDeclaration {
         &self.parameters
     }
 
+    /// Gets the number of arguments expected by the generator declaration.
+    #[inline]
+    #[must_use]
+    pub const fn expected_arg_count(&self) -> usize {
+        self.parameters.length() as usize
+    }
+
     /// Gets the body of the generator declaration.
     #[inline]
     #[must_use]
@@ -204,6 +211,13 @@ impl GeneratorExpression {
         &self.parameters
     }
 
+    /// Gets the number of arguments expected by the generator expression.
+    #[inline]
+    #[must_use]
+    pub const fn expected_arg_count(&self) -> usize {
+        self.parameters.length() as usize
+    }
+
     /// Gets the body of the generator expression.
     #[inline]
     #[must_use]
@@ -245,6 +259,15 @@ impl GeneratorExpression {
     pub const fn contains_direct_eval(&self) -> bool {
         self.contains_direct_eval
     }
+
+    /// Gets the signature of the generator expression, which is its name, parameters and kind.
+    ///
+    /// Unlike cloning the whole generator expression, this doesn't clone its body.
+    #[inline]
+    #[must_use]
+    pub fn signature_only(&self) -> FunctionSignature {
+        FunctionSignature::new(self.name, self.parameters.clone(), false, true)
+    }
 }
 
 impl Spanned for GeneratorExpression {
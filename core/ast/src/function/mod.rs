@@ -31,6 +31,7 @@ mod class;
 mod generator;
 mod ordinary_function;
 mod parameters;
+mod signature;
 
 use std::ops::ControlFlow;
 
@@ -46,6 +47,7 @@ pub use class::{
 pub use generator::{GeneratorDeclaration, GeneratorExpression};
 pub use ordinary_function::{FunctionDeclaration, FunctionExpression};
 pub use parameters::{FormalParameter, FormalParameterList, FormalParameterListFlags};
+pub use signature::FunctionSignature;
 
 use crate::{
     LinearPosition, Span, Spanned, StatementList, StatementListItem,
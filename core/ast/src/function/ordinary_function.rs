@@ -1,4 +1,4 @@
-use super::{FormalParameterList, FunctionBody};
+use super::{FormalParameterList, FunctionBody, FunctionSignature};
 use crate::{
     Declaration, LinearSpan, LinearSpanIgnoreEq, Span, Spanned, block_to_string,
     expression::{Expression, Identifier},
@@ -62,6 +62,16 @@ impl FunctionDeclaration {
         self.name
     }
 
+    /// Replaces the name of the function declaration.
+    ///
+    /// The parameters, body, scopes and direct `eval` flag of the function are kept as is, since
+    /// the name is bound in the enclosing scope. This means the scope analysis of the code
+    /// containing the declaration must be run again for the new name to be resolved.
+    #[inline]
+    pub fn rename(&mut self, name: Identifier) {
+        self.name = name;
+    }
+
     /// Gets the list of parameters of the function declaration.
     #[inline]
     #[must_use]
@@ -69,6 +79,13 @@ impl FunctionDeclaration {
         &self.parameters
     }
 
+    /// Gets the number of arguments expected by the function declaration.
+    #[inline]
+    #[must_use]
+    pub const fn expected_arg_count(&self) -> usize {
+        self.parameters.length() as usize
+    }
+
     /// Gets the body of the function declaration.
     #[inline]
     #[must_use]
@@ -228,6 +245,13 @@ impl FunctionExpression {
         &self.parameters
     }
 
+    /// Gets the number of arguments expected by the function expression.
+    #[inline]
+    #[must_use]
+    pub const fn expected_arg_count(&self) -> usize {
+        self.parameters.length() as usize
+    }
+
     /// Gets the body of the function expression.
     #[inline]
     #[must_use]
@@ -270,6 +294,15 @@ impl FunctionExpression {
         self.contains_direct_eval
     }
 
+    /// Gets the signature of the function expression, which is its name, parameters and kind.
+    ///
+    /// Unlike cloning the whole function expression, this doesn't clone its body.
+    #[inline]
+    #[must_use]
+    pub fn signature_only(&self) -> FunctionSignature {
+        FunctionSignature::new(self.name, self.parameters.clone(), false, false)
+    }
+
     /// Analyze the scope of the function expression.
     pub fn analyze_scope(&mut self, strict: bool, scope: &Scope, interner: &Interner) -> bool {
         if !collect_bindings(self, strict, false, scope, interner) {
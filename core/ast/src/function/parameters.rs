@@ -79,6 +79,9 @@ impl FormalParameterList {
 
     /// Returns the length of the parameter list.
     /// Note that this is not equal to the length of the parameters slice.
+    ///
+    /// This is the number of parameters before the first one with an initializer or the rest
+    /// parameter, used as the `length` property of the function.
     #[must_use]
     pub const fn length(&self) -> u32 {
         self.length
@@ -0,0 +1,63 @@
+use super::FormalParameterList;
+use crate::expression::Identifier;
+
+/// The signature of a function expression: its name, parameters and kind, without its body.
+///
+/// This is useful for tools that only need to know how a function can be called, like a symbol
+/// index, since getting a signature doesn't clone the body of the function.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FunctionSignature {
+    name: Option<Identifier>,
+    parameters: FormalParameterList,
+    is_async: bool,
+    is_generator: bool,
+}
+
+impl FunctionSignature {
+    /// Creates a new function signature.
+    #[inline]
+    #[must_use]
+    pub const fn new(
+        name: Option<Identifier>,
+        parameters: FormalParameterList,
+        is_async: bool,
+        is_generator: bool,
+    ) -> Self {
+        Self {
+            name,
+            parameters,
+            is_async,
+            is_generator,
+        }
+    }
+
+    /// Gets the name of the function.
+    #[inline]
+    #[must_use]
+    pub const fn name(&self) -> Option<Identifier> {
+        self.name
+    }
+
+    /// Gets the list of parameters of the function.
+    #[inline]
+    #[must_use]
+    pub const fn parameters(&self) -> &FormalParameterList {
+        &self.parameters
+    }
+
+    /// Returns `true` if the function is an async function.
+    #[inline]
+    #[must_use]
+    pub const fn is_async(&self) -> bool {
+        self.is_async
+    }
+
+    /// Returns `true` if the function is a generator function.
+    #[inline]
+    #[must_use]
+    pub const fn is_generator(&self) -> bool {
+        self.is_generator
+    }
+}
@@ -2,23 +2,29 @@
 //!
 //! [spec]: https://tc39.es/ecma262/#sec-syntax-directed-operations
 
-use core::ops::ControlFlow;
-use std::convert::Infallible;
+use core::{cell::Cell, hash::BuildHasher, ops::ControlFlow};
+use std::{collections::HashMap, convert::Infallible};
 
 use boa_interner::{Interner, Sym};
+use boa_string::JsString;
 use rustc_hash::FxHashSet;
 
 use crate::{
-    Declaration, Expression, LinearSpan, ModuleItem, Script, Statement, StatementList,
-    StatementListItem,
+    Declaration, Expression, LinearPosition, LinearSpan, Module, ModuleItem, Position, Script,
+    Span, Spanned, Statement, StatementList, StatementListItem,
     declaration::{
         Binding, ExportDeclaration, ImportDeclaration, LexicalDeclaration, VarDeclaration, Variable,
     },
     expression::{
-        Await, Call, Identifier, NewTarget, OptionalOperationKind, SuperCall, This, Yield,
-        access::{PrivatePropertyAccess, SuperPropertyAccess},
-        literal::PropertyDefinition,
-        operator::BinaryInPrivate,
+        Await, Call, Identifier, NewTarget, OptionalOperationKind, Parenthesized, SuperCall, This,
+        Yield,
+        access::{PrivatePropertyAccess, SimplePropertyAccess, SuperPropertyAccess},
+        literal::{LiteralKind, ObjectMethodDefinition, PropertyDefinition},
+        operator::{
+            BinaryInPrivate, Conditional, Unary,
+            binary::{BinaryOp, RelationalOp},
+            unary::UnaryOp,
+        },
     },
     function::{
         ArrowFunction, AsyncArrowFunction, AsyncFunctionDeclaration, AsyncFunctionExpression,
@@ -26,11 +32,15 @@ use crate::{
         ClassElementName, ClassExpression, FormalParameterList, FunctionBody, FunctionDeclaration,
         FunctionExpression, GeneratorDeclaration, GeneratorExpression, PrivateFieldDefinition,
     },
+    pattern::{ArrayPattern, ArrayPatternElement, ObjectPattern, ObjectPatternElement},
+    property::{MethodDefinitionKind, PropertyName},
+    scope::Scope,
+    scope_analyzer::{collect_bindings, collect_unresolved_identifiers},
     statement::{
         LabelledItem, With,
         iteration::{ForLoopInitializer, IterableLoopInitializer},
     },
-    visitor::{NodeRef, VisitWith, Visitor},
+    visitor::{NodeRef, NodeRefMut, VisitWith, Visitor, VisitorMut, walk_with_callbacks},
 };
 
 #[cfg(test)]
@@ -2543,3 +2553,1221 @@ impl<'ast> Visitor<'ast> for ReturnsValueVisitor {
         ControlFlow::Continue(())
     }
 }
+
+/// Returns `true` if the given `while` or `do-while` loop never terminates by itself.
+///
+/// This is the case when the condition of the loop is a constant truthy literal, and the body
+/// contains no `break` statement targeting the loop. Breaks targeting nested loops, `switch`
+/// statements or labelled statements inside the body don't count. Since the labels of the loop
+/// are not part of the node, any labelled `break` whose target is not declared inside the body is
+/// assumed to exit the loop.
+///
+/// Other kinds of nodes always return `false`.
+#[must_use]
+pub fn is_statically_infinite<'a, N>(node: &'a N) -> bool
+where
+    &'a N: Into<NodeRef<'a>>,
+{
+    let (condition, body) = match node.into() {
+        NodeRef::WhileLoop(node) => (node.condition(), node.body()),
+        NodeRef::DoWhileLoop(node) => (node.cond(), node.body()),
+        _ => return false,
+    };
+
+    eval_const_bool(condition) == Some(true)
+        && BreaksOutOfLoopVisitor::default()
+            .visit_statement(body)
+            .is_continue()
+}
+
+/// Evaluates `ToBoolean` for constant literal expressions, returning `None` for any other expression.
+fn eval_const_bool(expr: &Expression) -> Option<bool> {
+    match expr {
+        Expression::Literal(lit) => Some(match lit.kind() {
+            LiteralKind::String(s) => *s != Sym::EMPTY_STRING,
+            LiteralKind::Num(n) => *n != 0.0 && !n.is_nan(),
+            LiteralKind::Int(n) => *n != 0,
+            LiteralKind::BigInt(n) => n.sign() != num_bigint::Sign::NoSign,
+            LiteralKind::Bool(b) => *b,
+            LiteralKind::Null | LiteralKind::Undefined => false,
+        }),
+        Expression::Parenthesized(p) => eval_const_bool(p.expression()),
+        _ => None,
+    }
+}
+
+/// The [`Visitor`] used for [`is_statically_infinite`] and [`if_chain_to_switch`].
+///
+/// Breaks when it finds a `break` statement exiting the visited loop body.
+#[derive(Debug, Default)]
+struct BreaksOutOfLoopVisitor {
+    /// Number of breakable statements within the loop body enclosing the current node.
+    breakable_depth: u32,
+    /// Labels declared within the loop body enclosing the current node.
+    labels: Vec<Sym>,
+}
+
+impl BreaksOutOfLoopVisitor {
+    fn visit_breakable<N: VisitWith>(&mut self, node: &N) -> ControlFlow<()> {
+        self.breakable_depth += 1;
+        let result = node.visit_with(self);
+        self.breakable_depth -= 1;
+        result
+    }
+}
+
+impl<'ast> Visitor<'ast> for BreaksOutOfLoopVisitor {
+    type BreakTy = ();
+
+    // Expressions and declarations cannot contain a `break` targeting an enclosing statement.
+    fn visit_expression(&mut self, _: &'ast Expression) -> ControlFlow<Self::BreakTy> {
+        ControlFlow::Continue(())
+    }
+
+    fn visit_declaration(&mut self, _: &'ast Declaration) -> ControlFlow<Self::BreakTy> {
+        ControlFlow::Continue(())
+    }
+
+    fn visit_break(
+        &mut self,
+        node: &'ast crate::statement::iteration::Break,
+    ) -> ControlFlow<Self::BreakTy> {
+        let exits = match node.label() {
+            None => self.breakable_depth == 0,
+            Some(label) => !self.labels.contains(&label),
+        };
+        if exits {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+
+    fn visit_labelled(
+        &mut self,
+        node: &'ast crate::statement::Labelled,
+    ) -> ControlFlow<Self::BreakTy> {
+        self.labels.push(node.label());
+        let result = node.visit_with(self);
+        self.labels.pop();
+        result
+    }
+
+    fn visit_while_loop(
+        &mut self,
+        node: &'ast crate::statement::WhileLoop,
+    ) -> ControlFlow<Self::BreakTy> {
+        self.visit_breakable(node)
+    }
+
+    fn visit_do_while_loop(
+        &mut self,
+        node: &'ast crate::statement::DoWhileLoop,
+    ) -> ControlFlow<Self::BreakTy> {
+        self.visit_breakable(node)
+    }
+
+    fn visit_for_loop(
+        &mut self,
+        node: &'ast crate::statement::ForLoop,
+    ) -> ControlFlow<Self::BreakTy> {
+        self.visit_breakable(node)
+    }
+
+    fn visit_for_in_loop(
+        &mut self,
+        node: &'ast crate::statement::ForInLoop,
+    ) -> ControlFlow<Self::BreakTy> {
+        self.visit_breakable(node)
+    }
+
+    fn visit_for_of_loop(
+        &mut self,
+        node: &'ast crate::statement::ForOfLoop,
+    ) -> ControlFlow<Self::BreakTy> {
+        self.visit_breakable(node)
+    }
+
+    fn visit_switch(&mut self, node: &'ast crate::statement::Switch) -> ControlFlow<Self::BreakTy> {
+        self.visit_breakable(node)
+    }
+}
+
+/// Returns `true` if every control-flow path through the function body ends in a `return` or
+/// `throw` statement, meaning the function never implicitly returns `undefined`.
+///
+/// The analysis is conservative: it may return `false` for bodies that always return, but never
+/// returns `true` for a body that can complete normally.
+///
+/// - `if` statements return on all paths only if they have an `else` branch, and both branches do.
+/// - `switch` statements need a `default` clause, no `break` out of the switch, and a last clause
+///   that returns on all paths, since every other clause eventually falls through to it.
+/// - `try` statements return on all paths if their `finally` block does, or if both the `try`
+///   block and the `catch` block (if any) do.
+/// - Loops are only considered to return on all paths if they never terminate by themselves,
+///   as in [`is_statically_infinite`]. Labelled statements are never considered to.
+#[must_use]
+pub fn all_paths_return(body: &FunctionBody) -> bool {
+    statements_always_return(body.statements())
+}
+
+/// Returns `true` if the statement list always ends in a `return` or `throw` statement.
+fn statements_always_return(statements: &[StatementListItem]) -> bool {
+    statements.iter().any(|item| match item {
+        StatementListItem::Statement(statement) => statement_always_returns(statement),
+        StatementListItem::Declaration(_) => false,
+    })
+}
+
+/// Returns `true` if the statement always ends in a `return` or `throw` statement.
+fn statement_always_returns(statement: &Statement) -> bool {
+    match statement {
+        Statement::Return(_) | Statement::Throw(_) => true,
+        Statement::Block(block) => statements_always_return(block.statement_list().statements()),
+        Statement::If(node) => node.else_node().is_some_and(|else_node| {
+            statement_always_returns(node.body()) && statement_always_returns(else_node)
+        }),
+        Statement::Switch(switch) => {
+            switch.default().is_some()
+                && switch.cases().iter().all(|case| {
+                    BreaksOutOfLoopVisitor::default()
+                        .visit_statement_list(case.body())
+                        .is_continue()
+                })
+                && switch
+                    .cases()
+                    .last()
+                    .is_some_and(|case| statements_always_return(case.body().statements()))
+        }
+        Statement::Try(node) => {
+            let block_returns = |block: &crate::statement::Block| {
+                statements_always_return(block.statement_list().statements())
+            };
+            node.finally()
+                .is_some_and(|finally| block_returns(finally.block()))
+                || (block_returns(node.block())
+                    && node
+                        .catch()
+                        .is_none_or(|catch| block_returns(catch.block())))
+        }
+        Statement::With(node) => statement_always_returns(node.statement()),
+        Statement::WhileLoop(node) => is_statically_infinite(node),
+        Statement::DoWhileLoop(node) => is_statically_infinite(node),
+        _ => false,
+    }
+}
+
+/// Returns the spans of the calls in tail position of a function body, in source order.
+///
+/// A call is in tail position if its result is directly returned by the function, as in
+/// `return f()`. This also covers the body of concise arrow functions like `x => f(x)`, which is
+/// parsed as a `return` statement. The analysis follows [`HasCallInTailPosition`][spec]:
+///
+/// - `return` statements are found through blocks, `if` statements, `switch` cases, labelled
+///   statements and `while`, `do-while` and `for` loops. `for-in` and `for-of` loops are skipped,
+///   since returning from them must close the iterator after the call.
+/// - Inside `try` statements, the `finally` block is in tail position if there is one, and the
+///   `catch` block otherwise. The `try` block itself never is.
+/// - In the returned expression, both branches of a conditional, the right operand of `&&`, `||`,
+///   `??` and `,`, and parenthesized expressions are in tail position. Both plain calls and
+///   tagged templates count as calls.
+///
+/// Nested functions are not traversed, since their tail calls are their own. Note that calls in
+/// tail position only get proper tail calls in strict mode code.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-hascallintailposition
+#[must_use]
+pub fn tail_call_positions(body: &FunctionBody) -> Vec<Span> {
+    let mut spans = Vec::new();
+    statements_tail_calls(body.statements(), &mut spans);
+    spans
+}
+
+/// Collects the tail calls of the `return` statements in a statement list.
+fn statements_tail_calls(statements: &[StatementListItem], spans: &mut Vec<Span>) {
+    for item in statements {
+        if let StatementListItem::Statement(statement) = item {
+            statement_tail_calls(statement, spans);
+        }
+    }
+}
+
+/// Collects the tail calls of the `return` statements in a statement.
+fn statement_tail_calls(statement: &Statement, spans: &mut Vec<Span>) {
+    match statement {
+        Statement::Return(node) => {
+            if let Some(target) = node.target() {
+                expression_tail_calls(target, spans);
+            }
+        }
+        Statement::Block(block) => {
+            statements_tail_calls(block.statement_list().statements(), spans);
+        }
+        Statement::If(node) => {
+            statement_tail_calls(node.body(), spans);
+            if let Some(else_node) = node.else_node() {
+                statement_tail_calls(else_node, spans);
+            }
+        }
+        Statement::Switch(switch) => {
+            for case in switch.cases() {
+                statements_tail_calls(case.body().statements(), spans);
+            }
+        }
+        Statement::Labelled(labelled) => {
+            if let LabelledItem::Statement(statement) = labelled.item() {
+                statement_tail_calls(statement, spans);
+            }
+        }
+        Statement::Try(node) => {
+            let block = match (node.catch(), node.finally()) {
+                (_, Some(finally)) => finally.block(),
+                (Some(catch), None) => catch.block(),
+                (None, None) => return,
+            };
+            statements_tail_calls(block.statement_list().statements(), spans);
+        }
+        Statement::WhileLoop(node) => statement_tail_calls(node.body(), spans),
+        Statement::DoWhileLoop(node) => statement_tail_calls(node.body(), spans),
+        Statement::ForLoop(node) => statement_tail_calls(node.body(), spans),
+        _ => {}
+    }
+}
+
+/// Collects the calls in tail position of a returned expression.
+fn expression_tail_calls(expr: &Expression, spans: &mut Vec<Span>) {
+    match expr {
+        Expression::Call(call) => spans.push(call.span()),
+        Expression::TaggedTemplate(template) => spans.push(template.span()),
+        Expression::Parenthesized(parenthesized) => {
+            expression_tail_calls(parenthesized.expression(), spans);
+        }
+        Expression::Conditional(conditional) => {
+            expression_tail_calls(conditional.if_true(), spans);
+            expression_tail_calls(conditional.if_false(), spans);
+        }
+        Expression::Binary(binary)
+            if matches!(binary.op(), BinaryOp::Logical(_) | BinaryOp::Comma) =>
+        {
+            expression_tail_calls(binary.rhs(), spans);
+        }
+        _ => {}
+    }
+}
+
+/// A range of statement indices within a [`FunctionBody`], as returned by [`split_at_awaits`].
+pub type StatementRange = core::ops::Range<usize>;
+
+/// Partitions the statements of a function body into segments separated by `await` boundaries.
+///
+/// Each segment ends right after a statement that contains an `await` expression, which makes
+/// the segments suitable for coroutine-style transforms that resume after each suspension point.
+/// The last segment holds the statements after the last `await`, and may be empty. A body with `n`
+/// awaiting statements always produces `n + 1` segments.
+///
+/// Nested functions, arrow functions and class methods are treated as opaque, so `await`s inside
+/// them are not boundaries.
+#[must_use]
+pub fn split_at_awaits(body: &FunctionBody) -> Vec<StatementRange> {
+    let mut segments = Vec::new();
+    let mut start = 0;
+    for (index, statement) in body.statements().iter().enumerate() {
+        if contains(statement, ContainsSymbol::AwaitExpression) {
+            segments.push(start..index + 1);
+            start = index + 1;
+        }
+    }
+    segments.push(start..body.statements().len());
+    segments
+}
+
+/// Builds the `awaited.then(continuation)` call expression.
+///
+/// This is a building block for transforms that lower `await` expressions to promise chains,
+/// where the code after an `await` becomes the continuation function. `awaited` is wrapped in
+/// parentheses if needed, so that the result prints back to valid code: `(a || b).then(fn)`.
+/// The call spans from the start of `awaited` to the end of `continuation`.
+#[must_use]
+pub fn build_then_continuation(
+    awaited: Expression,
+    continuation: FunctionExpression,
+) -> Expression {
+    let awaited_span = awaited.span();
+    let target = match awaited {
+        Expression::Identifier(_)
+        | Expression::This(_)
+        | Expression::PropertyAccess(_)
+        | Expression::Call(_)
+        | Expression::Parenthesized(_) => awaited,
+        _ => Parenthesized::new(awaited, awaited_span).into(),
+    };
+    let then = Identifier::new(Sym::THEN, Span::new(awaited_span.end(), awaited_span.end()));
+    let function = SimplePropertyAccess::new(target, then);
+    let span = awaited_span.encompass(continuation.span());
+    Call::new(
+        Expression::PropertyAccess(function.into()),
+        Box::new([continuation.into()]),
+        span,
+    )
+    .into()
+}
+
+/// Rewrites `while (c) body` into the equivalent `for (; c; ) body` loop.
+///
+/// The condition and body, whether a block or a single statement, are kept as-is, so the loop
+/// prints back to code with the same semantics. This is useful for backends that only handle a
+/// single loop form.
+#[must_use]
+pub fn while_to_for(loop_node: &crate::statement::WhileLoop) -> crate::statement::ForLoop {
+    crate::statement::ForLoop::new(
+        None,
+        Some(loop_node.condition().clone()),
+        None,
+        loop_node.body().clone(),
+    )
+}
+
+/// Flattens a chain of right-nested conditional expressions into its branches, in order.
+///
+/// `a ? b : c ? d : e` becomes `[(Some(a), b), (Some(c), d), (None, e)]`: each branch is paired
+/// with the condition that selects it, and the last branch, which has no condition, is taken when
+/// all the previous conditions are falsy. Conditionals nested in conditions or in the `true`
+/// branches are not flattened, and neither are parenthesized ones.
+#[must_use]
+pub fn flatten_conditional(expr: &Conditional) -> Vec<(Option<&Expression>, &Expression)> {
+    let mut branches = Vec::new();
+    let mut current = expr;
+    loop {
+        branches.push((Some(current.condition()), current.if_true()));
+        match current.if_false() {
+            Expression::Conditional(next) => current = next,
+            last => {
+                branches.push((None, last));
+                return branches;
+            }
+        }
+    }
+}
+
+/// Simplifies an `if` statement whose `then` branch is empty.
+///
+/// `if (c) {} else s` is rewritten to `if (!c) s`, negating the condition with the unary `!`
+/// operator, and wrapping it in parentheses if needed: `if (!(a || b)) s`. If the `else` branch
+/// is empty or missing too, the statement is dropped entirely when the condition is free of side
+/// effects, and replaced by the expression statement `c;` otherwise. Any other statement is
+/// returned unchanged.
+///
+/// An empty branch is either an empty statement (`;`) or an empty block (`{}`). Only literals,
+/// `this` and function expressions are considered side-effect free, since even reading an
+/// identifier can throw.
+#[must_use]
+pub fn simplify_if(if_node: crate::statement::If) -> StatementListItem {
+    fn is_empty(statement: &Statement) -> bool {
+        match statement {
+            Statement::Empty => true,
+            Statement::Block(block) => block.statement_list().statements().is_empty(),
+            _ => false,
+        }
+    }
+
+    fn is_side_effect_free(expr: &Expression) -> bool {
+        match expr {
+            Expression::This(_)
+            | Expression::Literal(_)
+            | Expression::FunctionExpression(_)
+            | Expression::ArrowFunction(_)
+            | Expression::AsyncArrowFunction(_)
+            | Expression::GeneratorExpression(_)
+            | Expression::AsyncFunctionExpression(_)
+            | Expression::AsyncGeneratorExpression(_) => true,
+            Expression::Parenthesized(parenthesized) => {
+                is_side_effect_free(parenthesized.expression())
+            }
+            _ => false,
+        }
+    }
+
+    if !is_empty(if_node.body()) {
+        return Statement::If(if_node).into();
+    }
+
+    let condition = if_node.cond().clone();
+    match if_node.else_node() {
+        Some(else_node) if !is_empty(else_node) => {
+            let condition_span = condition.span();
+            let operand = match condition {
+                Expression::Identifier(_)
+                | Expression::This(_)
+                | Expression::Literal(_)
+                | Expression::PropertyAccess(_)
+                | Expression::Call(_)
+                | Expression::Parenthesized(_) => condition,
+                _ => Parenthesized::new(condition, condition_span).into(),
+            };
+            let negated = Unary::new(UnaryOp::Not, operand, condition_span);
+            Statement::If(crate::statement::If::new(
+                negated.into(),
+                else_node.clone(),
+                None,
+                if_node.span(),
+            ))
+            .into()
+        }
+        _ if is_side_effect_free(&condition) => Statement::Empty.into(),
+        _ => Statement::Expression(condition).into(),
+    }
+}
+
+/// Rewrites an `if`/`else if` chain comparing the same identifier against literal values into a
+/// `switch` statement.
+///
+/// Every condition of the chain must be a strict equality (`===`) between the identifier and a
+/// literal, in any order, since that is the comparison used by `switch`. Each branch becomes a
+/// `case` clause ending with a `break`, and a final `else` becomes the `default` clause. Blocks
+/// without lexical declarations are unwrapped into the clause; other blocks are kept to preserve
+/// their scope.
+///
+/// Returns `None` if the conditions don't match this shape, or if a branch contains a `break`
+/// that could exit the branch, since it would target the `switch` after the rewrite. The returned
+/// `switch` has no scope information, so the tree has to be analyzed again before compiling it.
+#[must_use]
+pub fn if_chain_to_switch(if_node: &crate::statement::If) -> Option<crate::statement::Switch> {
+    /// Gets the identifier and the literal compared by a `===` condition.
+    fn compared_operands(condition: &Expression) -> Option<(Identifier, &Expression)> {
+        let Expression::Binary(binary) = condition.flatten() else {
+            return None;
+        };
+        if binary.op() != BinaryOp::Relational(RelationalOp::StrictEqual) {
+            return None;
+        }
+        match (binary.lhs().flatten(), binary.rhs().flatten()) {
+            (Expression::Identifier(ident), value @ Expression::Literal(_))
+            | (value @ Expression::Literal(_), Expression::Identifier(ident)) => {
+                Some((*ident, value))
+            }
+            _ => None,
+        }
+    }
+
+    /// Converts a branch of the chain into the body of a `case` clause.
+    fn case_body(branch: &Statement) -> Option<StatementList> {
+        if BreaksOutOfLoopVisitor::default()
+            .visit_statement(branch)
+            .is_break()
+        {
+            return None;
+        }
+
+        let (mut items, linear_pos_end, strict) = match branch {
+            Statement::Block(block) if lexically_scoped_declarations(block).is_empty() => {
+                let list = block.statement_list();
+                (
+                    list.statements().to_vec(),
+                    list.linear_pos_end(),
+                    list.strict(),
+                )
+            }
+            _ => (
+                vec![branch.clone().into()],
+                LinearPosition::default(),
+                false,
+            ),
+        };
+        items.push(Statement::Break(crate::statement::Break::new(None)).into());
+        Some(StatementList::new(items, linear_pos_end, strict))
+    }
+
+    let mut discriminant: Option<Identifier> = None;
+    let mut cases = Vec::new();
+    let mut current = if_node;
+    loop {
+        let (ident, value) = compared_operands(current.cond())?;
+        match discriminant {
+            Some(discriminant) if discriminant.sym() != ident.sym() => return None,
+            Some(_) => {}
+            None => discriminant = Some(ident),
+        }
+        cases.push(crate::statement::Case::new(
+            value.clone(),
+            case_body(current.body())?,
+        ));
+
+        match current.else_node() {
+            Some(Statement::If(next)) => current = next,
+            Some(else_node) => {
+                cases.push(crate::statement::Case::default(case_body(else_node)?));
+                break;
+            }
+            None => break,
+        }
+    }
+
+    Some(crate::statement::Switch::new(
+        discriminant?.into(),
+        cases.into(),
+    ))
+}
+
+/// Returns `true` if the expression is a numeric literal, or a reference to `NaN` or `Infinity`.
+///
+/// Both `NaN` and `Infinity` are identifiers referring to properties of the global object, not
+/// literals, but tools like constant propagation passes usually want to treat them as numbers.
+/// Numeric literals include `BigInt` literals, and `-Infinity` is recognized as well.
+///
+/// This only checks the shape of the expression: a local binding named `NaN` or `Infinity` is
+/// still considered numeric. Use [`is_numeric_like_in_scope`] to rule those out when the scope of
+/// the expression is known.
+#[must_use]
+pub fn is_numeric_like(expr: &Expression) -> bool {
+    match expr {
+        Expression::Literal(literal) => matches!(
+            literal.kind(),
+            LiteralKind::Num(_) | LiteralKind::Int(_) | LiteralKind::BigInt(_)
+        ),
+        Expression::Identifier(ident) => matches!(ident.sym(), Sym::NAN | Sym::INFINITY),
+        Expression::Unary(unary) => {
+            unary.op() == UnaryOp::Minus
+                && matches!(unary.target(), Expression::Identifier(ident) if ident.sym() == Sym::INFINITY)
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if the expression is numeric like in [`is_numeric_like`], and its references
+/// to `NaN` or `Infinity` are not shadowed by a binding of `scope` or of its outer scopes.
+///
+/// `scope` must be the scope the expression is evaluated in, after scope analysis. Bindings of the
+/// global scope are not considered, since `NaN` and `Infinity` cannot be redefined there. Note
+/// that references inside `with` statements may still resolve to a property of the object.
+#[must_use]
+pub fn is_numeric_like_in_scope(expr: &Expression, scope: &Scope) -> bool {
+    let shadowed = |name: &str| {
+        let name = JsString::from(name);
+        let mut current = Some(scope.clone());
+        while let Some(scope) = current {
+            if !scope.is_global() && scope.has_binding(&name) {
+                return true;
+            }
+            current = scope.outer();
+        }
+        false
+    };
+    match expr {
+        Expression::Identifier(ident) if ident.sym() == Sym::NAN => !shadowed("NaN"),
+        Expression::Identifier(_) | Expression::Unary(_) if is_numeric_like(expr) => {
+            !shadowed("Infinity")
+        }
+        _ => is_numeric_like(expr),
+    }
+}
+
+/// An `await` expression found outside of an async context by [`validate_await_positions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MisplacedAwaitError {
+    span: Span,
+}
+
+impl MisplacedAwaitError {
+    /// Gets the span of the misplaced `await` expression.
+    #[must_use]
+    pub const fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Returns an error message for the error.
+    #[must_use]
+    pub const fn message(&self) -> &'static str {
+        "await is only valid in async functions and the top level bodies of modules"
+    }
+}
+
+/// Checks that every `await` expression in `node` is lexically inside an async context.
+///
+/// Async contexts are the bodies of async functions, async arrow functions, async methods and
+/// async generators, and the top level of modules. The parser already rejects `await` outside of
+/// these, so this is mostly useful to validate programmatically constructed trees.
+///
+/// The parameters of a function are never an async context, and neither are class field
+/// initializers or static blocks. A node that is not a [`Module`][crate::Module] is considered
+/// to start in a non-async context.
+///
+/// # Errors
+///
+/// Returns all the misplaced `await` expressions, in source order.
+pub fn validate_await_positions<'a, N>(node: &'a N) -> Result<(), Vec<MisplacedAwaitError>>
+where
+    &'a N: Into<NodeRef<'a>>,
+{
+    /// Visitor used by the function to find `await` expressions outside of async contexts.
+    #[derive(Debug, Default)]
+    struct AwaitPositionsVisitor {
+        in_async: bool,
+        errors: Vec<MisplacedAwaitError>,
+    }
+
+    impl AwaitPositionsVisitor {
+        fn visit_in_context<N>(&mut self, node: &N, in_async: bool)
+        where
+            N: VisitWith,
+        {
+            let outer = std::mem::replace(&mut self.in_async, in_async);
+            let _ = node.visit_with(self);
+            self.in_async = outer;
+        }
+
+        fn visit_function(
+            &mut self,
+            parameters: &FormalParameterList,
+            body: &FunctionBody,
+            is_async: bool,
+        ) -> ControlFlow<Infallible> {
+            self.visit_in_context(parameters, false);
+            self.visit_in_context(body, is_async);
+            ControlFlow::Continue(())
+        }
+    }
+
+    impl<'ast> Visitor<'ast> for AwaitPositionsVisitor {
+        type BreakTy = Infallible;
+
+        fn visit_module(&mut self, node: &'ast Module) -> ControlFlow<Self::BreakTy> {
+            self.visit_in_context(node, true);
+            ControlFlow::Continue(())
+        }
+
+        fn visit_await(&mut self, node: &'ast Await) -> ControlFlow<Self::BreakTy> {
+            if !self.in_async {
+                self.errors.push(MisplacedAwaitError { span: node.span() });
+            }
+            node.visit_with(self)
+        }
+
+        fn visit_function_expression(
+            &mut self,
+            node: &'ast FunctionExpression,
+        ) -> ControlFlow<Self::BreakTy> {
+            self.visit_function(node.parameters(), node.body(), false)
+        }
+
+        fn visit_function_declaration(
+            &mut self,
+            node: &'ast FunctionDeclaration,
+        ) -> ControlFlow<Self::BreakTy> {
+            self.visit_function(node.parameters(), node.body(), false)
+        }
+
+        fn visit_generator_expression(
+            &mut self,
+            node: &'ast GeneratorExpression,
+        ) -> ControlFlow<Self::BreakTy> {
+            self.visit_function(node.parameters(), node.body(), false)
+        }
+
+        fn visit_generator_declaration(
+            &mut self,
+            node: &'ast GeneratorDeclaration,
+        ) -> ControlFlow<Self::BreakTy> {
+            self.visit_function(node.parameters(), node.body(), false)
+        }
+
+        fn visit_async_function_expression(
+            &mut self,
+            node: &'ast AsyncFunctionExpression,
+        ) -> ControlFlow<Self::BreakTy> {
+            self.visit_function(node.parameters(), node.body(), true)
+        }
+
+        fn visit_async_function_declaration(
+            &mut self,
+            node: &'ast AsyncFunctionDeclaration,
+        ) -> ControlFlow<Self::BreakTy> {
+            self.visit_function(node.parameters(), node.body(), true)
+        }
+
+        fn visit_async_generator_expression(
+            &mut self,
+            node: &'ast AsyncGeneratorExpression,
+        ) -> ControlFlow<Self::BreakTy> {
+            self.visit_function(node.parameters(), node.body(), true)
+        }
+
+        fn visit_async_generator_declaration(
+            &mut self,
+            node: &'ast AsyncGeneratorDeclaration,
+        ) -> ControlFlow<Self::BreakTy> {
+            self.visit_function(node.parameters(), node.body(), true)
+        }
+
+        fn visit_arrow_function(
+            &mut self,
+            node: &'ast ArrowFunction,
+        ) -> ControlFlow<Self::BreakTy> {
+            self.visit_function(node.parameters(), node.body(), false)
+        }
+
+        fn visit_async_arrow_function(
+            &mut self,
+            node: &'ast AsyncArrowFunction,
+        ) -> ControlFlow<Self::BreakTy> {
+            self.visit_function(node.parameters(), node.body(), true)
+        }
+
+        fn visit_object_method_definition(
+            &mut self,
+            node: &'ast ObjectMethodDefinition,
+        ) -> ControlFlow<Self::BreakTy> {
+            // Computed property names are evaluated in the enclosing context.
+            node.name().visit_with(self)?;
+            let is_async = matches!(
+                node.kind(),
+                MethodDefinitionKind::Async | MethodDefinitionKind::AsyncGenerator
+            );
+            self.visit_function(node.parameters(), node.body(), is_async)
+        }
+
+        fn visit_class_element(&mut self, node: &'ast ClassElement) -> ControlFlow<Self::BreakTy> {
+            match node {
+                ClassElement::MethodDefinition(m) => {
+                    if let ClassElementName::PropertyName(name) = m.name() {
+                        name.visit_with(self)?;
+                    }
+                    let is_async = matches!(
+                        m.kind(),
+                        MethodDefinitionKind::Async | MethodDefinitionKind::AsyncGenerator
+                    );
+                    self.visit_function(m.parameters(), m.body(), is_async)
+                }
+                ClassElement::FieldDefinition(field)
+                | ClassElement::StaticFieldDefinition(field) => {
+                    field.name().visit_with(self)?;
+                    if let Some(initializer) = field.initializer() {
+                        self.visit_in_context(initializer, false);
+                    }
+                    ControlFlow::Continue(())
+                }
+                ClassElement::PrivateFieldDefinition(field)
+                | ClassElement::PrivateStaticFieldDefinition(field) => {
+                    if let Some(initializer) = field.initializer() {
+                        self.visit_in_context(initializer, false);
+                    }
+                    ControlFlow::Continue(())
+                }
+                ClassElement::StaticBlock(block) => {
+                    self.visit_in_context(block.statements(), false);
+                    ControlFlow::Continue(())
+                }
+            }
+        }
+    }
+
+    let mut visitor = AwaitPositionsVisitor::default();
+    let _ = visitor.visit(node);
+    if visitor.errors.is_empty() {
+        Ok(())
+    } else {
+        Err(visitor.errors)
+    }
+}
+
+/// A rest element found in a position other than the last by [`validate_spread_positions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MisplacedRestError {
+    span: Span,
+    parameter: bool,
+}
+
+impl MisplacedRestError {
+    /// Gets the span of the misplaced rest parameter or element.
+    #[must_use]
+    pub const fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Returns an error message for the error.
+    #[must_use]
+    pub const fn message(&self) -> &'static str {
+        if self.parameter {
+            "rest parameter must be the last formal parameter"
+        } else {
+            "rest element must be the last element of a destructuring pattern"
+        }
+    }
+}
+
+/// Checks that every rest parameter and rest element in `node` is the last one of its list.
+///
+/// Rest parameters must be the last parameter of a formal parameter list, and rest elements must
+/// be the last element of array and object destructuring patterns. Spread elements in calls,
+/// `new` expressions and array or object literals can appear in any position, so they are not
+/// checked. The parser already rejects misplaced rest elements, so this is mostly useful to
+/// validate programmatically constructed trees.
+///
+/// # Errors
+///
+/// Returns all the misplaced rest parameters and elements, in source order.
+pub fn validate_spread_positions<'a, N>(node: &'a N) -> Result<(), Vec<MisplacedRestError>>
+where
+    &'a N: Into<NodeRef<'a>>,
+{
+    /// Visitor used by the function to find rest parameters and elements that are not last.
+    #[derive(Debug, Default)]
+    struct SpreadPositionsVisitor {
+        errors: Vec<MisplacedRestError>,
+    }
+
+    impl<'ast> Visitor<'ast> for SpreadPositionsVisitor {
+        type BreakTy = Infallible;
+
+        fn visit_formal_parameter_list(
+            &mut self,
+            node: &'ast FormalParameterList,
+        ) -> ControlFlow<Self::BreakTy> {
+            let parameters = node.as_ref();
+            for parameter in &parameters[..parameters.len().saturating_sub(1)] {
+                if parameter.is_rest_param() {
+                    let span = match parameter.variable().binding() {
+                        Binding::Identifier(ident) => ident.span(),
+                        Binding::Pattern(pattern) => pattern.span(),
+                    };
+                    self.errors.push(MisplacedRestError {
+                        span,
+                        parameter: true,
+                    });
+                }
+            }
+            node.visit_with(self)
+        }
+
+        fn visit_object_pattern(
+            &mut self,
+            node: &'ast ObjectPattern,
+        ) -> ControlFlow<Self::BreakTy> {
+            let elements = node.bindings();
+            for element in &elements[..elements.len().saturating_sub(1)] {
+                let span = match element {
+                    ObjectPatternElement::RestProperty { ident } => ident.span(),
+                    ObjectPatternElement::AssignmentRestPropertyAccess { access } => access.span(),
+                    _ => continue,
+                };
+                self.errors.push(MisplacedRestError {
+                    span,
+                    parameter: false,
+                });
+            }
+            node.visit_with(self)
+        }
+
+        fn visit_array_pattern(&mut self, node: &'ast ArrayPattern) -> ControlFlow<Self::BreakTy> {
+            let elements = node.bindings();
+            for element in &elements[..elements.len().saturating_sub(1)] {
+                let span = match element {
+                    ArrayPatternElement::SingleNameRest { ident } => ident.span(),
+                    ArrayPatternElement::PropertyAccessRest { access } => access.span(),
+                    ArrayPatternElement::PatternRest { pattern } => pattern.span(),
+                    _ => continue,
+                };
+                self.errors.push(MisplacedRestError {
+                    span,
+                    parameter: false,
+                });
+            }
+            node.visit_with(self)
+        }
+    }
+
+    let mut visitor = SpreadPositionsVisitor::default();
+    let _ = visitor.visit(node);
+    if visitor.errors.is_empty() {
+        Ok(())
+    } else {
+        Err(visitor.errors)
+    }
+}
+
+/// Returns the free variables of a function, i.e. the identifiers referenced in its parameters or
+/// body that are not bound by the parameters or by any declaration inside the function.
+///
+/// These are the variables a closure captures from its enclosing scopes (or reads from the global
+/// object). Nested functions are included in the analysis, so an identifier referenced by an inner
+/// function is free if neither the inner nor the outer function binds it.
+///
+/// Identifiers inside `with` statements cannot be statically resolved, so they are reported as
+/// free unless bound by the function. `body` is expected to be valid, like the ones produced by
+/// the parser; an empty set is returned if the scope analysis fails.
+#[must_use]
+pub fn free_variables(
+    parameters: &FormalParameterList,
+    body: &FunctionBody,
+    interner: &Interner,
+) -> FxHashSet<Sym> {
+    let mut function = FunctionExpression::new(
+        None,
+        parameters.clone(),
+        body.clone(),
+        None,
+        false,
+        Span::EMPTY,
+    );
+    let scope = Scope::new_global();
+    if !collect_bindings(&mut function, body.strict(), false, &scope, interner) {
+        return FxHashSet::default();
+    }
+    collect_unresolved_identifiers(&mut function, scope, interner).unwrap_or_default()
+}
+
+/// Returns the names a script references without declaring them anywhere, which are resolved
+/// against the global object when the script runs (like `console` or `Math`).
+///
+/// This can be used to find out which globals a script needs before deciding what to expose to
+/// it. Declarations at the top level of the script and inside its functions and blocks are all
+/// taken into account, so only names that would be looked up on the global object are reported.
+///
+/// Identifiers inside `with` statements cannot be statically resolved, so they are reported
+/// unless the script declares them. `script` is expected to be valid, like the ones produced by
+/// the parser; an empty set is returned if the scope analysis fails.
+#[must_use]
+pub fn used_globals(script: &Script, interner: &Interner) -> FxHashSet<Sym> {
+    let mut script = script.clone();
+    let strict = script.strict();
+    let scope = Scope::new_global();
+    if !collect_bindings(&mut script, strict, false, &scope, interner) {
+        return FxHashSet::default();
+    }
+    let Some(mut names) = collect_unresolved_identifiers(&mut script, scope, interner) else {
+        return FxHashSet::default();
+    };
+
+    // Top level `var` and function declarations create properties of the global object instead
+    // of bindings, so they have to be removed separately.
+    for name in var_declared_names(&script) {
+        names.remove(&name);
+    }
+    if !strict {
+        for name in annex_b_function_declarations_names(&script) {
+            names.remove(&name);
+        }
+    }
+    names
+}
+
+/// Returns the maximum number of call expressions nested inside each other in a function body.
+///
+/// A call counts as nested in another if it appears anywhere in its callee or arguments, so
+/// `a(b(c(d())))` and `a(1 + b())` have a depth of 4 and 2 respectively, while `a(); b();` has a
+/// depth of 1. Calls inside nested functions are counted like any other nested call. A body
+/// without calls has a depth of 0.
+///
+/// This is a static estimate of how deeply calls are chained, not the runtime call stack depth,
+/// which also depends on what the called functions do.
+#[must_use]
+pub fn max_call_depth(body: &FunctionBody) -> usize {
+    let mut visitor = MaxCallDepthVisitor::default();
+    let _ = visitor.visit_function_body(body);
+    visitor.max
+}
+
+/// The [`Visitor`] used for [`max_call_depth`].
+#[derive(Debug, Default)]
+struct MaxCallDepthVisitor {
+    depth: usize,
+    max: usize,
+}
+
+impl<'ast> Visitor<'ast> for MaxCallDepthVisitor {
+    type BreakTy = Infallible;
+
+    fn visit_call(&mut self, node: &'ast Call) -> ControlFlow<Self::BreakTy> {
+        self.depth += 1;
+        self.max = self.max.max(self.depth);
+        let result = node.visit_with(self);
+        self.depth -= 1;
+        result
+    }
+}
+
+/// Returns `true` if evaluating the expression may mutate state, which is the case if it contains
+/// an assignment, a call, an increment or decrement (`++`/`--`), a `delete`, an `await` or a
+/// `yield`.
+///
+/// This is useful to check if a loop condition like `i++ < 10` updates the state of the loop.
+/// Calls include `new` expressions, `super` calls, dynamic imports, tagged templates and optional
+/// calls (`f?.()`). Function and arrow function expressions are not evaluated when defined, so
+/// their bodies are skipped. Side effects from implicit conversions or getters, like the ones of
+/// `a + b` or `obj.prop`, are not detected.
+#[must_use]
+pub fn condition_has_side_effects(expr: &Expression) -> bool {
+    SideEffectsVisitor.visit_expression(expr).is_break()
+}
+
+/// The [`Visitor`] used for [`condition_has_side_effects`].
+#[derive(Debug)]
+struct SideEffectsVisitor;
+
+impl<'ast> Visitor<'ast> for SideEffectsVisitor {
+    type BreakTy = ();
+
+    fn visit_expression(&mut self, node: &'ast Expression) -> ControlFlow<Self::BreakTy> {
+        match node {
+            Expression::Assign(_)
+            | Expression::Call(_)
+            | Expression::New(_)
+            | Expression::SuperCall(_)
+            | Expression::ImportCall(_)
+            | Expression::TaggedTemplate(_)
+            | Expression::Update(_)
+            | Expression::Await(_)
+            | Expression::Yield(_) => ControlFlow::Break(()),
+            Expression::Unary(unary) if unary.op() == UnaryOp::Delete => ControlFlow::Break(()),
+            Expression::FunctionExpression(_)
+            | Expression::ArrowFunction(_)
+            | Expression::AsyncArrowFunction(_)
+            | Expression::GeneratorExpression(_)
+            | Expression::AsyncFunctionExpression(_)
+            | Expression::AsyncGeneratorExpression(_) => ControlFlow::Continue(()),
+            _ => node.visit_with(self),
+        }
+    }
+
+    fn visit_optional_operation_kind(
+        &mut self,
+        node: &'ast OptionalOperationKind,
+    ) -> ControlFlow<Self::BreakTy> {
+        if let OptionalOperationKind::Call { .. } = node {
+            return ControlFlow::Break(());
+        }
+        node.visit_with(self)
+    }
+}
+
+/// Returns the smallest node of `script` whose span contains `pos`, like the identifier under the
+/// cursor of an editor.
+///
+/// Spans are treated as half-open, like in [`Span::contains_position`], so the position right
+/// after the end of a node is not part of it. Only nodes that have a span are considered; the
+/// deepest of them containing `pos` is returned, and `None` if no node contains it.
+#[must_use]
+pub fn smallest_node_at(script: &Script, pos: Position) -> Option<NodeRef<'_>> {
+    // Both callbacks update the depth, so it can't be borrowed mutably by either of them.
+    let depth = Cell::new(0usize);
+    let mut smallest = None;
+    let _ = walk_with_callbacks::<_, _, _, Infallible>(
+        script,
+        |node| {
+            let current = depth.get() + 1;
+            depth.set(current);
+            if node_span(node).is_some_and(|span| span.contains_position(pos))
+                && smallest.is_none_or(|(smallest_depth, _)| current > smallest_depth)
+            {
+                smallest = Some((current, node));
+            }
+            ControlFlow::Continue(())
+        },
+        |_| {
+            depth.set(depth.get() - 1);
+            ControlFlow::Continue(())
+        },
+    );
+    smallest.map(|(_, node)| node)
+}
+
+/// Returns the span of a node, if it has one.
+fn node_span(node: NodeRef<'_>) -> Option<Span> {
+    let span = match node {
+        NodeRef::FunctionBody(node) => node.span(),
+        NodeRef::FunctionExpression(node) => node.span(),
+        NodeRef::GeneratorExpression(node) => node.span(),
+        NodeRef::AsyncFunctionExpression(node) => node.span(),
+        NodeRef::AsyncGeneratorExpression(node) => node.span(),
+        NodeRef::ClassExpression(node) => node.span(),
+        NodeRef::Expression(node) => node.span(),
+        NodeRef::If(node) => node.span(),
+        NodeRef::This(node) => node.span(),
+        NodeRef::Identifier(node) => node.span(),
+        NodeRef::PrivateName(node) => node.span(),
+        NodeRef::Pattern(node) => node.span(),
+        NodeRef::Literal(node) => node.span(),
+        NodeRef::RegExpLiteral(node) => node.span(),
+        NodeRef::ArrayLiteral(node) => node.span(),
+        NodeRef::ObjectLiteral(node) => node.span(),
+        NodeRef::Spread(node) => node.span(),
+        NodeRef::ArrowFunction(node) => node.span(),
+        NodeRef::AsyncArrowFunction(node) => node.span(),
+        NodeRef::TemplateLiteral(node) => node.span(),
+        NodeRef::PropertyAccess(node) => node.span(),
+        NodeRef::New(node) => node.span(),
+        NodeRef::Call(node) => node.span(),
+        NodeRef::SuperCall(node) => node.span(),
+        NodeRef::ImportCall(node) => node.span(),
+        NodeRef::Optional(node) => node.span(),
+        NodeRef::TaggedTemplate(node) => node.span(),
+        NodeRef::Assign(node) => node.span(),
+        NodeRef::Unary(node) => node.span(),
+        NodeRef::Update(node) => node.span(),
+        NodeRef::Binary(node) => node.span(),
+        NodeRef::BinaryInPrivate(node) => node.span(),
+        NodeRef::Conditional(node) => node.span(),
+        NodeRef::Await(node) => node.span(),
+        NodeRef::Yield(node) => node.span(),
+        NodeRef::Parenthesized(node) => node.span(),
+        NodeRef::NewTarget(node) => node.span(),
+        NodeRef::ImportMeta(node) => node.span(),
+        NodeRef::ObjectPattern(node) => node.span(),
+        NodeRef::ArrayPattern(node) => node.span(),
+        NodeRef::SimplePropertyAccess(node) => node.span(),
+        NodeRef::PrivatePropertyAccess(node) => node.span(),
+        NodeRef::SuperPropertyAccess(node) => node.span(),
+        NodeRef::OptionalOperation(node) => node.span(),
+        NodeRef::AssignTarget(node) => node.span(),
+        NodeRef::PropertyAccessField(node) => node.span(),
+        _ => return None,
+    };
+    Some(span)
+}
+
+/// Renames every identifier whose symbol is a key of `map` to the mapped symbol.
+///
+/// This rewrites both identifier references and binding positions, like variable declarations,
+/// function names and parameters. Property names (`x.prop`, `{ prop: x }`) and labels are not
+/// identifiers and are left untouched. A shorthand property (`{ x }`) whose identifier gets renamed
+/// is expanded (`{ x: y }`) to preserve the property key.
+pub fn rename_identifiers<'a, N, S>(node: &'a mut N, map: &HashMap<Sym, Sym, S>)
+where
+    &'a mut N: Into<NodeRefMut<'a>>,
+    S: BuildHasher,
+{
+    let _ = RenameIdentifiersVisitor { map }.visit(node);
+}
+
+/// The [`VisitorMut`] used for [`rename_identifiers`].
+#[derive(Debug)]
+struct RenameIdentifiersVisitor<'m, S> {
+    map: &'m HashMap<Sym, Sym, S>,
+}
+
+impl<'ast, S> VisitorMut<'ast> for RenameIdentifiersVisitor<'_, S>
+where
+    S: BuildHasher,
+{
+    type BreakTy = Infallible;
+
+    fn visit_identifier_mut(&mut self, node: &'ast mut Identifier) -> ControlFlow<Self::BreakTy> {
+        if let Some(&sym) = self.map.get(&node.sym()) {
+            *node.sym_mut() = sym;
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_property_definition_mut(
+        &mut self,
+        node: &'ast mut PropertyDefinition,
+    ) -> ControlFlow<Self::BreakTy> {
+        if let PropertyDefinition::IdentifierReference(ident) = node
+            && let Some(&sym) = self.map.get(&ident.sym())
+        {
+            let value = Identifier::new(sym, ident.span());
+            *node = PropertyDefinition::Property(PropertyName::Literal(*ident), value.into());
+            return ControlFlow::Continue(());
+        }
+        node.visit_with_mut(self)
+    }
+}
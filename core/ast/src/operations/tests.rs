@@ -1,10 +1,42 @@
-use boa_interner::Interner;
+use boa_interner::{Interner, Sym, ToInternedString};
+use boa_string::JsString;
+use rustc_hash::FxHashMap;
 
 use crate::{
-    Span, Statement,
-    expression::{Call, Identifier, NewTarget, This},
-    operations::{ContainsSymbol, contains},
-    statement::With,
+    Declaration, Expression, LinearPosition, LinearSpan, Position, Script, Span, Spanned,
+    Statement, StatementList, StatementListItem,
+    declaration::{LexicalDeclaration, Variable, VariableList},
+    expression::{
+        Await, Call, Identifier, NewTarget, TaggedTemplate, This,
+        access::SimplePropertyAccess,
+        literal::{Literal, LiteralKind, ObjectLiteral, PropertyDefinition},
+        operator::{
+            Assign, Binary, Conditional, Unary,
+            assign::AssignOp,
+            binary::{ArithmeticOp, LogicalOp, RelationalOp},
+            unary::UnaryOp,
+            update::{Update, UpdateOp, UpdateTarget},
+        },
+    },
+    function::{
+        ArrowFunction, AsyncFunctionExpression, FormalParameter, FormalParameterList, FunctionBody,
+        FunctionDeclaration, FunctionExpression,
+    },
+    operations::{
+        ContainsSymbol, MisplacedAwaitError, MisplacedRestError, all_paths_return,
+        build_then_continuation, condition_has_side_effects, contains, flatten_conditional,
+        free_variables, if_chain_to_switch, is_numeric_like, is_numeric_like_in_scope,
+        is_statically_infinite, max_call_depth, rename_identifiers, simplify_if, smallest_node_at,
+        split_at_awaits, tail_call_positions, validate_await_positions, validate_spread_positions,
+    },
+    pattern::{ArrayPattern, ArrayPatternElement},
+    scope::Scope,
+    statement::{
+        Block, Case, Catch, ErrorHandler, Finally, If, Labelled, LabelledItem, Return, Switch,
+        Throw, Try, With,
+        iteration::{Break, DoWhileLoop, WhileLoop},
+    },
+    visitor::NodeRef,
 };
 
 #[test]
@@ -54,3 +86,949 @@ fn check_contains_new_target_in_call_argument_position() {
 
     assert!(contains(&node, ContainsSymbol::NewTarget));
 }
+
+/// Builds a block statement with the given statements.
+fn block(statements: Vec<Statement>) -> Statement {
+    let items: Vec<StatementListItem> = statements.into_iter().map(Into::into).collect();
+    Block::from((items, LinearPosition::default())).into()
+}
+
+#[test]
+fn check_statically_infinite_loops() {
+    let always = || Literal::new(true, Span::EMPTY).into();
+
+    // while (true) {}
+    assert!(is_statically_infinite(&WhileLoop::new(
+        always(),
+        block(vec![])
+    )));
+
+    // while (true) { break; }
+    assert!(!is_statically_infinite(&WhileLoop::new(
+        always(),
+        block(vec![Break::new(None).into()]),
+    )));
+
+    // do {} while (1)
+    assert!(is_statically_infinite(&DoWhileLoop::new(
+        block(vec![]),
+        Literal::new(1, Span::EMPTY).into(),
+    )));
+
+    // do {} while (0)
+    assert!(!is_statically_infinite(&DoWhileLoop::new(
+        block(vec![]),
+        Literal::new(0, Span::EMPTY).into(),
+    )));
+
+    // while (x) {}
+    let mut interner = Interner::new();
+    let x = Identifier::new(interner.get_or_intern("x"), Span::EMPTY);
+    assert!(!is_statically_infinite(&WhileLoop::new(
+        x.into(),
+        block(vec![]),
+    )));
+}
+
+#[test]
+fn check_statically_infinite_loops_nested_breaks() {
+    let mut interner = Interner::new();
+    let inner = interner.get_or_intern("inner");
+    let outer = interner.get_or_intern("outer");
+    let always = || Literal::new(true, Span::EMPTY).into();
+
+    // while (true) { while (true) { break; } }
+    let node = WhileLoop::new(
+        always(),
+        block(vec![
+            WhileLoop::new(always(), block(vec![Break::new(None).into()])).into(),
+        ]),
+    );
+    assert!(is_statically_infinite(&node));
+
+    // while (true) { inner: { break inner; } }
+    let node = WhileLoop::new(
+        always(),
+        block(vec![
+            Labelled::new(
+                LabelledItem::Statement(block(vec![Break::new(Some(inner)).into()])),
+                inner,
+            )
+            .into(),
+        ]),
+    );
+    assert!(is_statically_infinite(&node));
+
+    // while (true) { while (true) { break outer; } }
+    let node = WhileLoop::new(
+        always(),
+        block(vec![
+            WhileLoop::new(always(), block(vec![Break::new(Some(outer)).into()])).into(),
+        ]),
+    );
+    assert!(!is_statically_infinite(&node));
+}
+
+#[test]
+fn check_split_at_awaits() {
+    let mut interner = Interner::new();
+    let call = |interner: &mut Interner, name: &str| -> Expression {
+        let function = Identifier::new(interner.get_or_intern(name), Span::EMPTY);
+        Call::new(function.into(), Box::default(), Span::EMPTY).into()
+    };
+    let awaited = |expression: Expression| -> Statement {
+        Expression::from(Await::new(expression.into(), Span::EMPTY, Span::EMPTY)).into()
+    };
+    let body = |items: Vec<StatementListItem>| {
+        FunctionBody::new(
+            StatementList::new(items, LinearPosition::default(), false),
+            Span::EMPTY,
+        )
+    };
+
+    // An `await` inside a nested function is not a boundary.
+    let nested = FunctionDeclaration::new(
+        Identifier::new(interner.get_or_intern("inner"), Span::EMPTY),
+        FormalParameterList::default(),
+        body(vec![awaited(call(&mut interner, "c")).into()]),
+        LinearSpan::default(),
+    );
+
+    let statements: Vec<StatementListItem> = vec![
+        Statement::from(call(&mut interner, "a")).into(),
+        awaited(call(&mut interner, "b")).into(),
+        Declaration::FunctionDeclaration(nested).into(),
+        Statement::from(call(&mut interner, "d")).into(),
+        awaited(call(&mut interner, "e")).into(),
+        Statement::from(call(&mut interner, "f")).into(),
+    ];
+
+    assert_eq!(split_at_awaits(&body(statements)), vec![0..2, 2..5, 5..6]);
+    assert_eq!(split_at_awaits(&body(Vec::new())), vec![0..0]);
+}
+
+#[test]
+fn check_validate_await_positions() {
+    let mut interner = Interner::new();
+    let x = Identifier::new(interner.get_or_intern("x"), Span::EMPTY);
+    let awaited = |span: Span| -> Statement {
+        Expression::from(Await::new(Box::new(x.into()), span, span)).into()
+    };
+    let body = |statements: Vec<Statement>| {
+        let items: Vec<StatementListItem> = statements.into_iter().map(Into::into).collect();
+        FunctionBody::new(
+            StatementList::new(items, LinearPosition::default(), false),
+            Span::EMPTY,
+        )
+    };
+    let first = Span::new((2, 3), (2, 10));
+    let second = Span::new((3, 3), (3, 10));
+
+    // async function () { await x; await x; }
+    let valid = AsyncFunctionExpression::new(
+        None,
+        FormalParameterList::default(),
+        body(vec![awaited(first), awaited(second)]),
+        LinearSpan::default(),
+        false,
+        Span::EMPTY,
+    );
+    assert_eq!(validate_await_positions(&valid), Ok(()));
+
+    // function () { await x; await x; }
+    let invalid = FunctionExpression::new(
+        None,
+        FormalParameterList::default(),
+        body(vec![awaited(first), awaited(second)]),
+        None,
+        false,
+        Span::EMPTY,
+    );
+    let errors = validate_await_positions(&invalid).unwrap_err();
+    assert_eq!(
+        errors
+            .iter()
+            .map(MisplacedAwaitError::span)
+            .collect::<Vec<_>>(),
+        [first, second]
+    );
+
+    // async function () { () => { await x; } }
+    let arrow = ArrowFunction::new(
+        None,
+        FormalParameterList::default(),
+        body(vec![awaited(first)]),
+        LinearSpan::default(),
+        Span::EMPTY,
+    );
+    let nested = AsyncFunctionExpression::new(
+        None,
+        FormalParameterList::default(),
+        body(vec![Expression::from(arrow).into()]),
+        LinearSpan::default(),
+        false,
+        Span::EMPTY,
+    );
+    let errors = validate_await_positions(&nested).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].span(), first);
+}
+
+#[test]
+fn check_rename_identifiers() {
+    let mut interner = Interner::new();
+    let f = interner.get_or_intern("f");
+    let x = interner.get_or_intern("x");
+    let y = interner.get_or_intern("y");
+    let renamed = interner.get_or_intern("renamed");
+    let ident = |sym| Identifier::new(sym, Span::EMPTY);
+
+    // function f(x) { do { x = x + y.x; } while (x < { x }); }
+    let increment = Assign::new(
+        AssignOp::Assign,
+        ident(x).into(),
+        Binary::new(
+            ArithmeticOp::Add.into(),
+            ident(x).into(),
+            Expression::PropertyAccess(SimplePropertyAccess::new(ident(y).into(), ident(x)).into()),
+        )
+        .into(),
+    );
+    let condition = Binary::new(
+        RelationalOp::LessThan.into(),
+        ident(x).into(),
+        ObjectLiteral::new(
+            vec![PropertyDefinition::IdentifierReference(ident(x))],
+            Span::EMPTY,
+        )
+        .into(),
+    );
+    let do_while = DoWhileLoop::new(
+        block(vec![Expression::from(increment).into()]),
+        condition.into(),
+    );
+    let mut function = FunctionDeclaration::new(
+        ident(f),
+        FormalParameter::new(Variable::from_identifier(ident(x), None), false).into(),
+        FunctionBody::new(
+            StatementList::new(
+                vec![Statement::from(do_while).into()],
+                LinearPosition::default(),
+                false,
+            ),
+            Span::EMPTY,
+        ),
+        LinearSpan::default(),
+    );
+
+    let mut map = FxHashMap::default();
+    map.insert(x, renamed);
+    rename_identifiers(&mut function, &map);
+
+    assert_eq!(
+        function.to_interned_string(&interner),
+        "function f(renamed) {\n    do {\n        renamed = renamed + y.x;\n    } while (renamed < {\n    x: renamed,\n});\n}"
+    );
+}
+
+#[test]
+fn check_all_paths_return() {
+    let mut interner = Interner::new();
+    let x = Identifier::new(interner.get_or_intern("x"), Span::EMPTY);
+    let ret = || Statement::from(Return::new(Some(Literal::new(1, Span::EMPTY).into())));
+    let throw = || Statement::from(Throw::new(Literal::new(2, Span::EMPTY).into()));
+    let body = |statements: Vec<Statement>| {
+        let items: Vec<StatementListItem> = statements.into_iter().map(Into::into).collect();
+        FunctionBody::new(
+            StatementList::new(items, LinearPosition::default(), false),
+            Span::EMPTY,
+        )
+    };
+    let list = |statements: Vec<Statement>| {
+        let items: Vec<StatementListItem> = statements.into_iter().map(Into::into).collect();
+        StatementList::new(items, LinearPosition::default(), false)
+    };
+
+    assert!(!all_paths_return(&body(Vec::new())));
+    assert!(all_paths_return(&body(vec![Statement::Empty, ret()])));
+
+    // if (x) { return 1; } else { throw 2; }
+    let guarded = If::new(
+        x.into(),
+        block(vec![ret()]),
+        Some(block(vec![throw()])),
+        Span::EMPTY,
+    );
+    assert!(all_paths_return(&body(vec![guarded.into()])));
+
+    // if (x) { return 1; }
+    let missing_else = If::new(x.into(), block(vec![ret()]), None, Span::EMPTY);
+    assert!(!all_paths_return(&body(vec![missing_else.clone().into()])));
+    assert!(all_paths_return(&body(vec![missing_else.into(), throw()])));
+
+    // switch (x) { case 1: default: return 1; }
+    let cases = |last: Statement| {
+        vec![
+            Case::new(Literal::new(1, Span::EMPTY).into(), list(Vec::new())),
+            Case::default(list(vec![last])),
+        ]
+    };
+    let switch = Switch::new(x.into(), cases(ret()).into_boxed_slice());
+    assert!(all_paths_return(&body(vec![switch.into()])));
+
+    // switch (x) { case 1: default: break; }
+    let switch = Switch::new(x.into(), cases(Break::new(None).into()).into_boxed_slice());
+    assert!(!all_paths_return(&body(vec![switch.into()])));
+
+    // switch (x) { case 1: return 1; }
+    let switch = Switch::new(
+        x.into(),
+        vec![Case::new(
+            Literal::new(1, Span::EMPTY).into(),
+            list(vec![ret()]),
+        )]
+        .into_boxed_slice(),
+    );
+    assert!(!all_paths_return(&body(vec![switch.into()])));
+
+    // while (true) {}
+    let infinite = WhileLoop::new(Literal::new(true, Span::EMPTY).into(), block(Vec::new()));
+    assert!(all_paths_return(&body(vec![infinite.into()])));
+
+    // while (x) { return 1; }
+    let finite = WhileLoop::new(x.into(), block(vec![ret()]));
+    assert!(!all_paths_return(&body(vec![finite.into()])));
+}
+
+#[test]
+fn check_build_then_continuation() {
+    let mut interner = Interner::new();
+    let x = Identifier::new(interner.get_or_intern("x"), Span::new((1, 1), (1, 2)));
+    let y = Identifier::new(interner.get_or_intern("y"), Span::new((1, 5), (1, 6)));
+    let name = Identifier::new(interner.get_or_intern("fn"), Span::EMPTY);
+    let continuation = |span| {
+        FunctionExpression::new(
+            Some(name),
+            FormalParameterList::default(),
+            FunctionBody::new(StatementList::default(), Span::EMPTY),
+            None,
+            true,
+            span,
+        )
+    };
+
+    let call = build_then_continuation(x.into(), continuation(Span::new((1, 10), (1, 25))));
+    assert_eq!(
+        call.to_interned_string(&interner),
+        "x.then(function fn() {})"
+    );
+    assert_eq!(call.span(), Span::new((1, 1), (1, 25)));
+
+    let sum = Binary::new(ArithmeticOp::Add.into(), x.into(), y.into());
+    let call = build_then_continuation(sum.into(), continuation(Span::EMPTY));
+    assert_eq!(
+        call.to_interned_string(&interner),
+        "(x + y).then(function fn() {})"
+    );
+}
+
+#[test]
+fn check_flatten_conditional() {
+    let mut interner = Interner::new();
+    let mut ident = |name: &str| -> Expression {
+        Identifier::new(interner.get_or_intern(name), Span::EMPTY).into()
+    };
+    let [c1, t1, c2, t2, c3, t3, last] =
+        ["c1", "t1", "c2", "t2", "c3", "t3", "last"].map(&mut ident);
+
+    // c1 ? t1 : c2 ? t2 : last
+    let two = Conditional::new(
+        c1.clone(),
+        t1.clone(),
+        Conditional::new(c2.clone(), t2.clone(), last.clone()).into(),
+    );
+    assert_eq!(
+        flatten_conditional(&two),
+        [(Some(&c1), &t1), (Some(&c2), &t2), (None, &last)]
+    );
+
+    // c1 ? t1 : c2 ? t2 : c3 ? t3 : last
+    let three = Conditional::new(
+        c1.clone(),
+        t1.clone(),
+        Conditional::new(
+            c2.clone(),
+            t2.clone(),
+            Conditional::new(c3.clone(), t3.clone(), last.clone()).into(),
+        )
+        .into(),
+    );
+    assert_eq!(
+        flatten_conditional(&three),
+        [
+            (Some(&c1), &t1),
+            (Some(&c2), &t2),
+            (Some(&c3), &t3),
+            (None, &last)
+        ]
+    );
+
+    // c1 ? (c2 ? t2 : last) : t1
+    let nested_true = Conditional::new(c1.clone(), two.if_false().clone(), t1.clone());
+    let branches = flatten_conditional(&nested_true);
+    assert_eq!(branches.len(), 2);
+    assert!(matches!(branches[0].1, Expression::Conditional(_)));
+    assert_eq!(branches[1], (None, &t1));
+}
+
+#[test]
+fn check_simplify_if_empty_then() {
+    let mut interner = Interner::new();
+    let cond: Expression = Identifier::new(interner.get_or_intern("c"), Span::EMPTY).into();
+    let call: Statement = Expression::from(Call::new(
+        Identifier::new(interner.get_or_intern("f"), Span::EMPTY).into(),
+        Box::default(),
+        Span::EMPTY,
+    ))
+    .into();
+
+    // if (c) {} else f(); -> if (!c) f();
+    let node = If::new(
+        cond.clone(),
+        block(Vec::new()),
+        Some(call.clone()),
+        Span::EMPTY,
+    );
+    let negated = Unary::new(UnaryOp::Not, cond.clone(), Span::EMPTY);
+    assert_eq!(
+        simplify_if(node),
+        Statement::If(If::new(negated.into(), call.clone(), None, Span::EMPTY)).into()
+    );
+
+    // if (c); else f(); -> if (!c) f();
+    let node = If::new(
+        cond.clone(),
+        Statement::Empty,
+        Some(call.clone()),
+        Span::EMPTY,
+    );
+    let StatementListItem::Statement(statement) = simplify_if(node) else {
+        panic!("expected a statement");
+    };
+    let Statement::If(simplified) = statement.as_ref() else {
+        panic!("expected an if statement");
+    };
+    assert!(matches!(simplified.cond(), Expression::Unary(unary) if unary.op() == UnaryOp::Not));
+    assert_eq!(simplified.body(), &call);
+    assert!(simplified.else_node().is_none());
+
+    // if (c) f(); else {} is left as-is.
+    let node = If::new(
+        cond.clone(),
+        call.clone(),
+        Some(block(Vec::new())),
+        Span::EMPTY,
+    );
+    assert_eq!(simplify_if(node.clone()), Statement::If(node).into());
+
+    // if (c || d) {} else f(); -> if (! (c || d)) f();
+    let d = Identifier::new(interner.get_or_intern("d"), Span::EMPTY);
+    let either = Binary::new(LogicalOp::Or.into(), cond, d.into());
+    let node = If::new(either.into(), block(Vec::new()), Some(call), Span::EMPTY);
+    assert_eq!(
+        simplify_if(node).to_interned_string(&interner),
+        "if (! (c || d)) f();"
+    );
+}
+
+#[test]
+fn check_simplify_if_both_empty() {
+    let mut interner = Interner::new();
+
+    // if (true) {} else {} is dropped.
+    let node = If::new(
+        Literal::new(true, Span::EMPTY).into(),
+        block(Vec::new()),
+        Some(block(Vec::new())),
+        Span::EMPTY,
+    );
+    assert_eq!(simplify_if(node), Statement::Empty.into());
+
+    // if (this); is dropped.
+    let node = If::new(
+        This::new(Span::EMPTY).into(),
+        Statement::Empty,
+        None,
+        Span::EMPTY,
+    );
+    assert_eq!(simplify_if(node), Statement::Empty.into());
+
+    // if (f()) {} else {} keeps the call: f();
+    let call: Expression = Call::new(
+        Identifier::new(interner.get_or_intern("f"), Span::EMPTY).into(),
+        Box::default(),
+        Span::EMPTY,
+    )
+    .into();
+    let node = If::new(
+        call.clone(),
+        block(Vec::new()),
+        Some(block(Vec::new())),
+        Span::EMPTY,
+    );
+    assert_eq!(simplify_if(node), Statement::Expression(call).into());
+}
+
+#[test]
+fn check_tail_call_positions() {
+    let mut interner = Interner::new();
+    let x: Expression = Identifier::new(interner.get_or_intern("x"), Span::EMPTY).into();
+    let f: Expression = Identifier::new(interner.get_or_intern("f"), Span::EMPTY).into();
+    // A call to `f()` on the given line.
+    let call = |line: u32| -> Expression {
+        Call::new(f.clone(), Box::default(), Span::new((line, 1), (line, 4))).into()
+    };
+    let ret = |expr: Expression| Statement::from(Return::new(Some(expr)));
+    let body = |statements: Vec<Statement>| {
+        let items: Vec<StatementListItem> = statements.into_iter().map(Into::into).collect();
+        FunctionBody::new(
+            StatementList::new(items, LinearPosition::default(), false),
+            Span::EMPTY,
+        )
+    };
+
+    // if (x) { return f(); } else { return f(); }
+    let both = If::new(
+        x.clone(),
+        block(vec![ret(call(1))]),
+        Some(block(vec![ret(call(2))])),
+        Span::EMPTY,
+    );
+    assert_eq!(
+        tail_call_positions(&body(vec![both.into()])),
+        [Span::new((1, 1), (1, 4)), Span::new((2, 1), (2, 4))]
+    );
+
+    // f(); return x ? f() : x && f(); return f() + 1;
+    let logical = Binary::new(LogicalOp::And.into(), x.clone(), call(4));
+    let conditional = Conditional::new(x.clone(), call(3), logical.into());
+    let sum = Binary::new(
+        ArithmeticOp::Add.into(),
+        call(5),
+        Literal::new(1, Span::EMPTY).into(),
+    );
+    assert_eq!(
+        tail_call_positions(&body(vec![
+            call(6).into(),
+            ret(conditional.into()),
+            ret(sum.into()),
+        ])),
+        [Span::new((3, 1), (3, 4)), Span::new((4, 1), (4, 4))]
+    );
+
+    // return f(f());
+    let nested = Call::new(f.clone(), Box::new([call(8)]), Span::new((7, 1), (7, 7)));
+    assert_eq!(
+        tail_call_positions(&body(vec![ret(nested.into())])),
+        [Span::new((7, 1), (7, 7))]
+    );
+
+    // try { return f(); } catch { return f(); }
+    let try_block =
+        |line: u32| Block::from((vec![ret(call(line)).into()], LinearPosition::default()));
+    let try_catch = Try::new(
+        try_block(9),
+        ErrorHandler::Catch(Catch::new(None, try_block(10))),
+    );
+    assert_eq!(
+        tail_call_positions(&body(vec![try_catch.into()])),
+        [Span::new((10, 1), (10, 4))]
+    );
+
+    // try { return f(); } catch { return f(); } finally { return f(); }
+    let try_catch_finally = Try::new(
+        try_block(11),
+        ErrorHandler::Full(
+            Catch::new(None, try_block(12)),
+            Finally::from(try_block(13)),
+        ),
+    );
+    // try { return f(); } finally { return f(); }
+    let try_finally = Try::new(
+        try_block(14),
+        ErrorHandler::Finally(Finally::from(try_block(15))),
+    );
+    assert_eq!(
+        tail_call_positions(&body(vec![try_catch_finally.into(), try_finally.into()])),
+        [Span::new((13, 1), (13, 4)), Span::new((15, 1), (15, 4))]
+    );
+
+    // return f`x`;
+    let template = TaggedTemplate::new(
+        f.clone(),
+        Box::new([interner.get_or_intern("x")]),
+        Box::new([Some(interner.get_or_intern("x"))]),
+        Box::default(),
+        0,
+        Span::new((16, 1), (16, 5)),
+    );
+    assert_eq!(
+        tail_call_positions(&body(vec![ret(template.into())])),
+        [Span::new((16, 1), (16, 5))]
+    );
+}
+
+#[test]
+fn check_smallest_node_at() {
+    let mut interner = Interner::new();
+    let x = interner.get_or_intern("x");
+    let foo = interner.get_or_intern("foo");
+    let y = interner.get_or_intern("y");
+
+    // x + foo(y);
+    let call = Call::new(
+        Identifier::new(foo, Span::new((1, 5), (1, 8))).into(),
+        Box::new([Identifier::new(y, Span::new((1, 9), (1, 10))).into()]),
+        Span::new((1, 8), (1, 11)),
+    );
+    let sum = Binary::new(
+        ArithmeticOp::Add.into(),
+        Identifier::new(x, Span::new((1, 1), (1, 2))).into(),
+        call.into(),
+    );
+    let script = Script::new(StatementList::new(
+        [Statement::Expression(sum.into()).into()],
+        LinearPosition::default(),
+        false,
+    ));
+
+    let identifier_at = |line, column| match smallest_node_at(&script, Position::new(line, column))
+    {
+        Some(NodeRef::Identifier(ident)) => Some(ident.to_interned_string(&interner)),
+        _ => None,
+    };
+    assert_eq!(identifier_at(1, 1).as_deref(), Some("x"));
+    assert_eq!(identifier_at(1, 6).as_deref(), Some("foo"));
+    assert_eq!(identifier_at(1, 9).as_deref(), Some("y"));
+
+    assert!(matches!(
+        smallest_node_at(&script, Position::new(1, 3)),
+        Some(NodeRef::Binary(_))
+    ));
+    assert!(matches!(
+        smallest_node_at(&script, Position::new(1, 10)),
+        Some(NodeRef::Call(_))
+    ));
+    assert!(smallest_node_at(&script, Position::new(1, 11)).is_none());
+    assert!(smallest_node_at(&script, Position::new(2, 1)).is_none());
+}
+
+#[test]
+fn check_condition_has_side_effects() {
+    let mut interner = Interner::new();
+    let i = Identifier::new(interner.get_or_intern("i"), Span::EMPTY);
+    let f: Expression = Identifier::new(interner.get_or_intern("f"), Span::EMPTY).into();
+    let ten: Expression = Literal::new(10, Span::EMPTY).into();
+    let less_than = |lhs: Expression| {
+        Expression::from(Binary::new(RelationalOp::LessThan.into(), lhs, ten.clone()))
+    };
+
+    // i++ < 10
+    let increment = Update::new(
+        UpdateOp::IncrementPost,
+        UpdateTarget::Identifier(i),
+        Span::EMPTY,
+    );
+    assert!(condition_has_side_effects(&less_than(increment.into())));
+
+    // i < 10
+    assert!(!condition_has_side_effects(&less_than(i.into())));
+
+    // f() < 10
+    let call = Call::new(f.clone(), Box::default(), Span::EMPTY);
+    assert!(condition_has_side_effects(&less_than(call.clone().into())));
+
+    // (() => f()) < 10
+    let arrow = ArrowFunction::new(
+        None,
+        FormalParameterList::default(),
+        FunctionBody::new(
+            StatementList::new(
+                [Statement::Expression(call.into()).into()],
+                LinearPosition::default(),
+                false,
+            ),
+            Span::EMPTY,
+        ),
+        LinearSpan::default(),
+        Span::EMPTY,
+    );
+    assert!(!condition_has_side_effects(&less_than(arrow.into())));
+}
+
+#[test]
+fn check_max_call_depth() {
+    let mut interner = Interner::new();
+    let f: Expression = Identifier::new(interner.get_or_intern("f"), Span::EMPTY).into();
+    let call = |args: Vec<Expression>| -> Expression {
+        Call::new(f.clone(), args.into_boxed_slice(), Span::EMPTY).into()
+    };
+    let body = |statements: Vec<Statement>| {
+        let items: Vec<StatementListItem> = statements.into_iter().map(Into::into).collect();
+        FunctionBody::new(
+            StatementList::new(items, LinearPosition::default(), false),
+            Span::EMPTY,
+        )
+    };
+
+    assert_eq!(max_call_depth(&body(Vec::new())), 0);
+
+    // f(); f(1, 2); f();
+    let one = Literal::new(1, Span::EMPTY);
+    let flat = body(vec![
+        call(Vec::new()).into(),
+        call(vec![
+            one.clone().into(),
+            Literal::new(2, Span::EMPTY).into(),
+        ])
+        .into(),
+        call(Vec::new()).into(),
+    ]);
+    assert_eq!(max_call_depth(&flat), 1);
+
+    // f(f(f(f()))); f(1 + f());
+    let nested = call(vec![call(vec![call(vec![call(Vec::new())])])]);
+    let sum = Binary::new(ArithmeticOp::Add.into(), one.into(), call(Vec::new()));
+    let deep = body(vec![nested.into(), call(vec![sum.into()]).into()]);
+    assert_eq!(max_call_depth(&deep), 4);
+
+    // f()(f(f()));
+    let chained = Call::new(
+        call(Vec::new()),
+        Box::new([call(vec![call(Vec::new())])]),
+        Span::EMPTY,
+    );
+    assert_eq!(
+        max_call_depth(&body(vec![Expression::from(chained).into()])),
+        3
+    );
+}
+
+#[test]
+fn check_is_numeric_like() {
+    let mut interner = Interner::new();
+    let ident = |sym| -> Expression { Identifier::new(sym, Span::EMPTY).into() };
+    let negate = |expr| -> Expression { Unary::new(UnaryOp::Minus, expr, Span::EMPTY).into() };
+
+    assert!(is_numeric_like(&Literal::new(1, Span::EMPTY).into()));
+    assert!(is_numeric_like(&Literal::new(1.5, Span::EMPTY).into()));
+    assert!(is_numeric_like(
+        &Literal::new(LiteralKind::BigInt(Box::new(2.into())), Span::EMPTY).into()
+    ));
+    assert!(is_numeric_like(&ident(Sym::NAN)));
+    assert!(is_numeric_like(&ident(Sym::INFINITY)));
+    assert!(is_numeric_like(&negate(ident(Sym::INFINITY))));
+
+    assert!(!is_numeric_like(&negate(ident(Sym::NAN))));
+    assert!(!is_numeric_like(&ident(interner.get_or_intern("nan"))));
+    assert!(!is_numeric_like(
+        &Literal::new(Sym::NAN, Span::EMPTY).into()
+    ));
+
+    // function f() { let NaN; ... }
+    let global = Scope::new_global();
+    let function = Scope::new(global.clone(), true);
+    let _binding = function.create_mutable_binding(JsString::from("NaN"), true);
+    assert!(is_numeric_like_in_scope(&ident(Sym::NAN), &global));
+    assert!(!is_numeric_like_in_scope(&ident(Sym::NAN), &function));
+
+    let block = Scope::new(function, false);
+    assert!(!is_numeric_like_in_scope(&ident(Sym::NAN), &block));
+    assert!(is_numeric_like_in_scope(
+        &negate(ident(Sym::INFINITY)),
+        &block
+    ));
+    assert!(is_numeric_like_in_scope(
+        &Literal::new(1, Span::EMPTY).into(),
+        &block
+    ));
+}
+
+#[test]
+fn check_free_variables() {
+    let mut interner = Interner::new();
+    let a = interner.get_or_intern("a");
+    let b = interner.get_or_intern("b");
+    let c = interner.get_or_intern("c");
+    let ident = |sym| Identifier::new(sym, Span::EMPTY);
+    let parameters: FormalParameterList =
+        FormalParameter::new(Variable::from_identifier(ident(a), None), false).into();
+
+    // (a) { let b = a + <init>; return b; }
+    let body = |init: Sym| {
+        let sum = Binary::new(
+            ArithmeticOp::Add.into(),
+            ident(a).into(),
+            ident(init).into(),
+        );
+        let declaration = LexicalDeclaration::Let(
+            VariableList::try_from(vec![Variable::from_identifier(ident(b), Some(sum.into()))])
+                .expect("non-empty variable list"),
+        );
+        let items: Vec<StatementListItem> = vec![
+            Declaration::from(declaration).into(),
+            Statement::from(Return::new(Some(ident(b).into()))).into(),
+        ];
+        FunctionBody::new(
+            StatementList::new(items, LinearPosition::default(), false),
+            Span::EMPTY,
+        )
+    };
+
+    // Captures `c` from an outer scope.
+    let free = free_variables(&parameters, &body(c), &interner);
+    assert_eq!(free.into_iter().collect::<Vec<_>>(), [c]);
+
+    // Only uses its parameter and its own declaration.
+    let free = free_variables(&parameters, &body(b), &interner);
+    assert!(free.is_empty(), "{free:?}");
+}
+
+#[test]
+fn check_validate_spread_positions() {
+    let mut interner = Interner::new();
+    let a = Identifier::new(interner.get_or_intern("a"), Span::new((1, 14), (1, 15)));
+    let b = Identifier::new(interner.get_or_intern("b"), Span::new((1, 20), (1, 21)));
+    let function = |parameters: Vec<FormalParameter>| {
+        FunctionExpression::new(
+            None,
+            FormalParameterList::from(parameters),
+            FunctionBody::new(StatementList::default(), Span::EMPTY),
+            None,
+            false,
+            Span::EMPTY,
+        )
+    };
+    let parameter = |ident: Identifier, rest: bool| {
+        FormalParameter::new(Variable::from_identifier(ident, None), rest)
+    };
+
+    // function (a, ...b) {}
+    let valid = function(vec![parameter(a, false), parameter(b, true)]);
+    assert_eq!(validate_spread_positions(&valid), Ok(()));
+
+    // function (...a, b) {}
+    let invalid = function(vec![parameter(a, true), parameter(b, false)]);
+    let errors = validate_spread_positions(&invalid).unwrap_err();
+    assert_eq!(
+        errors
+            .iter()
+            .map(MisplacedRestError::span)
+            .collect::<Vec<_>>(),
+        [a.span()]
+    );
+    assert_eq!(
+        errors[0].message(),
+        "rest parameter must be the last formal parameter"
+    );
+
+    // function ([...a, b]) {}
+    let pattern = ArrayPattern::new(
+        vec![
+            ArrayPatternElement::SingleNameRest { ident: a },
+            ArrayPatternElement::SingleName {
+                ident: b,
+                default_init: None,
+            },
+        ]
+        .into(),
+        Span::EMPTY,
+    );
+    let invalid = function(vec![FormalParameter::new(
+        Variable::from_pattern(pattern.into(), None),
+        false,
+    )]);
+    let errors = validate_spread_positions(&invalid).unwrap_err();
+    assert_eq!(
+        errors
+            .iter()
+            .map(MisplacedRestError::span)
+            .collect::<Vec<_>>(),
+        [a.span()]
+    );
+    assert_eq!(
+        errors[0].message(),
+        "rest element must be the last element of a destructuring pattern"
+    );
+}
+
+#[test]
+fn check_if_chain_to_switch() {
+    let mut interner = Interner::new();
+    let x: Expression = Identifier::new(interner.get_or_intern("x"), Span::EMPTY).into();
+    let y: Expression = Identifier::new(interner.get_or_intern("y"), Span::EMPTY).into();
+    let call = |name: &str, interner: &mut Interner| -> Statement {
+        Expression::from(Call::new(
+            Identifier::new(interner.get_or_intern(name), Span::EMPTY).into(),
+            Box::default(),
+            Span::EMPTY,
+        ))
+        .into()
+    };
+    let eq = |lhs: Expression, rhs: Expression| -> Expression {
+        Binary::new(RelationalOp::StrictEqual.into(), lhs, rhs).into()
+    };
+    let lit = |value: i32| -> Expression { Literal::new(value, Span::EMPTY).into() };
+    let call_a = call("a", &mut interner);
+    let call_b = call("b", &mut interner);
+    let call_c = call("c", &mut interner);
+
+    // if (x === 1) { a(); } else if (2 === x) b(); else c();
+    let chain = If::new(
+        eq(x.clone(), lit(1)),
+        block(vec![call_a.clone()]),
+        Some(
+            If::new(
+                eq(lit(2), x.clone()),
+                call_b.clone(),
+                Some(call_c.clone()),
+                Span::EMPTY,
+            )
+            .into(),
+        ),
+        Span::EMPTY,
+    );
+    let switch = if_chain_to_switch(&chain).expect("the chain should be converted");
+    assert_eq!(
+        switch.to_interned_string(&interner),
+        "switch (x) {\n    case 1:\n        a();\n        break;\n    case 2:\n        b();\n        \
+         break;\n    default:\n        c();\n        break;\n}"
+    );
+
+    // if (x === 1) a(); else if (y === 2) b();
+    let different = If::new(
+        eq(x.clone(), lit(1)),
+        call_a.clone(),
+        Some(If::new(eq(y, lit(2)), call_b.clone(), None, Span::EMPTY).into()),
+        Span::EMPTY,
+    );
+    assert!(if_chain_to_switch(&different).is_none());
+
+    // if (x == 1) a();
+    let loose = If::new(
+        Binary::new(RelationalOp::Equal.into(), x.clone(), lit(1)).into(),
+        call_a,
+        None,
+        Span::EMPTY,
+    );
+    assert!(if_chain_to_switch(&loose).is_none());
+
+    // if (x === 1) break;
+    let breaks = If::new(
+        eq(x, lit(1)),
+        Statement::Break(Break::new(None)),
+        None,
+        Span::EMPTY,
+    );
+    assert!(if_chain_to_switch(&breaks).is_none());
+}
@@ -141,6 +141,9 @@ pub struct Span {
 
 impl Debug for Span {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_synthetic() {
+            return f.write_str("Span(synthetic)");
+        }
         write!(
             f,
             "Span(({}, {}), ({}, {}))",
@@ -158,6 +161,30 @@ impl Span {
         end: Position::new(1, 1),
     };
 
+    /// The position of synthetic spans, which is past any position of a real source.
+    const SYNTHETIC_POSITION: Position = Position::new(u32::MAX, u32::MAX);
+
+    /// Creates a span for a node that is not part of the parsed source, like the nodes created
+    /// by transform passes.
+    ///
+    /// Synthetic spans are zero-width spans at a sentinel position that no source can reach, so
+    /// they can be told apart from the spans of parsed nodes with [`Span::is_synthetic`].
+    #[inline]
+    #[must_use]
+    pub const fn synthetic() -> Self {
+        Self {
+            start: Self::SYNTHETIC_POSITION,
+            end: Self::SYNTHETIC_POSITION,
+        }
+    }
+
+    /// Checks if this is a [synthetic](Span::synthetic) span.
+    #[inline]
+    #[must_use]
+    pub fn is_synthetic(self) -> bool {
+        self == Self::synthetic()
+    }
+
     /// Creates a new `Span`.
     ///
     /// # Panics
@@ -201,6 +228,51 @@ impl Span {
         let other = other.into();
         self.start <= other.start && self.end >= other.end
     }
+
+    /// Checks if the given position falls within this span.
+    ///
+    /// Unlike [`Span::contains`], the span is treated as half-open: the start position is
+    /// included, but the end position is not, since it points just past the last character of
+    /// the node. This makes adjacent spans never contain the same position, which is what editor
+    /// features like finding the node under the cursor need. Empty spans contain no positions.
+    #[inline]
+    #[must_use]
+    pub fn contains_position(self, pos: Position) -> bool {
+        self.start <= pos && pos < self.end
+    }
+
+    /// Checks if this span and another span share at least one position.
+    ///
+    /// Both spans are treated as half-open, like in [`Span::contains_position`], so spans that
+    /// only touch at their boundaries don't overlap, and empty spans never overlap any span.
+    #[inline]
+    #[must_use]
+    pub fn overlaps(self, other: Self) -> bool {
+        self.start.max(other.start) < self.end.min(other.end)
+    }
+
+    /// Returns the smallest span that encompasses both this span and another span or position.
+    ///
+    /// This is useful to compute the span of a composite construct from the spans of its parts.
+    /// Synthetic spans are ignored, so a parsed part keeps the result pointing to the source.
+    #[inline]
+    #[must_use]
+    pub fn encompass<S>(self, other: S) -> Self
+    where
+        S: Into<Self>,
+    {
+        let other = other.into();
+        if self.is_synthetic() {
+            return other;
+        }
+        if other.is_synthetic() {
+            return self;
+        }
+        Self {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        }
+    }
 }
 
 impl From<Position> for Span {
@@ -242,6 +314,9 @@ impl PartialOrd for Span {
 
 impl fmt::Display for Span {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_synthetic() {
+            return f.write_str("[synthetic]");
+        }
         write!(f, "[{}..{}]", self.start, self.end)
     }
 }
@@ -251,6 +326,15 @@ pub trait Spanned {
     /// Returns a span from the current type.
     #[must_use]
     fn span(&self) -> Span;
+
+    /// Checks if the element was created by a transform instead of being parsed from the source,
+    /// which is signaled by a [synthetic](Span::synthetic) span.
+    ///
+    /// Printers and source map generators can use this to skip elements without a location.
+    #[must_use]
+    fn is_synthetic(&self) -> bool {
+        self.span().is_synthetic()
+    }
 }
 
 /// A linear span in the ECMAScript source code.
@@ -283,6 +367,27 @@ impl LinearSpan {
         Self { start, end }
     }
 
+    /// Creates a linear span for a node that is not part of the parsed source, like the nodes
+    /// created by transform passes.
+    ///
+    /// Like [`Span::synthetic`], this is a zero-width span at a sentinel position that no source
+    /// can reach, so it can be told apart with [`LinearSpan::is_synthetic`].
+    #[inline]
+    #[must_use]
+    pub const fn synthetic() -> Self {
+        Self {
+            start: LinearPosition::new(usize::MAX),
+            end: LinearPosition::new(usize::MAX),
+        }
+    }
+
+    /// Checks if this is a [synthetic](LinearSpan::synthetic) linear span.
+    #[inline]
+    #[must_use]
+    pub fn is_synthetic(self) -> bool {
+        self == Self::synthetic()
+    }
+
     /// Test if the span is empty.
     #[inline]
     #[must_use]
@@ -313,11 +418,20 @@ impl LinearSpan {
         self.start <= other.start && self.end >= other.end
     }
 
-    /// Gets the starting position of the span.
+    /// Returns the smallest linear span that covers both this span and another span or position.
+    ///
+    /// Like [`Span::encompass`], synthetic spans are ignored, so a parsed part keeps the result
+    /// pointing to the source. Empty spans are not ignored, since they are used for positions.
     #[inline]
     #[must_use]
     pub fn union(self, other: impl Into<Self>) -> Self {
         let other: Self = other.into();
+        if self.is_synthetic() {
+            return other;
+        }
+        if other.is_synthetic() {
+            return self;
+        }
         Self {
             start: LinearPosition::new(self.start.pos.min(other.start.pos)),
             end: LinearPosition::new(self.end.pos.max(other.end.pos)),
@@ -671,6 +785,35 @@ mod tests {
         assert!(span_cd > span_ab);
     }
 
+    /// Checks that encompassing spans produces the minimal span covering both.
+    #[test]
+    fn span_encompass() {
+        let a = Position::new(10, 50);
+        let b = Position::new(10, 52);
+        let c = Position::new(11, 20);
+        let d = Position::new(12, 5);
+
+        let span_ab = Span::new(a, b);
+        let span_ad = Span::new(a, d);
+        let span_bc = Span::new(b, c);
+        let span_cd = Span::new(c, d);
+        let span_ac = Span::new(a, c);
+
+        // Adjacent spans.
+        assert_eq!(span_ab.encompass(span_bc), span_ac);
+        assert_eq!(span_bc.encompass(span_ab), span_ac);
+
+        // Disjoint spans.
+        assert_eq!(span_ab.encompass(span_cd), span_ad);
+        assert_eq!(span_cd.encompass(span_ab), span_ad);
+
+        // Contained spans and positions.
+        assert_eq!(span_ad.encompass(span_bc), span_ad);
+        assert_eq!(span_ab.encompass(a), span_ab);
+        assert_eq!(span_ab.encompass(d), span_ad);
+        assert_eq!(span_ab.encompass(span_ab), span_ab);
+    }
+
     /// Checks that the ordering of linear spans is correct.
     #[test]
     fn linear_span_ordering() {
@@ -718,6 +861,78 @@ mod tests {
         assert_eq!(span_cd.union(a), span_ad);
         assert_eq!(span_cd.union(span_bc), span_bd);
     }
+
+    /// Checks that synthetic spans are told apart from real spans, and ignored when encompassed.
+    #[test]
+    fn synthetic_span() {
+        let synthetic = Span::synthetic();
+        let real = Span::new(Position::new(1, 1), Position::new(1, 1));
+
+        assert!(synthetic.is_synthetic());
+        assert!(!real.is_synthetic());
+        assert_ne!(synthetic, real);
+        assert_eq!(synthetic.to_string(), "[synthetic]");
+
+        assert_eq!(synthetic.encompass(real), real);
+        assert_eq!(real.encompass(synthetic), real);
+        assert!(synthetic.encompass(synthetic).is_synthetic());
+    }
+
+    /// Checks that synthetic linear spans are ignored by unions, like spans are by `encompass`.
+    #[test]
+    fn synthetic_linear_span() {
+        let synthetic = LinearSpan::synthetic();
+        let real = LinearSpan::new(LinearPosition::new(3), LinearPosition::new(7));
+        let empty = LinearSpan::from(LinearPosition::new(10));
+
+        assert!(synthetic.is_synthetic());
+        assert!(!real.is_synthetic());
+        assert!(!LinearSpan::default().is_synthetic());
+
+        assert_eq!(synthetic.union(real), real);
+        assert_eq!(real.union(synthetic), real);
+        assert!(synthetic.union(synthetic).is_synthetic());
+        assert_eq!(
+            real.union(empty),
+            LinearSpan::new(LinearPosition::new(3), LinearPosition::new(10))
+        );
+    }
+
+    /// Checks the half-open bounds of `Span::contains_position`.
+    #[test]
+    fn span_contains_position() {
+        let span = Span::new(Position::new(2, 5), Position::new(3, 4));
+
+        assert!(span.contains_position(Position::new(2, 5)));
+        assert!(span.contains_position(Position::new(2, 80)));
+        assert!(span.contains_position(Position::new(3, 3)));
+        assert!(!span.contains_position(Position::new(3, 4)));
+        assert!(!span.contains_position(Position::new(2, 4)));
+        assert!(!span.contains_position(Position::new(4, 1)));
+
+        let empty = Span::new(Position::new(2, 5), Position::new(2, 5));
+        assert!(!empty.contains_position(Position::new(2, 5)));
+    }
+
+    /// Checks the half-open bounds of `Span::overlaps`.
+    #[test]
+    fn span_overlaps() {
+        let a = Span::new(Position::new(1, 1), Position::new(1, 10));
+        let b = Span::new(Position::new(1, 5), Position::new(2, 1));
+        let touching = Span::new(Position::new(1, 10), Position::new(1, 20));
+        let inner = Span::new(Position::new(1, 2), Position::new(1, 3));
+        let empty = Span::new(Position::new(1, 5), Position::new(1, 5));
+
+        assert!(a.overlaps(b));
+        assert!(b.overlaps(a));
+        assert!(a.overlaps(inner));
+        assert!(inner.overlaps(a));
+        assert!(a.overlaps(a));
+        assert!(!a.overlaps(touching));
+        assert!(!touching.overlaps(a));
+        assert!(!a.overlaps(empty));
+        assert!(!empty.overlaps(empty));
+    }
 }
 
 // TODO: union Span & LinearSpan into `SpanBase<T>` and then:
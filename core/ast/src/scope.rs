@@ -3,6 +3,7 @@
 //! Scopes are used to track the bindings of identifiers in the AST.
 
 use bitflags::bitflags;
+use boa_interner::{Interner, Sym};
 use boa_string::JsString;
 use std::{
     cell::{Cell, RefCell},
@@ -209,6 +210,19 @@ impl Scope {
         self.inner.bindings.borrow().len() as u32
     }
 
+    /// Returns the symbols of the names bound in this scope, in declaration order.
+    ///
+    /// Names that are not interned in the given [`Interner`] are skipped.
+    #[must_use]
+    pub fn binding_names(&self, interner: &Interner) -> Vec<Sym> {
+        self.inner
+            .bindings
+            .borrow()
+            .iter()
+            .filter_map(|binding| interner.get(&*binding.name.to_vec()))
+            .collect()
+    }
+
     /// Returns the number of bindings in this scope that are not local.
     #[must_use]
     #[allow(clippy::cast_possible_truncation)]
@@ -744,6 +758,42 @@ impl FunctionScopes {
         self.function_scope.clone()
     }
 
+    /// Returns the names bound in the [parameter scope][Self::parameter_scope] of this
+    /// function, in declaration order.
+    ///
+    /// This includes the implicit `arguments` binding if the function needs one. If the
+    /// parameters don't contain any expressions, `var` declarations of the body share this
+    /// scope with the parameters, so they are also part of the returned names.
+    #[must_use]
+    pub fn parameter_binding_names(&self, interner: &Interner) -> Vec<Sym> {
+        self.parameter_scope().binding_names(interner)
+    }
+
+    /// Returns the names bound in the scopes of the function body that are separate from the
+    /// [parameter scope][Self::parameter_scope], in declaration order.
+    ///
+    /// These are the `var` declarations when the parameters contain expressions, followed by
+    /// the lexical declarations of the body.
+    #[must_use]
+    pub fn body_binding_names(&self, interner: &Interner) -> Vec<Sym> {
+        let parameter_scope = self.parameter_scope();
+        let mut scopes = Vec::new();
+        let mut scope = Some(self.body_scope());
+        while let Some(current) = scope {
+            if Rc::ptr_eq(&current.inner, &parameter_scope.inner) {
+                break;
+            }
+            scope = current.outer();
+            scopes.push(current);
+        }
+
+        scopes
+            .iter()
+            .rev()
+            .flat_map(|scope| scope.binding_names(interner))
+            .collect()
+    }
+
     /// Marks all bindings in all scopes as escaping.
     pub(crate) fn escape_all_bindings(&self) {
         self.function_scope.escape_all_bindings();
@@ -30,7 +30,7 @@ use crate::{
     visitor::{NodeRef, NodeRefMut, VisitorMut},
 };
 use boa_interner::{Interner, Sym};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::ops::ControlFlow;
 
 /// Collect bindings and fill the scopes with them.
@@ -71,15 +71,42 @@ where
         direct_eval: in_eval,
         with: false,
         interner,
+        unresolved: None,
     };
     !visitor.visit(node.into()).is_break()
 }
 
+/// Collect the identifiers that cannot be resolved to a declarative binding.
+///
+/// The bindings of `node` must have been collected with [`collect_bindings`] in `scope`.
+/// Returns `None` if the analysis fails.
+pub(crate) fn collect_unresolved_identifiers<'a, N>(
+    node: &'a mut N,
+    scope: Scope,
+    interner: &Interner,
+) -> Option<FxHashSet<Sym>>
+where
+    &'a mut N: Into<NodeRefMut<'a>>,
+{
+    let mut visitor = BindingEscapeAnalyzer {
+        scope,
+        direct_eval: false,
+        with: false,
+        interner,
+        unresolved: Some(FxHashSet::default()),
+    };
+    if visitor.visit(node.into()).is_break() {
+        return None;
+    }
+    visitor.unresolved
+}
+
 struct BindingEscapeAnalyzer<'interner> {
     scope: Scope,
     direct_eval: bool,
     with: bool,
     interner: &'interner Interner,
+    unresolved: Option<FxHashSet<Sym>>,
 }
 
 impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
@@ -87,6 +114,14 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
 
     fn visit_identifier_mut(&mut self, node: &'ast mut Identifier) -> ControlFlow<Self::BreakTy> {
         let name = node.to_js_string(self.interner);
+        if let Some(unresolved) = &mut self.unresolved
+            && self
+                .scope
+                .get_identifier_reference(name.clone())
+                .is_global_object()
+        {
+            unresolved.insert(node.sym());
+        }
         self.scope
             .access_binding(&name, self.direct_eval || self.with);
         ControlFlow::Continue(())
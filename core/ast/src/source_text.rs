@@ -40,6 +40,14 @@ impl SourceText {
         self.source_text.pop();
     }
 
+    /// Shortens the source text, keeping the code points before `pos`.
+    ///
+    /// Has no effect if `pos` is past the current end.
+    #[inline]
+    pub fn truncate(&mut self, pos: LinearPosition) {
+        self.source_text.truncate(pos.pos());
+    }
+
     /// Collect code point.
     ///
     /// # Panics
@@ -1,6 +1,7 @@
 //! If statement
 
 use crate::{
+    Span, Spanned,
     expression::Expression,
     statement::Statement,
     visitor::{VisitWith, Visitor, VisitorMut},
@@ -31,6 +32,7 @@ pub struct If {
     condition: Expression,
     body: Box<Statement>,
     else_node: Option<Box<Statement>>,
+    span: Span,
 }
 
 impl If {
@@ -57,13 +59,73 @@ impl If {
     /// Creates an `If` AST node.
     #[inline]
     #[must_use]
-    pub fn new(condition: Expression, body: Statement, else_node: Option<Statement>) -> Self {
+    pub fn new(
+        condition: Expression,
+        body: Statement,
+        else_node: Option<Statement>,
+        span: Span,
+    ) -> Self {
         Self {
             condition,
             body: body.into(),
             else_node: else_node.map(Box::new),
+            span,
         }
     }
+
+    /// Creates a string of the `if` statement with every branch wrapped in braces.
+    ///
+    /// Unlike [`to_indented_string`](ToIndentedString::to_indented_string), which keeps
+    /// single-statement branches as written, this produces a canonical brace style:
+    /// `if (x) y();` is printed as `if (x) {\n    y();\n}`. `else if` chains are kept as-is,
+    /// with their branches normalized as well.
+    #[must_use]
+    pub fn to_normalized_string(&self, interner: &Interner, indentation: usize) -> String {
+        let mut buf = format!(
+            "if ({}) {}",
+            self.cond().to_interned_string(interner),
+            normalized_branch(self.body(), interner, indentation)
+        );
+        match self.else_node() {
+            Some(Statement::If(else_if)) => {
+                let _ = write!(
+                    buf,
+                    " else {}",
+                    else_if.to_normalized_string(interner, indentation)
+                );
+            }
+            Some(else_e) => {
+                let _ = write!(
+                    buf,
+                    " else {}",
+                    normalized_branch(else_e, interner, indentation)
+                );
+            }
+            None => {}
+        }
+        buf
+    }
+}
+
+/// Formats a branch of an `if` statement, wrapping it in a block if it isn't one already.
+fn normalized_branch(branch: &Statement, interner: &Interner, indentation: usize) -> String {
+    let inner = match branch {
+        Statement::Block(block) => return block.to_indented_string(interner, indentation),
+        Statement::If(if_stm) => format!(
+            "{}{}",
+            "    ".repeat(indentation + 1),
+            if_stm.to_normalized_string(interner, indentation + 1)
+        ),
+        _ => branch.to_indented_string(interner, indentation + 1),
+    };
+    format!("{{\n{inner}\n{}}}", "    ".repeat(indentation))
+}
+
+impl Spanned for If {
+    #[inline]
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
 impl ToIndentedString for If {
@@ -440,6 +440,258 @@ pub trait Visitor<'ast>: Sized {
     }
 }
 
+/// Invokes the given macro with the list of visit methods of [`Visitor`] and their node types.
+macro_rules! with_visit_methods {
+    ($macro:ident) => {
+        $macro! {
+            visit_script, Script;
+            visit_module, Module;
+            visit_function_body, FunctionBody;
+            visit_statement_list, StatementList;
+            visit_statement_list_item, StatementListItem;
+            visit_statement, Statement;
+            visit_declaration, Declaration;
+            visit_function_expression, FunctionExpression;
+            visit_function_declaration, FunctionDeclaration;
+            visit_generator_expression, GeneratorExpression;
+            visit_generator_declaration, GeneratorDeclaration;
+            visit_async_function_expression, AsyncFunctionExpression;
+            visit_async_function_declaration, AsyncFunctionDeclaration;
+            visit_async_generator_expression, AsyncGeneratorExpression;
+            visit_async_generator_declaration, AsyncGeneratorDeclaration;
+            visit_class_expression, ClassExpression;
+            visit_class_declaration, ClassDeclaration;
+            visit_lexical_declaration, LexicalDeclaration;
+            visit_block, Block;
+            visit_var_declaration, VarDeclaration;
+            visit_expression, Expression;
+            visit_if, If;
+            visit_do_while_loop, DoWhileLoop;
+            visit_while_loop, WhileLoop;
+            visit_for_loop, ForLoop;
+            visit_for_in_loop, ForInLoop;
+            visit_for_of_loop, ForOfLoop;
+            visit_switch, Switch;
+            visit_continue, Continue;
+            visit_break, Break;
+            visit_return, Return;
+            visit_labelled, Labelled;
+            visit_throw, Throw;
+            visit_try, Try;
+            visit_with, With;
+            visit_this, This;
+            visit_identifier, Identifier;
+            visit_formal_parameter_list, FormalParameterList;
+            visit_class_element, ClassElement;
+            visit_private_name, PrivateName;
+            visit_variable_list, VariableList;
+            visit_variable, Variable;
+            visit_binding, Binding;
+            visit_pattern, Pattern;
+            visit_literal, Literal;
+            visit_reg_exp_literal, RegExpLiteral;
+            visit_array_literal, ArrayLiteral;
+            visit_object_literal, ObjectLiteral;
+            visit_spread, Spread;
+            visit_arrow_function, ArrowFunction;
+            visit_async_arrow_function, AsyncArrowFunction;
+            visit_template_literal, TemplateLiteral;
+            visit_property_access, PropertyAccess;
+            visit_new, New;
+            visit_call, Call;
+            visit_super_call, SuperCall;
+            visit_import_call, ImportCall;
+            visit_optional, Optional;
+            visit_tagged_template, TaggedTemplate;
+            visit_assign, Assign;
+            visit_unary, Unary;
+            visit_update, Update;
+            visit_binary, Binary;
+            visit_binary_in_private, BinaryInPrivate;
+            visit_conditional, Conditional;
+            visit_await, Await;
+            visit_yield, Yield;
+            visit_parenthesized, Parenthesized;
+            visit_new_target, NewTarget;
+            visit_import_meta, ImportMeta;
+            visit_for_loop_initializer, ForLoopInitializer;
+            visit_iterable_loop_initializer, IterableLoopInitializer;
+            visit_case, Case;
+            visit_sym, Sym;
+            visit_labelled_item, LabelledItem;
+            visit_catch, Catch;
+            visit_finally, Finally;
+            visit_formal_parameter, FormalParameter;
+            visit_property_name, PropertyName;
+            visit_object_method_definition, ObjectMethodDefinition;
+            visit_object_pattern, ObjectPattern;
+            visit_array_pattern, ArrayPattern;
+            visit_property_definition, PropertyDefinition;
+            visit_template_element, TemplateElement;
+            visit_simple_property_access, SimplePropertyAccess;
+            visit_private_property_access, PrivatePropertyAccess;
+            visit_super_property_access, SuperPropertyAccess;
+            visit_optional_operation, OptionalOperation;
+            visit_assign_target, AssignTarget;
+            visit_object_pattern_element, ObjectPatternElement;
+            visit_array_pattern_element, ArrayPatternElement;
+            visit_property_access_field, PropertyAccessField;
+            visit_optional_operation_kind, OptionalOperationKind;
+            visit_module_item_list, ModuleItemList;
+            visit_module_item, ModuleItem;
+            visit_module_specifier, ModuleSpecifier;
+            visit_import_kind, ImportKind;
+            visit_import_declaration, ImportDeclaration;
+            visit_import_specifier, ImportSpecifier;
+            visit_re_export_kind, ReExportKind;
+            visit_export_declaration, ExportDeclaration;
+            visit_export_specifier, ExportSpecifier;
+        }
+    };
+}
+
+/// Generates the visit function implementations of visitors wrapping the default traversal of
+/// every node between an `enter` and a `leave` method, like [`PathTrackingVisitor`].
+macro_rules! define_enter_leave_visit {
+    ($($fn_name:ident, $type_name:ident);* $(;)?) => {
+        $(
+            fn $fn_name(&mut self, node: &'ast $type_name) -> ControlFlow<Self::BreakTy> {
+                self.enter(node.into())?;
+                let result = node.visit_with(self);
+                self.leave(node.into(), result)
+            }
+        )*
+    };
+}
+
+/// A [`Visitor`] that keeps track of the path of nodes enclosing the visited node.
+///
+/// The callback is called for every visited node, together with the chain of its ancestors,
+/// from the outermost to the innermost. Returning [`ControlFlow::Break`] from the callback stops
+/// the traversal, and the break value is propagated to the caller.
+///
+/// This works for any node implementing [`VisitWith`], since it only relies on the default
+/// traversal of each node.
+pub struct PathTrackingVisitor<'ast, F> {
+    path: Vec<NodeRef<'ast>>,
+    on_node: F,
+}
+
+impl<F> std::fmt::Debug for PathTrackingVisitor<'_, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PathTrackingVisitor")
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'ast, F, B> PathTrackingVisitor<'ast, F>
+where
+    F: FnMut(NodeRef<'ast>, &[NodeRef<'ast>]) -> ControlFlow<B>,
+{
+    /// Creates a new `PathTrackingVisitor` calling `on_node` with each visited node and its ancestors.
+    pub const fn new(on_node: F) -> Self {
+        Self {
+            path: Vec::new(),
+            on_node,
+        }
+    }
+
+    fn enter(&mut self, node: NodeRef<'ast>) -> ControlFlow<B> {
+        (self.on_node)(node, &self.path)?;
+        self.path.push(node);
+        ControlFlow::Continue(())
+    }
+
+    fn leave(&mut self, _node: NodeRef<'ast>, result: ControlFlow<B>) -> ControlFlow<B> {
+        self.path.pop();
+        result
+    }
+}
+
+impl<'ast, F, B> Visitor<'ast> for PathTrackingVisitor<'ast, F>
+where
+    F: FnMut(NodeRef<'ast>, &[NodeRef<'ast>]) -> ControlFlow<B>,
+{
+    type BreakTy = B;
+
+    with_visit_methods!(define_enter_leave_visit);
+}
+
+/// Finds the first node within `node` (including `node` itself) matching `predicate`, returning the
+/// path of nodes leading to it, from `node` to the matching node.
+///
+/// The traversal stops as soon as a matching node is found.
+pub fn find_node_path<'ast, N, P>(node: N, mut predicate: P) -> Option<Vec<NodeRef<'ast>>>
+where
+    N: Into<NodeRef<'ast>>,
+    P: FnMut(NodeRef<'ast>) -> bool,
+{
+    let mut visitor = PathTrackingVisitor::new(|node, path: &[NodeRef<'ast>]| {
+        if predicate(node) {
+            let mut path = path.to_vec();
+            path.push(node);
+            ControlFlow::Break(path)
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+    match visitor.visit(node) {
+        ControlFlow::Break(path) => Some(path),
+        ControlFlow::Continue(()) => None,
+    }
+}
+
+/// A [`Visitor`] calling `on_enter` before and `on_leave` after visiting the children of each node.
+struct CallbackVisitor<E, L> {
+    on_enter: E,
+    on_leave: L,
+}
+
+impl<E, L> CallbackVisitor<E, L> {
+    fn enter<'ast, B>(&mut self, node: NodeRef<'ast>) -> ControlFlow<B>
+    where
+        E: FnMut(NodeRef<'ast>) -> ControlFlow<B>,
+    {
+        (self.on_enter)(node)
+    }
+
+    fn leave<'ast, B>(&mut self, node: NodeRef<'ast>, result: ControlFlow<B>) -> ControlFlow<B>
+    where
+        L: FnMut(NodeRef<'ast>) -> ControlFlow<B>,
+    {
+        result?;
+        (self.on_leave)(node)
+    }
+}
+
+impl<'ast, E, L, B> Visitor<'ast> for CallbackVisitor<E, L>
+where
+    E: FnMut(NodeRef<'ast>) -> ControlFlow<B>,
+    L: FnMut(NodeRef<'ast>) -> ControlFlow<B>,
+{
+    type BreakTy = B;
+
+    with_visit_methods!(define_enter_leave_visit);
+}
+
+/// Walks `node` and all of its descendants in depth-first order, calling `on_enter` when a node is
+/// reached and `on_leave` once all of its children have been visited.
+///
+/// Both callbacks receive a [`NodeRef`] tagging the kind of the node, so simple traversals can be
+/// written as a single `match` instead of implementing every method of [`Visitor`]. Returning
+/// [`ControlFlow::Break`] from either callback stops the traversal, and the break value is
+/// returned. `on_leave` is not called for the nodes that were being visited when the traversal
+/// stopped.
+pub fn walk_with_callbacks<'ast, N, E, L, B>(node: N, on_enter: E, on_leave: L) -> ControlFlow<B>
+where
+    N: Into<NodeRef<'ast>>,
+    E: FnMut(NodeRef<'ast>) -> ControlFlow<B>,
+    L: FnMut(NodeRef<'ast>) -> ControlFlow<B>,
+{
+    CallbackVisitor { on_enter, on_leave }.visit(node)
+}
+
 /// Represents an AST visitor which can modify AST content.
 ///
 /// This implementation is based largely on [chalk](https://github.com/rust-lang/chalk/blob/23d39c90ceb9242fbd4c43e9368e813e7c2179f7/chalk-ir/src/visit.rs)'s
@@ -705,3 +957,140 @@ impl VisitWith for Sym {
         ControlFlow::Continue(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::ops::ControlFlow;
+
+    use super::{NodeRef, PathTrackingVisitor, Visitor, find_node_path, walk_with_callbacks};
+    use crate::{
+        Span, Statement, StatementListItem,
+        expression::{Await, Identifier},
+        statement::{Block, WhileLoop},
+    };
+    use boa_interner::Sym;
+
+    /// Builds `await a; while (true) { await b; }`.
+    fn awaits_in_loop() -> Vec<StatementListItem> {
+        let await_expr = |name| {
+            Statement::Expression(
+                Await::new(
+                    Box::new(Identifier::new(name, Span::EMPTY).into()),
+                    Span::EMPTY,
+                    Span::EMPTY,
+                )
+                .into(),
+            )
+        };
+        let body = Block::from((
+            vec![await_expr(Sym::NAME).into()],
+            crate::LinearPosition::default(),
+        ));
+        vec![
+            await_expr(Sym::ARGUMENTS).into(),
+            Statement::WhileLoop(WhileLoop::new(
+                Identifier::new(Sym::TRUE, Span::EMPTY).into(),
+                body.into(),
+            ))
+            .into(),
+        ]
+    }
+
+    #[test]
+    fn path_tracking_visitor_reports_ancestors() {
+        let items = awaits_in_loop();
+        let mut awaits = Vec::new();
+        let mut visitor = PathTrackingVisitor::new(|node, path: &[NodeRef<'_>]| {
+            if let NodeRef::Await(node) = node {
+                let in_loop = path
+                    .iter()
+                    .any(|ancestor| matches!(ancestor, NodeRef::WhileLoop(_)));
+                awaits.push((node.target().clone(), in_loop));
+            }
+            ControlFlow::<()>::Continue(())
+        });
+        for item in &items {
+            assert!(visitor.visit_statement_list_item(item).is_continue());
+        }
+
+        assert_eq!(
+            awaits,
+            [
+                (Identifier::new(Sym::ARGUMENTS, Span::EMPTY).into(), false),
+                (Identifier::new(Sym::NAME, Span::EMPTY).into(), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn find_node_path_stops_at_first_match() {
+        let items = awaits_in_loop();
+        let path = find_node_path(&items[1], |node| matches!(node, NodeRef::Await(_)))
+            .expect("await should be found");
+
+        assert!(matches!(path.first(), Some(NodeRef::StatementListItem(_))));
+        assert!(matches!(path.last(), Some(NodeRef::Await(_))));
+        assert!(
+            path.iter()
+                .any(|node| matches!(node, NodeRef::WhileLoop(_)))
+        );
+        assert!(path.iter().any(|node| matches!(node, NodeRef::Block(_))));
+
+        assert!(find_node_path(&items[1], |node| matches!(node, NodeRef::Yield(_))).is_none());
+    }
+
+    #[test]
+    fn walk_with_callbacks_counts_node_kinds() {
+        let items = awaits_in_loop();
+        let (mut awaits, mut loops, mut identifiers, mut blocks) = (0, 0, 0, 0);
+        let (mut entered, mut left) = (0, 0);
+        for item in &items {
+            let result = walk_with_callbacks(
+                item,
+                |node| {
+                    entered += 1;
+                    match node {
+                        NodeRef::Await(_) => awaits += 1,
+                        NodeRef::WhileLoop(_) => loops += 1,
+                        NodeRef::Identifier(_) => identifiers += 1,
+                        NodeRef::Block(_) => blocks += 1,
+                        _ => {}
+                    }
+                    ControlFlow::<()>::Continue(())
+                },
+                |_| {
+                    left += 1;
+                    ControlFlow::Continue(())
+                },
+            );
+            assert!(result.is_continue());
+        }
+
+        assert_eq!((awaits, loops, identifiers, blocks), (2, 1, 3, 1));
+        assert_eq!(entered, left);
+    }
+
+    #[test]
+    fn walk_with_callbacks_stops_on_break() {
+        let items = awaits_in_loop();
+        let mut left = Vec::new();
+        let result = walk_with_callbacks(
+            &items[1],
+            |node| match node {
+                NodeRef::Await(_) => ControlFlow::Break("await"),
+                _ => ControlFlow::Continue(()),
+            },
+            |node| {
+                left.push(node);
+                ControlFlow::Continue(())
+            },
+        );
+
+        assert_eq!(result, ControlFlow::Break("await"));
+        assert!(
+            !left
+                .iter()
+                .any(|node| matches!(node, NodeRef::WhileLoop(_) | NodeRef::Block(_)))
+        );
+    }
+}
@@ -979,6 +979,7 @@ pub(crate) fn function_call(
     context: &mut Context,
 ) -> JsResult<CallValue> {
     context.check_runtime_limits()?;
+    context.charge_tick_budget()?;
 
     let function = function_object
         .downcast_ref::<OrdinaryFunction>()
@@ -1067,6 +1068,7 @@ fn function_construct(
     context: &mut Context,
 ) -> JsResult<CallValue> {
     context.check_runtime_limits()?;
+    context.charge_tick_budget()?;
 
     let function = this_function_object
         .downcast_ref::<OrdinaryFunction>()
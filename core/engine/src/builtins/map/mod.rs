@@ -557,13 +557,6 @@ impl Map {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        use std::hash::BuildHasherDefault;
-
-        use indexmap::IndexMap;
-        use rustc_hash::FxHasher;
-
-        use crate::builtins::{Array, Number, iterable::if_abrupt_close_iterator};
-
         let items = args.get_or_undefined(0);
         let callback = args.get_or_undefined(1);
         // 1. Let groups be ? GroupBy(items, callbackfn, zero).
@@ -580,6 +573,35 @@ impl Map {
             JsNativeError::typ().with_message("callback must be a callable object")
         })?;
 
+        Self::group_by_with(
+            items,
+            |value, k, context| {
+                // e. Let key be Completion(Call(callbackfn, undefined, « value, 𝔽(k) »)).
+                callback.call(&JsValue::undefined(), &[value.clone(), k.into()], context)
+            },
+            context,
+        )
+    }
+
+    /// Groups the elements of `items` into a new `Map`, keyed by the result of `key_fn`.
+    ///
+    /// This performs the steps of `Map.groupBy` after validating its arguments. `key_fn` is called
+    /// with each element and its index.
+    pub(crate) fn group_by_with<F>(
+        items: &JsValue,
+        mut key_fn: F,
+        context: &mut Context,
+    ) -> JsResult<JsValue>
+    where
+        F: FnMut(&JsValue, u64, &mut Context) -> JsResult<JsValue>,
+    {
+        use std::hash::BuildHasherDefault;
+
+        use indexmap::IndexMap;
+        use rustc_hash::FxHasher;
+
+        use crate::builtins::{Array, Number, iterable::if_abrupt_close_iterator};
+
         // 3. Let groups be a new empty List.
         let mut groups: IndexMap<JsValue, Vec<JsValue>, BuildHasherDefault<FxHasher>> =
             IndexMap::default();
@@ -614,7 +636,7 @@ impl Map {
             let value = next;
 
             // e. Let key be Completion(Call(callbackfn, undefined, « value, 𝔽(k) »)).
-            let key = callback.call(&JsValue::undefined(), &[value.clone(), k.into()], context);
+            let key = key_fn(&value, k, context);
 
             // f. IfAbruptCloseIterator(key, iteratorRecord).
             let mut key = if_abrupt_close_iterator!(key, iterator, context);
@@ -186,6 +186,17 @@ impl<V> OrderedMap<V> {
         })
     }
 
+    /// Return an iterator over mutable references to the values of the map, in their order
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.map.iter_mut().filter_map(|o| {
+            if let (MapKey::Key(_), Some(value)) = o {
+                Some(value)
+            } else {
+                None
+            }
+        })
+    }
+
     /// Return `true` if an equivalent to `key` exists in the map.
     ///
     /// Computes in **O(1)** time (average).
@@ -1,6 +1,12 @@
-use crate::{JsNativeErrorKind, JsValue, TestAction, run_test_actions};
+use crate::{
+    JsBigInt, JsNativeErrorKind, JsObject, JsSymbol, JsValue, Source, TestAction,
+    assert_insertion_order, js_string,
+    object::builtins::{JsMap, MapMutation},
+    run_test_actions,
+};
 use boa_macros::js_str;
 use indoc::indoc;
+use std::{cell::RefCell, ops::ControlFlow, rc::Rc};
 
 #[test]
 fn construct() {
@@ -305,3 +311,444 @@ fn for_of_delete() {
             "#}),
     ]);
 }
+
+#[test]
+fn reinsert_after_delete_appends() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let map = JsMap::new(context);
+        for (key, value) in [("a", 1), ("b", 2), ("c", 3)] {
+            map.set(js_string!(key), value, context).unwrap();
+        }
+        assert_insertion_order(
+            &map,
+            &[
+                js_str!("a").into(),
+                js_str!("b").into(),
+                js_str!("c").into(),
+            ],
+            context,
+        );
+
+        // Overwriting an existing key keeps its position.
+        map.set(js_string!("a"), 10, context).unwrap();
+        assert_insertion_order(
+            &map,
+            &[
+                js_str!("a").into(),
+                js_str!("b").into(),
+                js_str!("c").into(),
+            ],
+            context,
+        );
+
+        // Re-inserting a deleted key appends it at the end.
+        map.delete(js_string!("a"), context).unwrap();
+        assert_insertion_order(&map, &[js_str!("b").into(), js_str!("c").into()], context);
+        map.set(js_string!("a"), 1, context).unwrap();
+        map.set(-0.0, 0, context).unwrap();
+        map.delete(js_string!("c"), context).unwrap();
+        map.set(js_string!("c"), 3, context).unwrap();
+        assert_insertion_order(
+            &map,
+            &[
+                js_str!("b").into(),
+                js_str!("a").into(),
+                0.into(),
+                js_str!("c").into(),
+            ],
+            context,
+        );
+    })]);
+}
+
+#[test]
+fn try_for_each_native_break() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let map = JsMap::new(context);
+        for (key, value) in [("a", 1), ("b", 2), ("c", 3), ("d", 4)] {
+            map.set(js_string!(key), value, context).unwrap();
+        }
+
+        let mut visited = Vec::new();
+        map.try_for_each_native(|key, value| {
+            visited.push((key, value));
+            if visited.len() == 2 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .unwrap();
+        assert_eq!(
+            visited,
+            [
+                (js_str!("a").into(), JsValue::new(1)),
+                (js_str!("b").into(), JsValue::new(2)),
+            ]
+        );
+
+        // Breaking leaves the map untouched.
+        assert_eq!(map.get_size(context).unwrap(), JsValue::new(4));
+        assert_insertion_order(
+            &map,
+            &[
+                js_str!("a").into(),
+                js_str!("b").into(),
+                js_str!("c").into(),
+                js_str!("d").into(),
+            ],
+            context,
+        );
+
+        // Entries deleted during the iteration are skipped.
+        let mut visited = Vec::new();
+        map.try_for_each_native(|key, _| {
+            if visited.is_empty() {
+                map.pop(js_string!("b"), context).unwrap();
+            }
+            visited.push(key);
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+        assert_eq!(
+            visited,
+            [
+                js_str!("a").into(),
+                js_str!("c").into(),
+                js_str!("d").into()
+            ]
+        );
+    })]);
+}
+
+#[test]
+fn keys_matching_threshold() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let map = JsMap::new(context);
+        for (key, value) in [("a", 5), ("b", 12), ("c", 7), ("d", 20)] {
+            map.set(js_string!(key), value, context).unwrap();
+        }
+        map.set(js_string!("e"), js_string!("30"), context).unwrap();
+
+        let keys = map
+            .keys_matching(
+                |_, value| value.as_number().is_some_and(|n| n > 6.0),
+                context,
+            )
+            .unwrap();
+        assert_eq!(
+            keys,
+            [
+                JsValue::from(js_str!("b")),
+                js_str!("c").into(),
+                js_str!("d").into(),
+            ]
+        );
+
+        // The map is left untouched.
+        assert_eq!(map.get_size(context).unwrap(), JsValue::new(5));
+        assert_eq!(map.get(js_str!("b"), context).unwrap(), JsValue::new(12));
+    })]);
+}
+
+#[test]
+fn count_where_truthy_values() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let map = JsMap::new(context);
+        map.set(js_string!("a"), 1, context).unwrap();
+        map.set(js_string!("b"), 0, context).unwrap();
+        map.set(js_string!("c"), js_string!("x"), context).unwrap();
+        map.set(js_string!("d"), js_string!(), context).unwrap();
+        map.set(js_string!("e"), JsValue::undefined(), context)
+            .unwrap();
+        map.set(js_string!("f"), true, context).unwrap();
+        map.delete(js_str!("f"), context).unwrap();
+
+        let count = map
+            .count_where(|_, value| value.to_boolean(), context)
+            .unwrap();
+        assert_eq!(count, 2);
+
+        // The map is left untouched.
+        assert_eq!(map.get_size(context).unwrap(), JsValue::new(5));
+    })]);
+}
+
+#[test]
+fn sum_values() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let map = JsMap::new(context);
+        assert_eq!(map.sum_values(context).unwrap().to_bits(), 0.0f64.to_bits());
+
+        map.set(js_string!("a"), 1, context).unwrap();
+        map.set(js_string!("b"), 2.5, context).unwrap();
+        map.set(js_string!("c"), js_string!("3"), context).unwrap();
+        map.set(js_string!("d"), true, context).unwrap();
+        assert_eq!(map.sum_values(context).unwrap().to_bits(), 7.5f64.to_bits());
+    })]);
+}
+
+#[test]
+fn sum_values_propagates_nan() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let map = JsMap::new(context);
+        map.set(js_string!("a"), 1, context).unwrap();
+        map.set(js_string!("b"), JsValue::undefined(), context)
+            .unwrap();
+        assert!(map.sum_values(context).unwrap().is_nan());
+    })]);
+}
+
+#[test]
+fn sum_values_non_numeric() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let symbol = JsMap::new(context);
+        symbol.set(js_string!("a"), 1, context).unwrap();
+        symbol
+            .set(js_string!("b"), JsSymbol::new(None).unwrap(), context)
+            .unwrap();
+        assert!(symbol.sum_values(context).is_err());
+
+        let bigint = JsMap::new(context);
+        bigint
+            .set(js_string!("a"), JsBigInt::from(1), context)
+            .unwrap();
+        assert!(bigint.sum_values(context).is_err());
+    })]);
+}
+
+#[test]
+fn with_capacity_grows() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let map = JsMap::with_capacity(2, context);
+        assert_eq!(map.get_size(context).unwrap(), JsValue::new(0));
+
+        for i in 0..10 {
+            map.set(i, i * 2, context).unwrap();
+        }
+        map.delete(3, context).unwrap();
+
+        assert_eq!(map.get_size(context).unwrap(), JsValue::new(9));
+        assert_eq!(map.get(9, context).unwrap(), JsValue::new(18));
+        assert_eq!(map.get(3, context).unwrap(), JsValue::undefined());
+        let expected: Vec<JsValue> = [0, 1, 2, 4, 5, 6, 7, 8, 9].map(JsValue::new).into();
+        assert_insertion_order(&map, &expected, context);
+    })]);
+}
+
+#[test]
+fn set_default_value_keeps_order() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let map = JsMap::new(context);
+        for (key, value) in [("c", 1), ("a", 2), ("b", 3)] {
+            map.set(js_string!(key), value, context).unwrap();
+        }
+        map.delete(js_str!("a"), context).unwrap();
+        map.set(js_string!("a"), 4, context).unwrap();
+
+        map.set_default_value(false, context).unwrap();
+
+        assert_eq!(map.get_size(context).unwrap(), JsValue::new(3));
+        for key in ["a", "b", "c"] {
+            assert_eq!(
+                map.get(js_string!(key), context).unwrap(),
+                JsValue::new(false)
+            );
+        }
+        assert_insertion_order(
+            &map,
+            &[
+                js_str!("c").into(),
+                js_str!("b").into(),
+                js_str!("a").into(),
+            ],
+            context,
+        );
+    })]);
+}
+
+#[test]
+fn collect_remaining_keys() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let map = JsMap::new(context);
+        for (key, value) in [("a", 1), ("b", 2), ("c", 3)] {
+            map.set(js_string!(key), value, context).unwrap();
+        }
+
+        let keys = map.keys(context).unwrap();
+        assert_eq!(
+            keys.next(context)
+                .unwrap()
+                .as_object()
+                .unwrap()
+                .get(js_string!("value"), context)
+                .unwrap(),
+            JsValue::from(js_str!("a"))
+        );
+        assert_eq!(
+            keys.collect_remaining(context).unwrap(),
+            [JsValue::from(js_str!("b")), js_str!("c").into()]
+        );
+
+        let keys = map.keys(context).unwrap();
+        assert_eq!(
+            keys.collect_remaining(context).unwrap(),
+            [
+                JsValue::from(js_str!("a")),
+                js_str!("b").into(),
+                js_str!("c").into()
+            ]
+        );
+    })]);
+}
+
+#[test]
+fn from_plain_object_copies_own_enumerable_properties() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let object = context
+            .eval(Source::from_bytes(indoc! {r#"
+                const object = Object.create({ inherited: 0 });
+                object.b = 1;
+                object[Symbol.for("s")] = 2;
+                object.a = 3;
+                object[7] = 4;
+                Object.defineProperty(object, "hidden", { value: 5, enumerable: false });
+                object
+            "#}))
+            .unwrap();
+        let object = object.as_object().unwrap().clone();
+
+        let map = JsMap::from_plain_object(&object, context).unwrap();
+
+        let symbol = context.eval(Source::from_bytes("Symbol.for('s')")).unwrap();
+        let expected = [
+            js_string!("7").into(),
+            js_string!("b").into(),
+            js_string!("a").into(),
+            symbol.clone(),
+        ];
+        assert_insertion_order(&map, &expected, context);
+        assert_eq!(map.get(symbol, context).unwrap(), JsValue::new(2));
+        assert_eq!(map.get(js_str!("7"), context).unwrap(), JsValue::new(4));
+    })]);
+}
+
+#[test]
+fn observe_reports_every_mutation() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let map = JsMap::new(context);
+        let early_clone = map.clone();
+        let mutations = Rc::new(RefCell::new(Vec::new()));
+        let log = mutations.clone();
+        map.observe(move |mutation| log.borrow_mut().push(mutation), context)
+            .unwrap();
+
+        map.set(js_str!("a"), 1, context).unwrap();
+        map.clone().set(-0.0, 2, context).unwrap();
+        assert!(!map.try_insert(js_str!("a"), 3, context).unwrap());
+        map.increment(js_str!("a"), 1.0, context).unwrap();
+        map.compute(js_str!("b"), |_| Some(JsValue::new(4)), context)
+            .unwrap();
+        map.compute(js_str!("missing"), |_| None, context).unwrap();
+        map.compute_if_present(js_str!("b"), |_| None, context)
+            .unwrap();
+        map.pop(js_str!("a"), context).unwrap();
+        map.delete(0, context).unwrap();
+        map.delete(js_str!("missing"), context).unwrap();
+
+        let other = JsMap::new(context);
+        other.set(js_str!("c"), 5, context).unwrap();
+        map.merge(&other, |_, current, _| current, context).unwrap();
+        map.set_default_value(6, context).unwrap();
+        map.clear(context).unwrap();
+
+        // Every handle to the map shares the observers, even if it was made before the callback
+        // was installed.
+        early_clone.set(js_str!("d"), 7, context).unwrap();
+        JsMap::from_object(map.clone().into())
+            .unwrap()
+            .set(js_str!("e"), 8, context)
+            .unwrap();
+
+        // Mutations made from JavaScript and mutations of other maps are not reported.
+        let object = JsObject::from(map.clone());
+        context
+            .global_object()
+            .set(js_str!("observed"), object, false, context)
+            .unwrap();
+        context
+            .eval(Source::from_bytes("observed.set('f', 9)"))
+            .unwrap();
+        JsMap::new(context).set(js_str!("g"), 10, context).unwrap();
+
+        assert_eq!(
+            *mutations.borrow(),
+            [
+                MapMutation::Set {
+                    key: js_str!("a").into(),
+                    value: JsValue::new(1),
+                },
+                MapMutation::Set {
+                    key: JsValue::new(0),
+                    value: JsValue::new(2),
+                },
+                MapMutation::Set {
+                    key: js_str!("a").into(),
+                    value: JsValue::new(2),
+                },
+                MapMutation::Set {
+                    key: js_str!("b").into(),
+                    value: JsValue::new(4),
+                },
+                MapMutation::Delete {
+                    key: js_str!("b").into(),
+                },
+                MapMutation::Delete {
+                    key: js_str!("a").into(),
+                },
+                MapMutation::Delete {
+                    key: JsValue::new(0),
+                },
+                MapMutation::Set {
+                    key: js_str!("c").into(),
+                    value: JsValue::new(5),
+                },
+                MapMutation::Set {
+                    key: js_str!("c").into(),
+                    value: JsValue::new(6),
+                },
+                MapMutation::Clear,
+                MapMutation::Set {
+                    key: js_str!("d").into(),
+                    value: JsValue::new(7),
+                },
+                MapMutation::Set {
+                    key: js_str!("e").into(),
+                    value: JsValue::new(8),
+                },
+            ]
+        );
+    })]);
+}
+
+#[test]
+fn observers_are_dropped_with_the_map() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let token = Rc::new(());
+        let captured = token.clone();
+        JsMap::new(context)
+            .observe(
+                move |_| {
+                    let _ = &captured;
+                },
+                context,
+            )
+            .unwrap();
+        assert_eq!(Rc::strong_count(&token), 2);
+
+        // The observers of collected maps are dropped the next time a map is observed.
+        boa_gc::force_collect();
+        JsMap::new(context).observe(|_| {}, context).unwrap();
+        assert_eq!(Rc::strong_count(&token), 1);
+    })]);
+}
@@ -1,4 +1,14 @@
-use crate::{TestAction, run_test_actions};
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    Context, JsNativeError, JsValue, TestAction,
+    builtins::promise::PromiseState,
+    context::{ContextBuilder, time::FixedClock},
+    job::{Job, NativeAsyncJob, NativeJob, PromiseJob, TimeoutJob},
+    js_string,
+    object::builtins::JsPromise,
+    run_test_actions,
+};
 use indoc::indoc;
 
 #[test]
@@ -15,5 +25,124 @@ fn promise() {
         TestAction::assert_eq("count", 2),
         TestAction::inspect_context(|ctx| ctx.run_jobs().unwrap()),
         TestAction::assert_eq("count", 3),
+        TestAction::assert_no_pending_jobs(),
+    ]);
+}
+
+#[test]
+#[should_panic(expected = "found 1 pending jobs")]
+fn assert_no_pending_jobs_fails_on_queued_job() {
+    run_test_actions([
+        TestAction::run("Promise.resolve().then(() => {});"),
+        TestAction::assert_no_pending_jobs(),
+    ]);
+}
+
+#[test]
+fn promise_state_after_jobs() {
+    fn state_of(name: &str, ctx: &mut Context) -> PromiseState {
+        let promise = ctx
+            .global_object()
+            .get(js_string!(name), ctx)
+            .unwrap()
+            .as_object()
+            .and_then(|obj| JsPromise::from_object(obj).ok())
+            .expect("value must be a promise");
+        promise.state()
+    }
+
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                    var fulfilled = Promise.resolve(1).then((x) => x + 1);
+                    var rejected = Promise.resolve().then(() => { throw 5; });
+                "#}),
+        TestAction::inspect_context(|ctx| {
+            assert_eq!(state_of("fulfilled", ctx), PromiseState::Pending);
+            assert_eq!(state_of("rejected", ctx), PromiseState::Pending);
+        }),
+        TestAction::inspect_context(|ctx| ctx.run_jobs().unwrap()),
+        TestAction::inspect_context(|ctx| {
+            assert_eq!(
+                state_of("fulfilled", ctx),
+                PromiseState::Fulfilled(2.into())
+            );
+            assert_eq!(state_of("rejected", ctx), PromiseState::Rejected(5.into()));
+        }),
     ]);
 }
+
+#[test]
+fn run_jobs_limited() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+                    var log = [];
+                    const p = Promise.resolve();
+                    p.then(() => log.push(1));
+                    p.then(() => log.push(2));
+                    p.then(() => log.push(3)).then(() => log.push(4));
+                "#}),
+        TestAction::inspect_context(|ctx| assert_eq!(ctx.run_jobs_limited(2).unwrap(), 2)),
+        TestAction::assert("arrayEquals(log, [1, 2])"),
+        // The job enqueued by the third callback counts toward the limit.
+        TestAction::inspect_context(|ctx| assert_eq!(ctx.run_jobs_limited(2).unwrap(), 2)),
+        TestAction::assert("arrayEquals(log, [1, 2, 3, 4])"),
+        TestAction::inspect_context(|ctx| assert_eq!(ctx.run_jobs_limited(2).unwrap(), 0)),
+    ]);
+}
+
+#[test]
+fn run_jobs_limited_keeps_queue_order() {
+    run_test_actions([TestAction::inspect_context(|ctx| {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let push = |log: &Rc<RefCell<Vec<&'static str>>>, entry| {
+            let log = log.clone();
+            move |_: &mut Context| {
+                log.borrow_mut().push(entry);
+                Ok(JsValue::undefined())
+            }
+        };
+
+        // The async job enqueued by the first promise job only runs once the promise job queue
+        // is empty, as it does with `run_jobs`.
+        let async_log = log.clone();
+        let first = push(&log, "promise 1");
+        ctx.enqueue_job(Job::PromiseJob(PromiseJob::new(move |ctx| {
+            ctx.enqueue_job(Job::AsyncJob(NativeAsyncJob::new(async move |_| {
+                async_log.borrow_mut().push("async");
+                Ok(JsValue::undefined())
+            })));
+            first(ctx)
+        })));
+        ctx.enqueue_job(Job::PromiseJob(PromiseJob::new(push(&log, "promise 2"))));
+
+        assert_eq!(ctx.run_jobs_limited(usize::MAX).unwrap(), 3);
+        assert_eq!(*log.borrow(), ["promise 1", "promise 2", "async"]);
+    })]);
+}
+
+#[test]
+fn run_jobs_limited_drops_due_timeouts_on_error() {
+    let clock = Rc::new(FixedClock::from_millis(0));
+    let ctx = &mut ContextBuilder::new().clock(clock.clone()).build().unwrap();
+    let timeout = |millis| {
+        Job::TimeoutJob(TimeoutJob::new(
+            NativeJob::new(|_| Err(JsNativeError::error().with_message("failed").into())),
+            millis,
+        ))
+    };
+    ctx.enqueue_job(timeout(10));
+    ctx.enqueue_job(timeout(20));
+    ctx.enqueue_job(timeout(100));
+    ctx.enqueue_job(Job::PromiseJob(PromiseJob::new(|_| {
+        Ok(JsValue::undefined())
+    })));
+    clock.forward(50);
+
+    // Like `run_jobs`, the error drops the other due timeout, but keeps the timeout that isn't
+    // due yet and the promise job.
+    assert!(ctx.run_jobs_limited(usize::MAX).is_err());
+    assert_eq!(ctx.pending_jobs(), Some(2));
+    assert_eq!(ctx.run_jobs_limited(usize::MAX).unwrap(), 1);
+    assert_eq!(ctx.pending_jobs(), Some(1));
+}
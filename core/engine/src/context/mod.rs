@@ -22,11 +22,11 @@ use crate::{
     js_string,
     module::{IdleModuleLoader, ModuleLoader, SimpleModuleLoader},
     native_function::NativeFunction,
-    object::{FunctionObjectBuilder, JsObject, shape::RootShape},
+    object::{FunctionObjectBuilder, JsObject, builtins::MapObservers, shape::RootShape},
     optimizer::{Optimizer, OptimizerOptions, OptimizerStatistics},
     property::{Attribute, PropertyDescriptor, PropertyKey},
     realm::Realm,
-    script::Script,
+    script::{ParseCache, Script},
     vm::{ActiveRunnable, CallFrame, Vm},
 };
 
@@ -100,6 +100,10 @@ pub struct Context {
     #[cfg(feature = "fuzz")]
     pub(crate) instructions_remaining: usize,
 
+    /// Number of ticks remaining before [`Context::eval_with_budget`] interrupts the
+    /// execution, if a budget is set.
+    pub(crate) tick_budget: Option<u64>,
+
     pub(crate) vm: Vm,
 
     pub(crate) kept_alive: Vec<JsObject>,
@@ -127,6 +131,12 @@ pub struct Context {
     /// Unique identifier for each parser instance used during the context lifetime.
     parser_identifier: u32,
 
+    /// Scripts parsed by [`Context::parse_cached`].
+    pub(crate) parse_cache: ParseCache,
+
+    /// Callbacks installed by [`JsMap::observe`](crate::object::builtins::JsMap::observe).
+    pub(crate) map_observers: MapObservers,
+
     data: HostDefined,
 }
 
@@ -199,6 +209,88 @@ impl Context {
         Script::parse(src, None, self)?.evaluate(self)
     }
 
+    /// Evaluates a parsed [`Script`], interrupting its execution once it has spent `max_ticks`
+    /// ticks.
+    ///
+    /// A tick is charged on every loop iteration and every function call, including the calls
+    /// made by the functions the script calls, which allows hosts to run untrusted scripts
+    /// without risking an infinite loop or unbounded recursion. Exhausting the budget throws an
+    /// uncatchable [`RuntimeLimit`][crate::JsNativeErrorKind::RuntimeLimit] error, which cannot
+    /// be intercepted with a `try` statement.
+    ///
+    /// Nested calls share the budget: the inner evaluation can spend at most what remains of
+    /// the outer budget, and the ticks it spends are charged to the outer budget.
+    ///
+    /// The budget only covers the synchronous evaluation of the script. Jobs enqueued by it,
+    /// such as promise reactions and the continuations of async functions, run later through
+    /// [`Context::run_jobs`] and are not budgeted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the script throws, or if the budget runs out before the script
+    /// completes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa_engine::{Context, Script, Source};
+    /// let mut context = Context::default();
+    ///
+    /// let script = Script::parse(Source::from_bytes("1 + 3"), None, &mut context).unwrap();
+    /// assert_eq!(context.eval_with_budget(&script, 1_000).unwrap(), 4.into());
+    ///
+    /// let script = Script::parse(Source::from_bytes("while (true) {}"), None, &mut context).unwrap();
+    /// let err = context.eval_with_budget(&script, 1_000).unwrap_err();
+    /// assert!(err.try_native(&mut context).unwrap().is_runtime_limit());
+    /// ```
+    pub fn eval_with_budget(&mut self, script: &Script, max_ticks: u64) -> JsResult<JsValue> {
+        let outer = self.tick_budget;
+        let budget = outer.map_or(max_ticks, |outer| outer.min(max_ticks));
+        self.tick_budget = Some(budget);
+        let result = script.evaluate(self);
+        let spent = budget - self.tick_budget.unwrap_or(0);
+        self.tick_budget = outer.map(|outer| outer - spent);
+        result
+    }
+
+    /// Parses the given source code as a [`Script`] in the current realm, reusing the AST of a
+    /// previous call with the same source code.
+    ///
+    /// This avoids lexing and parsing snippets that get evaluated repeatedly, like in REPLs or
+    /// test harnesses. An AST is only reused for the same source code, strictness, optimizer
+    /// options and realm, and only the most recently parsed scripts are kept. Scripts with
+    /// tagged templates are never cached, since each parse must get its own template objects.
+    /// Every call returns a new [`Script`] record, so the script still has to be compiled before
+    /// evaluating it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{Context, JsValue};
+    /// let mut context = Context::default();
+    ///
+    /// for i in 1..=3 {
+    ///     let script = context.parse_cached("var counter = (counter ?? 0) + 1; counter").unwrap();
+    ///     assert_eq!(script.evaluate(&mut context).unwrap(), JsValue::new(i));
+    /// }
+    /// assert_eq!(context.parse_cache_hits(), 2);
+    /// ```
+    pub fn parse_cached(&mut self, src: &str) -> JsResult<Script> {
+        Script::parse_cached(src, self)
+    }
+
+    /// Gets the number of times [`Context::parse_cached`] reused a cached AST.
+    #[inline]
+    #[must_use]
+    pub const fn parse_cache_hits(&self) -> u64 {
+        self.parse_cache.hits()
+    }
+
+    /// Removes all the ASTs cached by [`Context::parse_cached`].
+    #[inline]
+    pub fn clear_parse_cache(&mut self) {
+        self.parse_cache.clear();
+    }
+
     /// Applies optimizations to the [`StatementList`] inplace.
     pub fn optimize_statement_list(
         &mut self,
@@ -480,6 +572,33 @@ impl Context {
         result
     }
 
+    /// Runs at most `max` jobs with the provided job executor, returning the number of jobs
+    /// that were run.
+    ///
+    /// The remaining jobs stay queued, which allows hosts to interleave job processing with
+    /// their own work. Jobs enqueued while running other jobs also count toward `max`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a job throws, or if the job executor doesn't support
+    /// [`JobExecutor::run_jobs_limited`].
+    #[inline]
+    pub fn run_jobs_limited(&mut self, max: usize) -> JsResult<usize> {
+        let result = self.job_executor().run_jobs_limited(max, self);
+        self.clear_kept_objects();
+        result
+    }
+
+    /// Returns the number of jobs queued in the job executor, or `None` if the executor doesn't
+    /// keep track of them.
+    ///
+    /// This includes timeout jobs that are not due yet.
+    #[inline]
+    #[must_use]
+    pub fn pending_jobs(&self) -> Option<usize> {
+        self.job_executor.pending_jobs()
+    }
+
     /// Abstract operation [`ClearKeptObjects`][clear].
     ///
     /// Clears all objects maintained alive by calls to the [`AddToKeptObjects`][add] abstract
@@ -1096,6 +1215,7 @@ impl ContextBuilder {
             interner: self.interner.unwrap_or_default(),
             vm,
             strict: false,
+            tick_budget: None,
             #[cfg(feature = "temporal")]
             tz_provider: FsTzdbProvider::default(),
             #[cfg(feature = "intl")]
@@ -1123,6 +1243,8 @@ impl ContextBuilder {
             optimizer_options: OptimizerOptions::OPTIMIZE_ALL,
             root_shape,
             parser_identifier: 0,
+            parse_cache: ParseCache::default(),
+            map_observers: MapObservers::default(),
             can_block: self.can_block,
             data: HostDefined::default(),
         };
@@ -32,7 +32,7 @@
 
 use crate::context::time::{JsDuration, JsInstant};
 use crate::{
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue,
     object::{JsFunction, NativeObject},
     realm::Realm,
 };
@@ -478,6 +478,31 @@ pub trait JobExecutor: Any {
     /// Runs all jobs in the executor.
     fn run_jobs(self: Rc<Self>, context: &mut Context) -> JsResult<()>;
 
+    /// Runs at most `max` jobs in the executor, returning the number of jobs that were run.
+    ///
+    /// Jobs that are enqueued while running other jobs also count toward the limit. Any job
+    /// that wasn't run must stay queued for a future call to [`JobExecutor::run_jobs`] or
+    /// [`JobExecutor::run_jobs_limited`].
+    ///
+    /// By default this returns an error, since an executor cannot be run partially without
+    /// knowing how its queues are organized. Implementors that support bounded processing
+    /// should override it.
+    fn run_jobs_limited(self: Rc<Self>, max: usize, context: &mut Context) -> JsResult<usize> {
+        let _ = (max, context);
+        Err(JsNativeError::error()
+            .with_message("this job executor does not support running a limited number of jobs")
+            .into())
+    }
+
+    /// Returns the number of jobs waiting to be run, or `None` if the executor doesn't keep
+    /// track of them.
+    ///
+    /// By default this returns `None`. Implementors that can count their queued jobs should
+    /// override it.
+    fn pending_jobs(&self) -> Option<usize> {
+        None
+    }
+
     /// Asynchronously runs all jobs in the executor.
     ///
     /// By default forwards to [`JobExecutor::run_jobs`]. Implementors using async should override this
@@ -521,6 +546,14 @@ impl JobExecutor for IdleJobExecutor {
     fn run_jobs(self: Rc<Self>, _: &mut Context) -> JsResult<()> {
         Ok(())
     }
+
+    fn run_jobs_limited(self: Rc<Self>, _: usize, _: &mut Context) -> JsResult<usize> {
+        Ok(0)
+    }
+
+    fn pending_jobs(&self) -> Option<usize> {
+        Some(0)
+    }
 }
 
 /// A simple FIFO executor that bails on the first error.
@@ -609,4 +642,72 @@ impl JobExecutor for SimpleJobExecutor {
 
         Ok(())
     }
+
+    fn run_jobs_limited(self: Rc<Self>, max: usize, context: &mut Context) -> JsResult<usize> {
+        let now = context.clock().now();
+        let mut ran = 0;
+
+        // Jobs are run one at a time in the same order as `run_jobs`: the timeouts that are due
+        // first, then the async job queue and the promise job queue are drained in turns until
+        // both are empty.
+        let mut due_timeouts = self.timeout_jobs.borrow().range(..=now).count();
+        while ran < max && due_timeouts > 0 {
+            let timeout_job = self
+                .timeout_jobs
+                .borrow_mut()
+                .pop_first()
+                .map(|(_, job)| job);
+            let Some(job) = timeout_job else {
+                break;
+            };
+            due_timeouts -= 1;
+            if let Err(err) = job.call(context) {
+                // Like `run_jobs`, drop the rest of the timeouts that were due.
+                let mut timeouts = self.timeout_jobs.borrow_mut();
+                let jobs_to_keep = timeouts.split_off(&(now + JsDuration::from_millis(1)));
+                *timeouts = jobs_to_keep;
+                return Err(err);
+            }
+            ran += 1;
+        }
+
+        let mut draining_promise_jobs = false;
+        while ran < max {
+            let result = if draining_promise_jobs {
+                let promise_job = self.promise_jobs.borrow_mut().pop_front();
+                let Some(job) = promise_job else {
+                    if self.async_jobs.borrow().is_empty() {
+                        break;
+                    }
+                    draining_promise_jobs = false;
+                    continue;
+                };
+                job.call(context)
+            } else {
+                let async_job = self.async_jobs.borrow_mut().pop_front();
+                let Some(job) = async_job else {
+                    draining_promise_jobs = true;
+                    continue;
+                };
+                futures_lite::future::block_on(job.call(&RefCell::new(&mut *context)))
+            };
+
+            if let Err(err) = result {
+                self.async_jobs.borrow_mut().clear();
+                self.promise_jobs.borrow_mut().clear();
+                return Err(err);
+            }
+            ran += 1;
+        }
+
+        Ok(ran)
+    }
+
+    fn pending_jobs(&self) -> Option<usize> {
+        Some(
+            self.promise_jobs.borrow().len()
+                + self.async_jobs.borrow().len()
+                + self.timeout_jobs.borrow().len(),
+        )
+    }
 }
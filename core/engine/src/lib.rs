@@ -183,7 +183,7 @@ impl JsArgs for [JsValue] {
 }
 
 #[cfg(test)]
-use std::borrow::Cow;
+use std::{borrow::Cow, path::PathBuf};
 
 /// A test action executed in a test function.
 #[cfg(test)]
@@ -197,6 +197,9 @@ enum Inner {
     Run {
         source: Cow<'static, str>,
     },
+    RunFile {
+        path: PathBuf,
+    },
     InspectContext {
         op: fn(&mut Context),
     },
@@ -223,6 +226,7 @@ enum Inner {
     AssertContext {
         op: fn(&mut Context) -> bool,
     },
+    AssertNoPendingJobs,
 }
 
 #[cfg(test)]
@@ -239,6 +243,16 @@ impl TestAction {
         })
     }
 
+    /// Runs the script at `path`, panicking if the parsing or execution throws.
+    ///
+    /// Relative paths are resolved against the crate root, which allows tests to keep large
+    /// fixtures as standalone `.js` files.
+    fn run_file(path: &str) -> Self {
+        Self(Inner::RunFile {
+            path: PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(path),
+        })
+    }
+
     /// Executes `op` with the currently active context.
     ///
     /// Useful to make custom assertions that must be done from Rust code.
@@ -302,6 +316,13 @@ impl TestAction {
     fn assert_context(op: fn(&mut Context) -> bool) -> Self {
         Self(Inner::AssertContext { op })
     }
+
+    /// Asserts that the job queue of the context is empty.
+    ///
+    /// Useful after running the jobs of promise tests, to catch jobs that were left queued.
+    const fn assert_no_pending_jobs() -> Self {
+        Self(Inner::AssertNoPendingJobs)
+    }
 }
 
 /// Executes a list of test actions on a new, default context.
@@ -361,6 +382,18 @@ fn run_test_actions_with(actions: impl IntoIterator<Item = TestAction>, context:
                     panic!("{}\nUncaught {e}", fmt_test(&source, i));
                 }
             }
+            Inner::RunFile { path } => {
+                let source = match Source::from_filepath(&path) {
+                    Ok(source) => source,
+                    Err(e) => panic!(
+                        "\n\nTest file `{}`: \nCould not read file: {e}",
+                        path.display()
+                    ),
+                };
+                if let Err(e) = context.eval(source) {
+                    panic!("\n\nTest file `{}`: \nUncaught {e}", path.display());
+                }
+            }
             Inner::InspectContext { op } => {
                 op(context);
             }
@@ -444,6 +477,52 @@ fn run_test_actions_with(actions: impl IntoIterator<Item = TestAction>, context:
                 assert!(op(context), "Test case {i}");
                 i += 1;
             }
+            Inner::AssertNoPendingJobs => {
+                let pending = context
+                    .pending_jobs()
+                    .expect("the job executor must report its pending jobs");
+                assert_eq!(pending, 0, "Test case {i}: found {pending} pending jobs");
+                i += 1;
+            }
         }
     }
 }
+
+/// Asserts that draining the [`entries()`](object::builtins::JsMap::entries) iterator of `map`
+/// yields exactly `expected_keys`, in order.
+#[cfg(test)]
+#[track_caller]
+fn assert_insertion_order(
+    map: &object::builtins::JsMap,
+    expected_keys: &[JsValue],
+    context: &mut Context,
+) {
+    let entries = map.entries(context).expect("failed to get the map entries");
+    let mut keys = Vec::new();
+    loop {
+        let result = entries
+            .next(context)
+            .expect("failed to advance the map iterator");
+        let result = result
+            .as_object()
+            .expect("iterator result must be an object");
+        if result
+            .get(js_string!("done"), context)
+            .expect("failed to get `done`")
+            .to_boolean()
+        {
+            break;
+        }
+        let entry = result
+            .get(js_string!("value"), context)
+            .expect("failed to get `value`");
+        let key = entry
+            .as_object()
+            .expect("map entry must be an array")
+            .get(0, context)
+            .expect("failed to get the entry key");
+        keys.push(key);
+    }
+
+    assert_eq!(keys, expected_keys, "unexpected map insertion order");
+}
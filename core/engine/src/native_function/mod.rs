@@ -424,6 +424,7 @@ pub(crate) fn native_function_call(
     // We technically don't need this since native functions don't push any new frames to the
     // vm, but we'll eventually have to combine the native stack with the vm stack.
     context.check_runtime_limits()?;
+    context.charge_tick_budget()?;
     let this_function_object = obj.clone();
 
     let NativeFunctionObject {
@@ -477,6 +478,7 @@ fn native_function_construct(
     // We technically don't need this since native functions don't push any new frames to the
     // vm, but we'll eventually have to combine the native stack with the vm stack.
     context.check_runtime_limits()?;
+    context.charge_tick_budget()?;
     let this_function_object = obj.clone();
 
     let NativeFunctionObject {
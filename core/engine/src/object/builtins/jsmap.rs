@@ -8,12 +8,22 @@ use crate::{
     },
     error::JsNativeError,
     js_string,
-    object::{JsFunction, JsMapIterator, JsObject},
+    object::{
+        ErasedObject, ErasedVTableObject, JsFunction, JsMapIterator, JsObject, Ref, RefMut,
+        internal_methods::InternalMethodContext,
+    },
     value::TryFromJs,
 };
 
-use boa_gc::{Finalize, Trace};
-use std::ops::Deref;
+use boa_gc::{Finalize, Gc, Trace, WeakGc};
+use num_traits::Zero;
+use std::{
+    cell::RefCell,
+    cmp::Ordering,
+    fmt,
+    ops::{ControlFlow, Deref},
+    rc::Rc,
+};
 
 /// `JsMap` provides a wrapper for Boa's implementation of the ECMAScript `Map` object.
 ///
@@ -74,6 +84,9 @@ use std::ops::Deref;
 /// # Ok(())
 /// }
 /// ```
+///
+/// Cloning a `JsMap` only clones the handle to the underlying `Map` object, so both handles
+/// share the same entries. Use [`JsMap::clone_deep`] to create an independent copy.
 #[derive(Debug, Clone, Trace, Finalize)]
 pub struct JsMap {
     inner: JsObject,
@@ -95,7 +108,35 @@ impl JsMap {
     /// ```
     #[inline]
     pub fn new(context: &mut Context) -> Self {
-        let map = Self::create_map(context);
+        let map = Self::create_map(OrderedMap::new(), context);
+        Self { inner: map }
+    }
+
+    /// Creates a new empty [`JsMap`] object with room for at least `capacity` entries.
+    ///
+    /// This avoids reallocating the map while inserting a known number of entries. The map
+    /// still grows past `capacity` if more entries are inserted.
+    ///
+    /// # Example
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::JsMap,
+    /// #    Context, JsResult, js_string,
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let map = JsMap::with_capacity(2, context);
+    /// map.set(js_string!("a"), 1, context)?;
+    /// map.set(js_string!("b"), 2, context)?;
+    /// map.set(js_string!("c"), 3, context)?;
+    ///
+    /// assert_eq!(map.get_size(context)?, 3.into());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn with_capacity(capacity: usize, context: &mut Context) -> Self {
+        let map = Self::create_map(OrderedMap::with_capacity(capacity), context);
         Self { inner: map }
     }
 
@@ -125,7 +166,7 @@ impl JsMap {
     /// ```
     pub fn from_js_iterable(iterable: &JsValue, context: &mut Context) -> JsResult<Self> {
         // Create a new map object.
-        let map = Self::create_map(context);
+        let map = Self::create_map(OrderedMap::new(), context);
 
         // Let adder be Get(map, "set") per spec. This action should not fail with default map.
         let adder = map
@@ -140,6 +181,121 @@ impl JsMap {
         Ok(Self { inner: map })
     }
 
+    /// Creates a new [`JsMap`] grouping the elements of `iterable` by the key returned by `key_fn`.
+    ///
+    /// Each key is mapped to a [`JsArray`][super::JsArray] of the elements with that key, in
+    /// iteration order, and keys are ordered by their first element. This mirrors
+    /// [`Map.groupBy`][mdn], with `-0` keys normalized to `+0`. If `key_fn` returns an error,
+    /// the iterator is closed and the error returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::{JsArray, JsMap},
+    /// #    Context, JsValue, JsResult, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let numbers = JsArray::from_iter([1, 2, 3, 4, 5].map(JsValue::from), context);
+    ///
+    /// let by_parity = JsMap::group_by(
+    ///     &numbers.into(),
+    ///     |value, context| {
+    ///         let odd = value.to_number(context)? % 2.0 != 0.0;
+    ///         Ok(js_string!(if odd { "odd" } else { "even" }).into())
+    ///     },
+    ///     context,
+    /// )?;
+    ///
+    /// let odd = by_parity.get(js_string!("odd"), context)?;
+    /// let odd = JsArray::from_object(odd.as_object().unwrap().clone())?;
+    /// assert_eq!(odd.length(context)?, 3);
+    /// assert_eq!(odd.at(2, context)?, 5.into());
+    ///
+    /// let even = by_parity.get(js_string!("even"), context)?;
+    /// let even = JsArray::from_object(even.as_object().unwrap().clone())?;
+    /// assert_eq!(even.length(context)?, 2);
+    /// assert_eq!(even.at(0, context)?, 2.into());
+    ///
+    /// // Groups are ordered by their first element.
+    /// assert_eq!(
+    ///     by_parity.first_entry(context)?.map(|(key, _)| key),
+    ///     Some(js_string!("odd").into())
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/groupBy
+    pub fn group_by<F>(iterable: &JsValue, mut key_fn: F, context: &mut Context) -> JsResult<Self>
+    where
+        F: FnMut(&JsValue, &mut Context) -> JsResult<JsValue>,
+    {
+        iterable.require_object_coercible()?;
+        let map = Map::group_by_with(
+            iterable,
+            |value, _, context| key_fn(value, context),
+            context,
+        )?;
+        let inner = map
+            .as_object()
+            .expect("`Map.groupBy` must return an object")
+            .clone();
+
+        Ok(Self { inner })
+    }
+
+    /// Creates a new [`JsMap`] from the own enumerable properties of `obj`.
+    ///
+    /// Both string and symbol keys are copied, in property order, together with their current
+    /// values; inherited properties are skipped. This is useful to convert configuration objects
+    /// into maps. Like [`Object.entries`][mdn], integer keys become strings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::{builtins::JsMap, ObjectInitializer},
+    /// #    property::Attribute,
+    /// #    Context, JsResult, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// // `{ a: 1, b: 2 }`
+    /// let object = ObjectInitializer::new(context)
+    ///     .property(js_string!("a"), 1, Attribute::all())
+    ///     .property(js_string!("b"), 2, Attribute::all())
+    ///     .build();
+    ///
+    /// let map = JsMap::from_plain_object(&object, context)?;
+    ///
+    /// assert_eq!(map.get_size(context)?, 2.into());
+    /// assert_eq!(map.get(js_string!("a"), context)?, 1.into());
+    /// assert_eq!(map.get(js_string!("b"), context)?, 2.into());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/entries
+    pub fn from_plain_object(obj: &JsObject, context: &mut Context) -> JsResult<Self> {
+        let keys = obj.__own_property_keys__(&mut InternalMethodContext::new(context))?;
+        let map = Self::with_capacity(keys.len(), context);
+        for key in keys {
+            let Some(desc) =
+                obj.__get_own_property__(&key, &mut InternalMethodContext::new(context))?
+            else {
+                continue;
+            };
+            if desc.expect_enumerable() {
+                let value = obj.get(key.clone(), context)?;
+                map.set(key, value, context)?;
+            }
+        }
+
+        Ok(map)
+    }
+
     /// Creates a [`JsMap`] from a valid [`JsObject`], or returns a `TypeError` if the provided object is not a [`JsMap`]
     ///
     /// # Examples
@@ -188,17 +344,110 @@ impl JsMap {
         }
     }
 
+    /// Gets a reference to the underlying [`JsObject`] of the [`JsMap`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::{builtins::JsMap, JsObject},
+    /// #    Context, JsResult,
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// fn is_extensible(object: &JsObject, context: &mut Context) -> JsResult<bool> {
+    ///     object.is_extensible(context)
+    /// }
+    ///
+    /// let js_map = JsMap::new(context);
+    /// assert!(is_extensible(js_map.as_object(), context)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn as_object(&self) -> &JsObject {
+        &self.inner
+    }
+
+    /// Consumes the [`JsMap`], returning the underlying [`JsObject`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::{builtins::JsMap, JsObject},
+    /// #    Context, JsResult, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let js_map = JsMap::new(context);
+    /// js_map.set(js_string!("foo"), 1, context)?;
+    ///
+    /// let object: JsObject = js_map.into_object();
+    /// let js_map = JsMap::from_object(object)?;
+    /// assert_eq!(js_map.get(js_string!("foo"), context)?, 1.into());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn into_object(self) -> JsObject {
+        self.inner.clone()
+    }
+
     // Utility function to generate the default `Map` object.
-    fn create_map(context: &mut Context) -> JsObject {
+    fn create_map(data: OrderedMap<JsValue>, context: &mut Context) -> JsObject {
         // Get default Map prototype
         let prototype = context.intrinsics().constructors().map().prototype();
 
         // Create a default map object with [[MapData]] as a new empty list
-        JsObject::from_proto_and_data_with_shared_shape(
-            context.root_shape(),
-            prototype,
-            <OrderedMap<JsValue>>::new(),
-        )
+        JsObject::from_proto_and_data_with_shared_shape(context.root_shape(), prototype, data)
+    }
+
+    // Utility function to borrow the `[[MapData]]` of the map.
+    fn borrow_map_data(&self) -> Ref<'_, OrderedMap<JsValue>> {
+        self.inner
+            .downcast_ref::<OrderedMap<JsValue>>()
+            .expect("objects cannot change type after creation")
+    }
+
+    // Utility function to mutably borrow the `[[MapData]]` of the map.
+    fn borrow_map_data_mut(&self) -> RefMut<'_, ErasedObject, OrderedMap<JsValue>> {
+        self.inner
+            .downcast_mut::<OrderedMap<JsValue>>()
+            .expect("objects cannot change type after creation")
+    }
+
+    // Utility function to check if any observer is installed, so mutations are only built
+    // when someone will receive them.
+    fn is_observed(&self, context: &Context) -> bool {
+        context
+            .map_observers
+            .get(&self.inner)
+            .is_some_and(|observers| !observers.borrow().is_empty())
+    }
+
+    // Utility function to report mutations to the observers installed by `JsMap::observe`.
+    fn notify<I>(&self, mutations: I, context: &Context)
+    where
+        I: IntoIterator<Item = MapMutation>,
+    {
+        let Some(installed) = context.map_observers.get(&self.inner) else {
+            return;
+        };
+
+        // Take the observers out while running them, so they can mutate the map or install new
+        // observers without a reentrant borrow.
+        let mut observers = installed.take();
+        for mutation in mutations {
+            for observer in &mut observers {
+                observer(mutation.clone());
+            }
+        }
+        let mut current = installed.borrow_mut();
+        observers.append(&mut current);
+        *current = observers;
     }
 
     /// Returns a new [`JsMapIterator`] object that yields the `[key, value]` pairs within the [`JsMap`] in insertion order.
@@ -245,14 +494,21 @@ impl JsMap {
         K: Into<JsValue>,
         V: Into<JsValue>,
     {
-        Map::set(
-            &self.inner.clone().into(),
-            &[key.into(), value.into()],
-            context,
-        )
+        let key = key.into();
+        let value = value.into();
+        let mutation = self.is_observed(context).then(|| MapMutation::Set {
+            key: normalize_key(key.clone()),
+            value: value.clone(),
+        });
+        let result = Map::set(&self.inner.clone().into(), &[key, value], context)?;
+        self.notify(mutation, context);
+        Ok(result)
     }
 
-    /// Gets the size of the [`JsMap`] object.
+    /// Inserts a new entry into the [`JsMap`] object only if `key` is not already present.
+    ///
+    /// Returns `true` if the entry was inserted, or `false` if the key already existed,
+    /// in which case the existing value is left untouched.
     ///
     /// # Example
     ///
@@ -265,20 +521,46 @@ impl JsMap {
     /// # let context = &mut Context::default();
     /// let js_map = JsMap::new(context);
     ///
-    /// js_map.set(js_string!("foo"), js_string!("bar"), context)?;
-    ///
-    /// let map_size = js_map.get_size(context)?;
+    /// assert!(js_map.try_insert(js_string!("foo"), 1, context)?);
+    /// assert!(!js_map.try_insert(js_string!("foo"), 2, context)?);
     ///
-    /// assert_eq!(map_size, 1.into());
+    /// assert_eq!(js_map.get(js_string!("foo"), context)?, 1.into());
+    /// assert_eq!(js_map.get_size(context)?, 1.into());
     /// # Ok(())
     /// # }
     /// ```
-    #[inline]
-    pub fn get_size(&self, context: &mut Context) -> JsResult<JsValue> {
-        Map::get_size(&self.inner.clone().into(), &[], context)
+    pub fn try_insert<K, V>(&self, key: K, value: V, context: &mut Context) -> JsResult<bool>
+    where
+        K: Into<JsValue>,
+        V: Into<JsValue>,
+    {
+        let key = normalize_key(key.into());
+
+        let value = value.into();
+        let mutation = self.is_observed(context).then(|| MapMutation::Set {
+            key: key.clone(),
+            value: value.clone(),
+        });
+
+        {
+            let mut map = self.borrow_map_data_mut();
+            if map.contains_key(&key) {
+                return Ok(false);
+            }
+            map.insert(key, value);
+        }
+        self.notify(mutation, context);
+        Ok(true)
     }
 
-    /// Removes element from [`JsMap`] with a matching `key` value.
+    /// Adds `by` to the numeric value stored under `key`, returning the new total.
+    ///
+    /// A missing key is treated as `0`, so this can be used to build counters and histograms
+    /// without having to get, coerce and set each value separately.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the existing value is not a number.
     ///
     /// # Example
     ///
@@ -289,25 +571,54 @@ impl JsMap {
     /// # };
     /// # fn main() -> JsResult<()> {
     /// # let context = &mut Context::default();
-    /// let js_map = JsMap::new(context);
-    /// js_map.set(js_string!("foo"), js_string!("bar"), context)?;
-    /// js_map.set(js_string!("hello"), js_string!("world"), context)?;
+    /// let histogram = JsMap::new(context);
     ///
-    /// js_map.delete(js_string!("foo"), context)?;
+    /// for word in "the quick fox jumps over the lazy fox the end".split(' ') {
+    ///     histogram.increment(js_string!(word), 1.0, context)?;
+    /// }
     ///
-    /// assert_eq!(js_map.get_size(context)?, 1.into());
-    /// assert_eq!(js_map.get(js_string!("foo"), context)?, JsValue::undefined());
+    /// assert_eq!(histogram.get(js_string!("the"), context)?, 3.into());
+    /// assert_eq!(histogram.get(js_string!("fox"), context)?, 2.into());
+    /// assert_eq!(histogram.get(js_string!("lazy"), context)?, 1.into());
+    /// assert_eq!(histogram.increment(js_string!("fox"), 0.5, context)?, 2.5);
+    ///
+    /// histogram.set(js_string!("word"), js_string!("not a number"), context)?;
+    /// assert!(histogram.increment(js_string!("word"), 1.0, context).is_err());
     /// # Ok(())
     /// # }
     /// ```
-    pub fn delete<T>(&self, key: T, context: &mut Context) -> JsResult<JsValue>
+    pub fn increment<K>(&self, key: K, by: f64, context: &mut Context) -> JsResult<f64>
     where
-        T: Into<JsValue>,
+        K: Into<JsValue>,
     {
-        Map::delete(&self.inner.clone().into(), &[key.into()], context)
+        let key = normalize_key(key.into());
+
+        let mut map = self.borrow_map_data_mut();
+        let current = match map.get(&key) {
+            None => 0.0,
+            Some(value) => value.as_number().ok_or_else(|| {
+                JsNativeError::typ().with_message("cannot increment a non-numeric map value")
+            })?,
+        };
+
+        let total = current + by;
+        let mutation = self.is_observed(context).then(|| MapMutation::Set {
+            key: key.clone(),
+            value: total.into(),
+        });
+        map.insert(key, total.into());
+        drop(map);
+        self.notify(mutation, context);
+        Ok(total)
     }
 
-    /// Gets the value associated with the specified key within the [`JsMap`], or `undefined` if the key does not exist.
+    /// Computes a new value for the given key from its current value.
+    ///
+    /// The current value (or `None` if the key is missing) is passed to `f`. If `f` returns
+    /// `Some`, the value is stored in the map, otherwise the key is removed. This covers inserting,
+    /// updating and removing an entry with a single call.
+    ///
+    /// Returns the new value, or `None` if the entry was removed.
     ///
     /// # Example
     ///
@@ -319,22 +630,69 @@ impl JsMap {
     /// # fn main() -> JsResult<()> {
     /// # let context = &mut Context::default();
     /// let js_map = JsMap::new(context);
-    /// js_map.set(js_string!("foo"), js_string!("bar"), context)?;
     ///
-    /// let retrieved_value = js_map.get(js_string!("foo"), context)?;
+    /// // Insert
+    /// let value = js_map.compute(
+    ///     js_string!("count"),
+    ///     |current| Some(current.unwrap_or_else(|| 1.into())),
+    ///     context,
+    /// )?;
+    /// assert_eq!(value, Some(1.into()));
     ///
-    /// assert_eq!(retrieved_value, js_string!("bar").into());
+    /// // Update
+    /// let value = js_map.compute(
+    ///     js_string!("count"),
+    ///     |current| current.and_then(|v| v.as_number()).map(|n| (n * 10.0).into()),
+    ///     context,
+    /// )?;
+    /// assert_eq!(value, Some(10.into()));
+    /// assert_eq!(js_map.get(js_string!("count"), context)?, 10.into());
+    ///
+    /// // Remove
+    /// assert_eq!(js_map.compute(js_string!("count"), |_| None, context)?, None);
+    /// assert_eq!(js_map.has(js_string!("count"), context)?, false.into());
     /// # Ok(())
     /// # }
     /// ```
-    pub fn get<T>(&self, key: T, context: &mut Context) -> JsResult<JsValue>
+    pub fn compute<K, F>(&self, key: K, f: F, context: &mut Context) -> JsResult<Option<JsValue>>
     where
-        T: Into<JsValue>,
+        K: Into<JsValue>,
+        F: FnOnce(Option<JsValue>) -> Option<JsValue>,
     {
-        Map::get(&self.inner.clone().into(), &[key.into()], context)
+        let key = normalize_key(key.into());
+
+        // The map must not be borrowed while calling `f`, since it could access the map.
+        let current = self.borrow_map_data().get(&key).cloned();
+        let existed = current.is_some();
+        let value = f(current);
+
+        let mutation = match &value {
+            Some(value) => self.is_observed(context).then(|| MapMutation::Set {
+                key: key.clone(),
+                value: value.clone(),
+            }),
+            None => (existed && self.is_observed(context))
+                .then(|| MapMutation::Delete { key: key.clone() }),
+        };
+        {
+            let mut map = self.borrow_map_data_mut();
+            match &value {
+                Some(value) => {
+                    map.insert(key, value.clone());
+                }
+                None => {
+                    map.remove(&key);
+                }
+            }
+        }
+        self.notify(mutation, context);
+        Ok(value)
     }
 
-    /// Removes all entries from the [`JsMap`].
+    /// Computes a new value for the given key from its current value, if the key is present.
+    ///
+    /// This is like [`JsMap::compute`], but `f` is only called if the map contains the key, and
+    /// `None` is returned without modifying the map otherwise.
     ///
     /// # Example
     ///
@@ -346,21 +704,35 @@ impl JsMap {
     /// # fn main() -> JsResult<()> {
     /// # let context = &mut Context::default();
     /// let js_map = JsMap::new(context);
-    /// js_map.set(js_string!("foo"), js_string!("bar"), context)?;
-    /// js_map.set(js_string!("hello"), js_string!("world"), context)?;
+    /// js_map.set(js_string!("foo"), 1, context)?;
     ///
-    /// js_map.clear(context)?;
+    /// let value = js_map.compute_if_present(js_string!("foo"), |v| Some(v), context)?;
+    /// assert_eq!(value, Some(1.into()));
     ///
+    /// let value = js_map.compute_if_present(js_string!("bar"), |v| Some(v), context)?;
+    /// assert_eq!(value, None);
+    /// assert_eq!(js_map.has(js_string!("bar"), context)?, false.into());
+    ///
+    /// assert_eq!(js_map.compute_if_present(js_string!("foo"), |_| None, context)?, None);
     /// assert_eq!(js_map.get_size(context)?, 0.into());
     /// # Ok(())
     /// # }
     /// ```
-    #[inline]
-    pub fn clear(&self, context: &mut Context) -> JsResult<JsValue> {
-        Map::clear(&self.inner.clone().into(), &[], context)
+    pub fn compute_if_present<K, F>(
+        &self,
+        key: K,
+        f: F,
+        context: &mut Context,
+    ) -> JsResult<Option<JsValue>>
+    where
+        K: Into<JsValue>,
+        F: FnOnce(JsValue) -> Option<JsValue>,
+    {
+        // Removing a missing key leaves the map untouched.
+        self.compute(key, |current| current.and_then(f), context)
     }
 
-    /// Checks if [`JsMap`] has an entry with the provided `key` value.
+    /// Gets the size of the [`JsMap`] object.
     ///
     /// # Example
     ///
@@ -372,49 +744,916 @@ impl JsMap {
     /// # fn main() -> JsResult<()> {
     /// # let context = &mut Context::default();
     /// let js_map = JsMap::new(context);
+    ///
     /// js_map.set(js_string!("foo"), js_string!("bar"), context)?;
     ///
-    /// let has_key = js_map.has(js_string!("foo"), context)?;
+    /// let map_size = js_map.get_size(context)?;
     ///
-    /// assert_eq!(has_key, true.into());
+    /// assert_eq!(map_size, 1.into());
     /// # Ok(())
     /// # }
     /// ```
-    pub fn has<T>(&self, key: T, context: &mut Context) -> JsResult<JsValue>
-    where
-        T: Into<JsValue>,
-    {
-        Map::has(&self.inner.clone().into(), &[key.into()], context)
+    #[inline]
+    pub fn get_size(&self, context: &mut Context) -> JsResult<JsValue> {
+        Map::get_size(&self.inner.clone().into(), &[], context)
     }
 
-    /// Executes the provided callback function for each key-value pair within the [`JsMap`].
+    /// Returns `true` if the [`JsMap`] has no entries.
+    ///
+    /// This reads the number of entries directly, so it is cheaper than comparing the result
+    /// of [`JsMap::get_size`] with zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::JsMap,
+    /// #    Context, JsValue, JsResult, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let js_map = JsMap::new(context);
+    /// assert!(js_map.is_empty(context)?);
+    ///
+    /// js_map.set(js_string!("foo"), js_string!("bar"), context)?;
+    /// assert!(!js_map.is_empty(context)?);
+    ///
+    /// js_map.delete(js_string!("foo"), context)?;
+    /// assert!(js_map.is_empty(context)?);
+    /// # Ok(())
+    /// # }
+    /// ```
     #[inline]
-    pub fn for_each(
-        &self,
-        callback: JsFunction,
-        this_arg: JsValue,
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
-        Map::for_each(
-            &self.inner.clone().into(),
-            &[callback.into(), this_arg],
-            context,
-        )
+    pub fn is_empty(&self, _context: &mut Context) -> JsResult<bool> {
+        Ok(self.borrow_map_data().is_empty())
     }
 
-    /// Executes the provided callback function for each key-value pair within the [`JsMap`].
-    #[inline]
-    pub fn for_each_native<F>(&self, f: F) -> JsResult<()>
+    /// Removes element from [`JsMap`] with a matching `key` value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::JsMap,
+    /// #    Context, JsValue, JsResult, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let js_map = JsMap::new(context);
+    /// js_map.set(js_string!("foo"), js_string!("bar"), context)?;
+    /// js_map.set(js_string!("hello"), js_string!("world"), context)?;
+    ///
+    /// js_map.delete(js_string!("foo"), context)?;
+    ///
+    /// assert_eq!(js_map.get_size(context)?, 1.into());
+    /// assert_eq!(js_map.get(js_string!("foo"), context)?, JsValue::undefined());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete<T>(&self, key: T, context: &mut Context) -> JsResult<JsValue>
     where
-        F: FnMut(JsValue, JsValue) -> JsResult<()>,
+        T: Into<JsValue>,
     {
-        let this = self.inner.clone().into();
-        Map::for_each_native(&this, f)
+        let key = key.into();
+        let observed_key = self
+            .is_observed(context)
+            .then(|| normalize_key(key.clone()));
+        let deleted = Map::delete(&self.inner.clone().into(), &[key], context)?;
+        if deleted.to_boolean() {
+            self.notify(observed_key.map(|key| MapMutation::Delete { key }), context);
+        }
+        Ok(deleted)
     }
 
-    /// Returns a new [`JsMapIterator`] object that yields the `value` for each element within the [`JsMap`] in insertion order.
-    #[inline]
-    pub fn values(&self, context: &mut Context) -> JsResult<JsMapIterator> {
+    /// Removes the entry with a matching `key` from the [`JsMap`], returning its value.
+    ///
+    /// Returns `None` if the map doesn't contain the key. Unlike [`JsMap::delete`], this moves
+    /// the removed value out of the map.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::JsMap,
+    /// #    Context, JsValue, JsResult, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let js_map = JsMap::new(context);
+    /// js_map.set(js_string!("foo"), js_string!("bar"), context)?;
+    ///
+    /// assert_eq!(
+    ///     js_map.pop(js_string!("foo"), context)?,
+    ///     Some(js_string!("bar").into())
+    /// );
+    /// assert_eq!(js_map.pop(js_string!("foo"), context)?, None);
+    /// assert_eq!(js_map.pop(js_string!("missing"), context)?, None);
+    /// assert_eq!(js_map.get_size(context)?, 0.into());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pop<K>(&self, key: K, context: &mut Context) -> JsResult<Option<JsValue>>
+    where
+        K: Into<JsValue>,
+    {
+        let key = normalize_key(key.into());
+
+        let value = self.borrow_map_data_mut().remove(&key);
+        if value.is_some() {
+            self.notify(
+                self.is_observed(context)
+                    .then(|| MapMutation::Delete { key }),
+                context,
+            );
+        }
+        Ok(value)
+    }
+
+    /// Gets the value associated with the specified key within the [`JsMap`], or `undefined` if the key does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::JsMap,
+    /// #    Context, JsValue, JsResult, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let js_map = JsMap::new(context);
+    /// js_map.set(js_string!("foo"), js_string!("bar"), context)?;
+    ///
+    /// let retrieved_value = js_map.get(js_string!("foo"), context)?;
+    ///
+    /// assert_eq!(retrieved_value, js_string!("bar").into());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get<T>(&self, key: T, context: &mut Context) -> JsResult<JsValue>
+    where
+        T: Into<JsValue>,
+    {
+        Map::get(&self.inner.clone().into(), &[key.into()], context)
+    }
+
+    /// Overwrites the value of every entry of the [`JsMap`] with `value`, keeping its keys and
+    /// their insertion order.
+    ///
+    /// This is useful to reset a map used as a table of flags.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::JsMap,
+    /// #    Context, JsValue, JsResult, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let seen = JsMap::new(context);
+    /// seen.set(js_string!("a"), true, context)?;
+    /// seen.set(js_string!("b"), true, context)?;
+    ///
+    /// seen.set_default_value(false, context)?;
+    ///
+    /// assert_eq!(seen.get(js_string!("a"), context)?, false.into());
+    /// assert_eq!(seen.get(js_string!("b"), context)?, false.into());
+    /// assert_eq!(seen.get_size(context)?, 2.into());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_default_value<V>(&self, value: V, context: &mut Context) -> JsResult<()>
+    where
+        V: Into<JsValue> + Clone,
+    {
+        let value = value.into();
+        for entry in self.borrow_map_data_mut().values_mut() {
+            *entry = value.clone();
+        }
+
+        if self.is_observed(context) {
+            let mutations = self
+                .borrow_map_data()
+                .iter()
+                .map(|(key, _)| MapMutation::Set {
+                    key: key.clone(),
+                    value: value.clone(),
+                })
+                .collect::<Vec<_>>();
+            self.notify(mutations, context);
+        }
+        Ok(())
+    }
+
+    /// Removes all entries from the [`JsMap`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::JsMap,
+    /// #    Context, JsValue, JsResult, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let js_map = JsMap::new(context);
+    /// js_map.set(js_string!("foo"), js_string!("bar"), context)?;
+    /// js_map.set(js_string!("hello"), js_string!("world"), context)?;
+    ///
+    /// js_map.clear(context)?;
+    ///
+    /// assert_eq!(js_map.get_size(context)?, 0.into());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn clear(&self, context: &mut Context) -> JsResult<JsValue> {
+        let result = Map::clear(&self.inner.clone().into(), &[], context)?;
+        if self.is_observed(context) {
+            self.notify([MapMutation::Clear], context);
+        }
+        Ok(result)
+    }
+
+    /// Installs a callback that is invoked after each mutation of the [`JsMap`] made through its
+    /// methods, like [`JsMap::set`], [`JsMap::delete`] or [`JsMap::clear`].
+    ///
+    /// This allows mirroring the entries of a map into Rust state. Removing a missing key is not
+    /// reported.
+    ///
+    /// Only mutations made through the methods of a [`JsMap`] wrapping this map are observed,
+    /// including handles created before the callback was installed and handles created from the
+    /// same object with [`JsMap::from_object`]. Mutations made from JavaScript are not reported.
+    ///
+    /// The callback is stored in the context, which holds the map weakly. Once the map is garbage
+    /// collected, the callback is dropped the next time a map is observed. The callback is not
+    /// traced by the garbage collector, so the GC managed values it captures, like [`JsValue`] or
+    /// [`JsObject`], are kept alive until then. Capturing the map itself keeps it alive as long
+    /// as the context.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::{JsMap, MapMutation},
+    /// #    Context, JsResult, js_string
+    /// # };
+    /// # use std::{cell::RefCell, rc::Rc};
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let map = JsMap::new(context);
+    /// let mutations = Rc::new(RefCell::new(Vec::new()));
+    ///
+    /// let log = mutations.clone();
+    /// map.observe(move |mutation| log.borrow_mut().push(mutation), context)?;
+    ///
+    /// map.set(js_string!("a"), 1, context)?;
+    /// map.clear(context)?;
+    ///
+    /// assert_eq!(
+    ///     *mutations.borrow(),
+    ///     [
+    ///         MapMutation::Set {
+    ///             key: js_string!("a").into(),
+    ///             value: 1.into()
+    ///         },
+    ///         MapMutation::Clear
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn observe<F>(&self, f: F, context: &mut Context) -> JsResult<()>
+    where
+        F: FnMut(MapMutation) + 'static,
+    {
+        context
+            .map_observers
+            .get_or_insert(&self.inner)
+            .borrow_mut()
+            .push(Box::new(f));
+        Ok(())
+    }
+
+    /// Checks if [`JsMap`] has an entry with the provided `key` value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::JsMap,
+    /// #    Context, JsValue, JsResult, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let js_map = JsMap::new(context);
+    /// js_map.set(js_string!("foo"), js_string!("bar"), context)?;
+    ///
+    /// let has_key = js_map.has(js_string!("foo"), context)?;
+    ///
+    /// assert_eq!(has_key, true.into());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn has<T>(&self, key: T, context: &mut Context) -> JsResult<JsValue>
+    where
+        T: Into<JsValue>,
+    {
+        Map::has(&self.inner.clone().into(), &[key.into()], context)
+    }
+
+    /// Executes the provided callback function for each key-value pair within the [`JsMap`].
+    #[inline]
+    pub fn for_each(
+        &self,
+        callback: JsFunction,
+        this_arg: JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Map::for_each(
+            &self.inner.clone().into(),
+            &[callback.into(), this_arg],
+            context,
+        )
+    }
+
+    /// Executes the provided callback function for each key-value pair within the [`JsMap`].
+    #[inline]
+    pub fn for_each_native<F>(&self, f: F) -> JsResult<()>
+    where
+        F: FnMut(JsValue, JsValue) -> JsResult<()>,
+    {
+        let this = self.inner.clone().into();
+        Map::for_each_native(&this, f)
+    }
+
+    /// Executes the provided closure for each key-value pair within the [`JsMap`], stopping as soon
+    /// as it returns [`ControlFlow::Break`].
+    ///
+    /// Entries are visited in insertion order, and entries added by the closure are visited too.
+    /// Breaking out of the iteration leaves the map unmodified, which is useful for "find first
+    /// matching" patterns without visiting every entry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::ops::ControlFlow;
+    /// # use boa_engine::{
+    /// #    object::builtins::JsMap,
+    /// #    Context, JsValue, JsResult, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let js_map = JsMap::new(context);
+    /// js_map.set(js_string!("a"), 1, context)?;
+    /// js_map.set(js_string!("b"), 20, context)?;
+    /// js_map.set(js_string!("c"), 30, context)?;
+    ///
+    /// let mut first_large = None;
+    /// js_map.try_for_each_native(|key, value| {
+    ///     if value.as_number().is_some_and(|n| n > 10.0) {
+    ///         first_large = Some(key);
+    ///         return ControlFlow::Break(());
+    ///     }
+    ///     ControlFlow::Continue(())
+    /// })?;
+    ///
+    /// assert_eq!(first_large, Some(js_string!("b").into()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_for_each_native<F>(&self, mut f: F) -> JsResult<()>
+    where
+        F: FnMut(JsValue, JsValue) -> ControlFlow<()>,
+    {
+        // Deleted entries keep their index while the map is locked.
+        let _lock = self.borrow_map_data_mut().lock(self.inner.clone());
+
+        let mut index = 0;
+        loop {
+            let entry = {
+                let map = self.borrow_map_data();
+                if index >= map.full_len() {
+                    return Ok(());
+                }
+                map.get_index(index)
+                    .map(|(key, value)| (key.clone(), value.clone()))
+            };
+            index += 1;
+
+            if let Some((key, value)) = entry
+                && f(key, value).is_break()
+            {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Merges the entries of `other` into the [`JsMap`].
+    ///
+    /// Keys missing from the [`JsMap`] are appended with the value from `other`. For keys present
+    /// in both maps, `resolver` is called with the key, the current value and the value from
+    /// `other`, and its result is stored in place, preserving the insertion order of the existing key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::JsMap,
+    /// #    Context, JsValue, JsResult, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let scores = JsMap::new(context);
+    /// scores.set(js_string!("alice"), 3, context)?;
+    /// scores.set(js_string!("bob"), 5, context)?;
+    ///
+    /// let new_scores = JsMap::new(context);
+    /// new_scores.set(js_string!("bob"), 2, context)?;
+    /// new_scores.set(js_string!("carol"), 4, context)?;
+    ///
+    /// // Sum the scores of keys present in both maps.
+    /// scores.merge(
+    ///     &new_scores,
+    ///     |_, current, other| {
+    ///         let sum = current.as_number().unwrap_or_default() + other.as_number().unwrap_or_default();
+    ///         JsValue::from(sum)
+    ///     },
+    ///     context,
+    /// )?;
+    ///
+    /// assert_eq!(scores.get(js_string!("alice"), context)?, 3.into());
+    /// assert_eq!(scores.get(js_string!("bob"), context)?, 7.into());
+    /// assert_eq!(scores.get(js_string!("carol"), context)?, 4.into());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn merge<F>(&self, other: &JsMap, mut resolver: F, context: &mut Context) -> JsResult<()>
+    where
+        F: FnMut(&JsValue, JsValue, JsValue) -> JsValue,
+    {
+        // Take a snapshot first, since `other` could be the same map as `self`.
+        let entries = other
+            .borrow_map_data()
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect::<Vec<_>>();
+
+        for (key, value) in entries {
+            let current = self.borrow_map_data().get(&key).cloned();
+            let value = match current {
+                Some(current) => resolver(&key, current, value),
+                None => value,
+            };
+            let mutation = self.is_observed(context).then(|| MapMutation::Set {
+                key: key.clone(),
+                value: value.clone(),
+            });
+            self.borrow_map_data_mut().insert(key, value);
+            self.notify(mutation, context);
+        }
+
+        Ok(())
+    }
+
+    /// Computes the differences between the [`JsMap`] and `other`.
+    ///
+    /// Keys are matched the same way `Map` does, and values are compared using `SameValueZero`.
+    /// Keys only in `other` are listed as added and keys only in the [`JsMap`] as removed, while
+    /// keys whose value differs are listed as changed with both values. Every list keeps the
+    /// insertion order of the map it was taken from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::JsMap,
+    /// #    Context, JsValue, JsResult, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let before = JsMap::new(context);
+    /// before.set(js_string!("a"), 1, context)?;
+    /// before.set(js_string!("b"), 2, context)?;
+    /// before.set(js_string!("c"), f64::NAN, context)?;
+    ///
+    /// let after = JsMap::new(context);
+    /// after.set(js_string!("b"), 3, context)?;
+    /// after.set(js_string!("c"), f64::NAN, context)?;
+    /// after.set(js_string!("d"), 4, context)?;
+    ///
+    /// let diff = before.diff(&after, context)?;
+    /// assert_eq!(diff.added, [JsValue::from(js_string!("d"))]);
+    /// assert_eq!(diff.removed, [JsValue::from(js_string!("a"))]);
+    /// assert_eq!(
+    ///     diff.changed,
+    ///     [(js_string!("b").into(), 2.into(), 3.into())]
+    /// );
+    /// assert!(!diff.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn diff(&self, other: &JsMap, _context: &mut Context) -> JsResult<MapDiff> {
+        let current = self.borrow_map_data();
+        let other = other.borrow_map_data();
+
+        let mut diff = MapDiff::default();
+        for (key, value) in current.iter() {
+            match other.get(key) {
+                None => diff.removed.push(key.clone()),
+                Some(new) if !JsValue::same_value_zero(value, new) => {
+                    diff.changed.push((key.clone(), value.clone(), new.clone()));
+                }
+                Some(_) => {}
+            }
+        }
+        diff.added = other
+            .iter()
+            .filter(|(key, _)| !current.contains_key(key))
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        Ok(diff)
+    }
+
+    /// Creates a new [`JsMap`] with the same entries as this map, in the same insertion order.
+    ///
+    /// Unlike [`Clone::clone`], which returns another handle to the same `Map` object, this
+    /// creates a new `Map` whose entries can be modified independently. The copy is shallow:
+    /// keys and values are copied as [`JsValue`]s, so objects stored in the map are shared by
+    /// both maps.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::JsMap,
+    /// #    Context, JsValue, JsResult, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let map = JsMap::new(context);
+    /// map.set(js_string!("a"), 1, context)?;
+    ///
+    /// let alias = map.clone();
+    /// let copy = map.clone_deep(context)?;
+    ///
+    /// copy.set(js_string!("a"), 2, context)?;
+    /// copy.set(js_string!("b"), 3, context)?;
+    /// assert_eq!(map.get(js_string!("a"), context)?, 1.into());
+    /// assert_eq!(map.get_size(context)?, 1.into());
+    ///
+    /// alias.set(js_string!("a"), 4, context)?;
+    /// assert_eq!(map.get(js_string!("a"), context)?, 4.into());
+    /// assert_eq!(copy.get(js_string!("a"), context)?, 2.into());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn clone_deep(&self, context: &mut Context) -> JsResult<JsMap> {
+        let copy = JsMap::new(context);
+        {
+            let source = self.borrow_map_data();
+            let mut target = copy.borrow_map_data_mut();
+            for (key, value) in source.iter() {
+                target.insert(key.clone(), value.clone());
+            }
+        }
+        Ok(copy)
+    }
+
+    /// Returns the first key-value pair of the [`JsMap`], or `None` if the map is empty.
+    ///
+    /// Entries are ordered by insertion, not by key, so this is the oldest entry still in the map.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::JsMap,
+    /// #    Context, JsValue, JsResult, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let map = JsMap::new(context);
+    /// assert_eq!(map.first_entry(context)?, None);
+    ///
+    /// map.set(js_string!("b"), 1, context)?;
+    /// map.set(js_string!("a"), 2, context)?;
+    /// assert_eq!(
+    ///     map.first_entry(context)?,
+    ///     Some((js_string!("b").into(), 1.into()))
+    /// );
+    ///
+    /// map.delete(js_string!("b"), context)?;
+    /// assert_eq!(
+    ///     map.first_entry(context)?,
+    ///     Some((js_string!("a").into(), 2.into()))
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn first_entry(&self, _context: &mut Context) -> JsResult<Option<(JsValue, JsValue)>> {
+        Ok(self
+            .borrow_map_data()
+            .iter()
+            .next()
+            .map(|(k, v)| (k.clone(), v.clone())))
+    }
+
+    /// Returns the last key-value pair of the [`JsMap`], or `None` if the map is empty.
+    ///
+    /// Entries are ordered by insertion, not by key, so this is the most recently inserted entry.
+    /// Updating the value of an existing key does not move it to the end.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::JsMap,
+    /// #    Context, JsValue, JsResult, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let map = JsMap::new(context);
+    /// assert_eq!(map.last_entry(context)?, None);
+    ///
+    /// map.set(js_string!("b"), 1, context)?;
+    /// map.set(js_string!("a"), 2, context)?;
+    /// map.set(js_string!("b"), 3, context)?;
+    /// assert_eq!(
+    ///     map.last_entry(context)?,
+    ///     Some((js_string!("a").into(), 2.into()))
+    /// );
+    ///
+    /// map.delete(js_string!("a"), context)?;
+    /// assert_eq!(
+    ///     map.last_entry(context)?,
+    ///     Some((js_string!("b").into(), 3.into()))
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn last_entry(&self, _context: &mut Context) -> JsResult<Option<(JsValue, JsValue)>> {
+        let map = self.borrow_map_data();
+        Ok((0..map.full_len())
+            .rev()
+            .find_map(|index| map.get_index(index))
+            .map(|(k, v)| (k.clone(), v.clone())))
+    }
+
+    /// Returns a snapshot of the entries of the [`JsMap`], sorted with the comparator `cmp`.
+    ///
+    /// The map itself is not modified and keeps its insertion order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::JsMap,
+    /// #    Context, JsValue, JsResult, js_string
+    /// # };
+    /// # use std::cmp::Ordering;
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let map = JsMap::new(context);
+    /// map.set(2, js_string!("two"), context)?;
+    /// map.set(10, js_string!("ten"), context)?;
+    /// map.set(1, js_string!("one"), context)?;
+    ///
+    /// let sorted = map.entries_sorted_by(
+    ///     |(a, _), (b, _)| {
+    ///         b.as_number()
+    ///             .partial_cmp(&a.as_number())
+    ///             .unwrap_or(Ordering::Equal)
+    ///     },
+    ///     context,
+    /// )?;
+    ///
+    /// assert_eq!(
+    ///     sorted,
+    ///     vec![
+    ///         (10.into(), js_string!("ten").into()),
+    ///         (2.into(), js_string!("two").into()),
+    ///         (1.into(), js_string!("one").into()),
+    ///     ]
+    /// );
+    ///
+    /// // The map keeps its insertion order.
+    /// assert_eq!(map.first_entry(context)?, Some((2.into(), js_string!("two").into())));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn entries_sorted_by<F>(
+        &self,
+        cmp: F,
+        _context: &mut Context,
+    ) -> JsResult<Vec<(JsValue, JsValue)>>
+    where
+        F: FnMut(&(JsValue, JsValue), &(JsValue, JsValue)) -> Ordering,
+    {
+        let mut entries = self
+            .borrow_map_data()
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect::<Vec<_>>();
+        entries.sort_by(cmp);
+        Ok(entries)
+    }
+
+    /// Returns the keys of the entries that satisfy `pred`, in insertion order.
+    ///
+    /// `pred` is called with the key and value of every entry. The map is not modified, and
+    /// since the entries are copied before calling `pred`, it is safe for `pred` to access the
+    /// map through another handle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::JsMap,
+    /// #    Context, JsValue, JsResult, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let stock = JsMap::new(context);
+    /// stock.set(js_string!("apples"), 0, context)?;
+    /// stock.set(js_string!("pears"), 4, context)?;
+    /// stock.set(js_string!("plums"), 0, context)?;
+    ///
+    /// let sold_out = stock.keys_matching(|_, count| count.as_number() == Some(0.0), context)?;
+    /// assert_eq!(
+    ///     sold_out,
+    ///     [JsValue::from(js_string!("apples")), js_string!("plums").into()]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn keys_matching<F>(&self, mut pred: F, _context: &mut Context) -> JsResult<Vec<JsValue>>
+    where
+        F: FnMut(&JsValue, &JsValue) -> bool,
+    {
+        let entries = self
+            .borrow_map_data()
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect::<Vec<_>>();
+        Ok(entries
+            .into_iter()
+            .filter(|(k, v)| pred(k, v))
+            .map(|(k, _)| k)
+            .collect())
+    }
+
+    /// Returns the number of entries that satisfy `pred`.
+    ///
+    /// `pred` is called with the key and value of every entry, in insertion order. The map is not
+    /// modified, and like [`JsMap::keys_matching`], `pred` may access the map through another
+    /// handle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::JsMap,
+    /// #    Context, JsValue, JsResult, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let flags = JsMap::new(context);
+    /// flags.set(js_string!("verbose"), true, context)?;
+    /// flags.set(js_string!("quiet"), false, context)?;
+    /// flags.set(js_string!("color"), true, context)?;
+    ///
+    /// assert_eq!(flags.count_where(|_, enabled| enabled.to_boolean(), context)?, 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn count_where<F>(&self, mut pred: F, _context: &mut Context) -> JsResult<usize>
+    where
+        F: FnMut(&JsValue, &JsValue) -> bool,
+    {
+        let entries = self
+            .borrow_map_data()
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect::<Vec<_>>();
+        Ok(entries.iter().filter(|(k, v)| pred(k, v)).count())
+    }
+
+    /// Returns the sum of the values of the [`JsMap`], converting each of them with `ToNumber`.
+    ///
+    /// This is useful for maps used as numeric accumulators. An empty map sums to `0`.
+    ///
+    /// Values that convert to `NaN`, like `undefined` or non-numeric strings, are not errors:
+    /// `NaN` propagates through the sum, so the result is `NaN` if any value converts to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any value cannot be converted to a number, like symbols and `BigInt`s,
+    /// or if converting an object throws.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::JsMap,
+    /// #    Context, JsValue, JsResult, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let totals = JsMap::new(context);
+    /// totals.set(js_string!("apples"), 3, context)?;
+    /// totals.set(js_string!("pears"), 1.5, context)?;
+    /// totals.set(js_string!("plums"), js_string!("2"), context)?;
+    ///
+    /// assert_eq!(totals.sum_values(context)?, 6.5);
+    ///
+    /// totals.set(js_string!("figs"), JsValue::undefined(), context)?;
+    /// assert!(totals.sum_values(context)?.is_nan());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sum_values(&self, context: &mut Context) -> JsResult<f64> {
+        // Converting objects can run arbitrary code, which could mutate the map.
+        let values = self
+            .borrow_map_data()
+            .iter()
+            .map(|(_, v)| v.clone())
+            .collect::<Vec<_>>();
+        let mut sum = 0.0;
+        for value in values {
+            sum += value.to_number(context)?;
+        }
+        Ok(sum)
+    }
+
+    /// Converts the [`JsMap`] into a JSON object, converting its values with
+    /// [`JsValue::to_json`].
+    ///
+    /// Entries with an `undefined` value are skipped, as they are for plain objects.
+    ///
+    /// Only available with the `deser` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if any key is not a string, since JSON object keys must be strings,
+    /// or if any value cannot be converted to JSON.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::JsMap,
+    /// #    Context, JsValue, JsResult, js_string
+    /// # };
+    /// # use serde_json::json;
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let js_map = JsMap::new(context);
+    /// js_map.set(js_string!("name"), js_string!("Boa"), context)?;
+    /// js_map.set(js_string!("stars"), 5, context)?;
+    ///
+    /// assert_eq!(
+    ///     js_map.to_json(context)?,
+    ///     json!({ "name": "Boa", "stars": 5 })
+    /// );
+    ///
+    /// js_map.set(1, js_string!("one"), context)?;
+    /// assert!(js_map.to_json(context).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "deser")]
+    pub fn to_json(&self, context: &mut Context) -> JsResult<serde_json::Value> {
+        let entries = self
+            .borrow_map_data()
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect::<Vec<_>>();
+
+        let mut object = serde_json::Map::with_capacity(entries.len());
+        for (key, value) in entries {
+            let Some(key) = key.as_string() else {
+                return Err(JsNativeError::typ()
+                    .with_message(format!(
+                        "cannot convert map to JSON: keys must be strings, found a key of type `{}`",
+                        key.type_of()
+                    ))
+                    .into());
+            };
+            if let Some(value) = value.to_json(context)? {
+                object.insert(key.to_std_string_escaped(), value);
+            }
+        }
+
+        Ok(serde_json::Value::Object(object))
+    }
+
+    /// Returns a new [`JsMapIterator`] object that yields the `value` for each element within the [`JsMap`] in insertion order.
+    #[inline]
+    pub fn values(&self, context: &mut Context) -> JsResult<JsMapIterator> {
         let iterator_record = Map::values(&self.inner.clone().into(), &[], context)?
             .get_iterator(IteratorHint::Sync, context)?;
         let map_iterator_object = iterator_record.iterator();
@@ -422,6 +1661,95 @@ impl JsMap {
     }
 }
 
+/// Normalizes a `-0` key to `+0`, as `Map.prototype.set` does.
+fn normalize_key(key: JsValue) -> JsValue {
+    match key.as_number() {
+        Some(n) if n.is_zero() => JsValue::new(0),
+        _ => key,
+    }
+}
+
+/// The differences between two maps, as computed by [`JsMap::diff`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MapDiff {
+    /// The keys that are only in the new map.
+    pub added: Vec<JsValue>,
+    /// The keys that are only in the old map.
+    pub removed: Vec<JsValue>,
+    /// The keys in both maps with different values, as `(key, old, new)`.
+    pub changed: Vec<(JsValue, JsValue, JsValue)>,
+}
+
+impl MapDiff {
+    /// Returns `true` if both maps had the same entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// A mutation of a [`JsMap`], as reported to the callbacks installed by [`JsMap::observe`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum MapMutation {
+    /// An entry was inserted or updated.
+    Set {
+        /// The key of the entry.
+        key: JsValue,
+        /// The new value of the entry.
+        value: JsValue,
+    },
+    /// An entry was removed.
+    Delete {
+        /// The key of the removed entry.
+        key: JsValue,
+    },
+    /// All entries were removed.
+    Clear,
+}
+
+type MapObserver = Box<dyn FnMut(MapMutation)>;
+
+/// The callbacks installed with [`JsMap::observe`] in a context, for each observed map.
+///
+/// Maps are held weakly, so observing a map doesn't keep it alive. Unobserved maps have no entry.
+#[derive(Default)]
+pub(crate) struct MapObservers {
+    maps: Vec<(WeakGc<ErasedVTableObject>, Rc<RefCell<Vec<MapObserver>>>)>,
+}
+
+impl MapObservers {
+    /// Gets the observers of `map`, if any were installed.
+    fn get(&self, map: &JsObject) -> Option<Rc<RefCell<Vec<MapObserver>>>> {
+        self.maps.iter().find_map(|(weak, observers)| {
+            weak.upgrade()
+                .is_some_and(|object| Gc::ptr_eq(&object, map.inner()))
+                .then(|| observers.clone())
+        })
+    }
+
+    /// Gets the observers of `map`, creating an empty list for it if none were installed.
+    ///
+    /// This also drops the observers of maps that were garbage collected.
+    fn get_or_insert(&mut self, map: &JsObject) -> Rc<RefCell<Vec<MapObserver>>> {
+        self.maps.retain(|(weak, _)| weak.is_upgradable());
+        if let Some(observers) = self.get(map) {
+            return observers;
+        }
+        let observers = Rc::default();
+        self.maps
+            .push((WeakGc::new(map.inner()), Rc::clone(&observers)));
+        observers
+    }
+}
+
+impl fmt::Debug for MapObservers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapObservers")
+            .field("maps", &self.maps.len())
+            .finish()
+    }
+}
+
 impl From<JsMap> for JsObject {
     #[inline]
     fn from(o: JsMap) -> Self {
@@ -456,3 +1784,57 @@ impl TryFromJs for JsMap {
         }
     }
 }
+
+/// Extension trait to collect an iterator of key-value pairs into a new [`JsMap`].
+///
+/// [`FromIterator`] cannot be implemented for [`JsMap`], since creating a map requires a
+/// [`Context`].
+///
+/// # Example
+///
+/// ```
+/// # use boa_engine::{
+/// #    object::builtins::{CollectJsMap, JsMap},
+/// #    Context, JsResult, js_string
+/// # };
+/// # fn main() -> JsResult<()> {
+/// # let context = &mut Context::default();
+/// let map: JsMap = [("a", 1), ("b", 2), ("c", 3)]
+///     .into_iter()
+///     .filter(|(_, value)| value % 2 == 1)
+///     .map(|(key, value)| (js_string!(key), value))
+///     .collect_js_map(context)?;
+///
+/// assert_eq!(map.get_size(context)?, 2.into());
+/// assert_eq!(map.get(js_string!("c"), context)?, 3.into());
+/// assert!(!map.has(js_string!("b"), context)?.to_boolean());
+/// # Ok(())
+/// # }
+/// ```
+pub trait CollectJsMap {
+    /// Collects the key-value pairs into a new [`JsMap`], in iteration order.
+    ///
+    /// Later pairs overwrite the values of earlier pairs with the same key, like
+    /// [`JsMap::set`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if inserting any of the pairs fails.
+    fn collect_js_map(self, context: &mut Context) -> JsResult<JsMap>;
+}
+
+impl<I, K, V> CollectJsMap for I
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Into<JsValue>,
+    V: Into<JsValue>,
+{
+    fn collect_js_map(self, context: &mut Context) -> JsResult<JsMap> {
+        let iter = self.into_iter();
+        let map = JsMap::with_capacity(iter.size_hint().0, context);
+        for (key, value) in iter {
+            map.set(key, value, context)?;
+        }
+        Ok(map)
+    }
+}
@@ -1,7 +1,7 @@
 //! A Rust API wrapper for Boa's `MapIterator` Builtin ECMAScript Object
 use crate::{
-    Context, JsResult, JsValue, builtins::map::MapIterator, error::JsNativeError, object::JsObject,
-    value::TryFromJs,
+    Context, JsResult, JsValue, builtins::map::MapIterator, error::JsNativeError, js_string,
+    object::JsObject, value::TryFromJs,
 };
 
 use boa_gc::{Finalize, Trace};
@@ -30,6 +30,47 @@ impl JsMapIterator {
     pub fn next(&self, context: &mut Context) -> JsResult<JsValue> {
         MapIterator::next(&self.inner.clone().into(), &[], context)
     }
+
+    /// Advances the `JsMapIterator` until it is done, collecting all the remaining values.
+    ///
+    /// The values are the keys or values of the map for iterators returned by [`JsMap::keys`] and
+    /// [`JsMap::values`], and `[key, value]` arrays for iterators returned by [`JsMap::entries`].
+    ///
+    /// [`JsMap::keys`]: crate::object::builtins::JsMap::keys
+    /// [`JsMap::values`]: crate::object::builtins::JsMap::values
+    /// [`JsMap::entries`]: crate::object::builtins::JsMap::entries
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::JsMap,
+    /// #    Context, JsResult, JsValue, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let map = JsMap::new(context);
+    /// map.set(js_string!("a"), 1, context)?;
+    /// map.set(js_string!("b"), 2, context)?;
+    ///
+    /// let values = map.values(context)?.collect_remaining(context)?;
+    /// assert_eq!(values, [JsValue::new(1), JsValue::new(2)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn collect_remaining(self, context: &mut Context) -> JsResult<Vec<JsValue>> {
+        let mut values = Vec::new();
+        loop {
+            let result = self.next(context)?;
+            let result = result.as_object().ok_or_else(|| {
+                JsNativeError::typ().with_message("iterator result is not an object")
+            })?;
+            if result.get(js_string!("done"), context)?.to_boolean() {
+                return Ok(values);
+            }
+            values.push(result.get(js_string!("value"), context)?);
+        }
+    }
 }
 
 impl From<JsMapIterator> for JsObject {
@@ -431,6 +431,11 @@ impl JsPromise {
 
     /// Gets the current state of the promise.
     ///
+    /// The state is read synchronously from the internal slots of the promise; this never runs
+    /// or schedules any jobs. A promise whose settlement depends on queued jobs will keep
+    /// returning [`PromiseState::Pending`] until those jobs are run (e.g. with
+    /// [`Context::run_jobs`]).
+    ///
     /// # Examples
     ///
     /// ```
@@ -37,6 +37,32 @@ impl JsSet {
         Set::get_size(&self.inner.clone().into())
     }
 
+    /// Returns `true` if the `Set` has no elements.
+    ///
+    /// Same as JavaScript's `set.size === 0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{object::builtins::JsSet, Context, JsResult, js_string};
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let set = JsSet::new(context);
+    /// assert!(set.is_empty()?);
+    ///
+    /// set.add(js_string!("foo"), context)?;
+    /// assert!(!set.is_empty()?);
+    ///
+    /// set.delete(js_string!("foo"), context)?;
+    /// assert!(set.is_empty()?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> JsResult<bool> {
+        Ok(self.size()? == 0)
+    }
+
     /// Appends value to the Set object.
     /// Returns the Set object with added value.
     ///
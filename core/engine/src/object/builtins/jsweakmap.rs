@@ -0,0 +1,180 @@
+//! A Rust API wrapper for Boa's `WeakMap` Builtin ECMAScript Object
+use std::ops::Deref;
+
+use boa_gc::{Finalize, Trace};
+
+use crate::{
+    Context, JsResult, JsValue,
+    builtins::weak_map::{NativeWeakMap, WeakMap},
+    error::JsNativeError,
+    object::JsObject,
+    value::TryFromJs,
+};
+
+/// `JsWeakMap` provides a wrapper for Boa's implementation of the ECMAScript `WeakMap` object.
+///
+/// Keys must be objects, and they are held weakly: an entry is dropped once its key is garbage
+/// collected, which makes `JsWeakMap` a good fit for caches keyed by JavaScript objects.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_engine::{
+/// #    object::{builtins::JsWeakMap, JsObject},
+/// #    Context, JsValue, JsResult, js_string
+/// # };
+/// # fn main() -> JsResult<()> {
+/// # let context = &mut Context::default();
+/// let cache = JsWeakMap::new(context);
+/// let key = JsObject::with_null_proto();
+///
+/// cache.set(key.clone(), js_string!("cached"), context)?;
+/// assert!(cache.has(key.clone(), context)?);
+/// assert_eq!(cache.get(key.clone(), context)?, js_string!("cached").into());
+///
+/// assert!(cache.delete(key.clone(), context)?);
+/// assert!(!cache.has(key, context)?);
+///
+/// // Keys must be objects.
+/// assert!(cache.set(js_string!("key"), 1, context).is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsWeakMap {
+    inner: JsObject,
+}
+
+impl JsWeakMap {
+    /// Creates a new empty [`JsWeakMap`] object.
+    #[inline]
+    pub fn new(context: &mut Context) -> Self {
+        let prototype = context.intrinsics().constructors().weak_map().prototype();
+        let inner = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            NativeWeakMap::new(),
+        );
+
+        Self { inner }
+    }
+
+    /// Creates a [`JsWeakMap`] from a valid [`JsObject`], or returns a `TypeError` if the provided
+    /// object is not a `WeakMap`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::{builtins::{JsMap, JsWeakMap}, JsObject},
+    /// #    Context, JsResult,
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let weak_map = JsWeakMap::new(context);
+    /// let object: JsObject = weak_map.into();
+    /// assert!(JsWeakMap::from_object(object).is_ok());
+    ///
+    /// // A `Map` is not a `WeakMap`.
+    /// let map = JsMap::new(context);
+    /// assert!(JsWeakMap::from_object(map.into()).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn from_object(object: JsObject) -> JsResult<Self> {
+        if object.is::<NativeWeakMap>() {
+            Ok(Self { inner: object })
+        } else {
+            Err(JsNativeError::typ()
+                .with_message("object is not a WeakMap")
+                .into())
+        }
+    }
+
+    /// Inserts a new entry into the [`JsWeakMap`] object.
+    ///
+    /// Returns a `TypeError` if `key` is not an object, like `WeakMap.prototype.set`.
+    ///
+    /// Same as JavaScript's `weakMap.set(key, value)`.
+    pub fn set<K, V>(&self, key: K, value: V, context: &mut Context) -> JsResult<JsValue>
+    where
+        K: Into<JsValue>,
+        V: Into<JsValue>,
+    {
+        WeakMap::set(
+            &self.inner.clone().into(),
+            &[key.into(), value.into()],
+            context,
+        )
+    }
+
+    /// Gets the value associated with the specified key within the [`JsWeakMap`], or `undefined`
+    /// if the key does not exist.
+    ///
+    /// Same as JavaScript's `weakMap.get(key)`.
+    pub fn get<K>(&self, key: K, context: &mut Context) -> JsResult<JsValue>
+    where
+        K: Into<JsValue>,
+    {
+        WeakMap::get(&self.inner.clone().into(), &[key.into()], context)
+    }
+
+    /// Returns a boolean asserting whether a value has been associated to the key in the
+    /// [`JsWeakMap`] or not.
+    ///
+    /// Same as JavaScript's `weakMap.has(key)`.
+    pub fn has<K>(&self, key: K, context: &mut Context) -> JsResult<bool>
+    where
+        K: Into<JsValue>,
+    {
+        WeakMap::has(&self.inner.clone().into(), &[key.into()], context)
+            .map(|value| value.to_boolean())
+    }
+
+    /// Removes the entry associated with the key, returning `true` if an entry was removed.
+    ///
+    /// Same as JavaScript's `weakMap.delete(key)`.
+    pub fn delete<K>(&self, key: K, context: &mut Context) -> JsResult<bool>
+    where
+        K: Into<JsValue>,
+    {
+        WeakMap::delete(&self.inner.clone().into(), &[key.into()], context)
+            .map(|value| value.to_boolean())
+    }
+}
+
+impl From<JsWeakMap> for JsObject {
+    #[inline]
+    fn from(o: JsWeakMap) -> Self {
+        o.inner.clone()
+    }
+}
+
+impl From<JsWeakMap> for JsValue {
+    #[inline]
+    fn from(o: JsWeakMap) -> Self {
+        o.inner.clone().into()
+    }
+}
+
+impl Deref for JsWeakMap {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl TryFromJs for JsWeakMap {
+    fn try_from_js(value: &JsValue, _context: &mut Context) -> JsResult<Self> {
+        if let Some(o) = value.as_object() {
+            Self::from_object(o.clone())
+        } else {
+            Err(JsNativeError::typ()
+                .with_message("value is not a WeakMap object")
+                .into())
+        }
+    }
+}
@@ -281,6 +281,17 @@ impl<T: ?Sized> Object<T> {
         &mut self.properties
     }
 
+    /// Transitions the object to a unique (non-shared) shape, if it doesn't have one already.
+    ///
+    /// Objects normally only get a unique shape after a large number of shape transitions, so
+    /// this is mostly useful for reproducing shape-related issues.
+    ///
+    /// This is not part of the public API; it only exists for the CLI's `$boa.shape` debug object.
+    #[doc(hidden)]
+    pub fn force_unique_shape(&mut self) {
+        self.properties.shape = self.properties.shape.to_unique();
+    }
+
     /// Inserts a field in the object `properties` without checking if it's writable.
     ///
     /// If a field was already in the object with the same name, then `true` is returned
@@ -103,6 +103,16 @@ impl Shape {
         None
     }
 
+    /// Returns a [`UniqueShape`] with the same properties and prototype as this [`Shape`].
+    ///
+    /// If the shape is already unique, a clone of it is returned.
+    pub(crate) fn to_unique(&self) -> Self {
+        match &self.inner {
+            Inner::Shared(shape) => shape.to_unique().into(),
+            Inner::Unique(_) => self.clone(),
+        }
+    }
+
     /// Create an insert property transitions returning the new transitioned [`Shape`].
     ///
     /// NOTE: This assumes that there is no property with the given key!
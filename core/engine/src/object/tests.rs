@@ -1,4 +1,4 @@
-use crate::{JsNativeErrorKind, TestAction, run_test_actions};
+use crate::{JsNativeErrorKind, TestAction, js_string, run_test_actions};
 use indoc::indoc;
 
 #[test]
@@ -38,3 +38,24 @@ fn object_properties_return_order() {
         ),
     ]);
 }
+
+#[test]
+fn force_unique_shape_keeps_properties() {
+    run_test_actions([
+        TestAction::run("var o = { x: 10, y: 20 };"),
+        TestAction::inspect_context(|context| {
+            let o = context
+                .global_object()
+                .get(js_string!("o"), context)
+                .unwrap();
+            let o = o.as_object().unwrap();
+            assert!(o.borrow().shape().is_shared());
+
+            o.borrow_mut().force_unique_shape();
+            assert!(o.borrow().shape().is_unique());
+        }),
+        TestAction::run("o.z = 30; delete o.x;"),
+        TestAction::assert("o.y === 20 && o.z === 30 && !('x' in o)"),
+        TestAction::assert_eq("Object.keys(o).join()", js_string!("y,z")),
+    ]);
+}
@@ -22,7 +22,7 @@ use crate::{
     module::Module,
     object::shape::RootShape,
 };
-use boa_gc::{Finalize, Gc, GcRef, GcRefCell, GcRefMut, Trace};
+use boa_gc::{Finalize, Gc, GcRef, GcRefCell, GcRefMut, Trace, WeakGc};
 
 /// Representation of a Realm.
 ///
@@ -225,4 +225,24 @@ impl Realm {
         let ptr: *const _ = &raw const *self.inner;
         ptr.cast()
     }
+
+    /// Creates a weak reference to this realm, which doesn't keep it alive.
+    pub(crate) fn downgrade(&self) -> WeakRealm {
+        WeakRealm {
+            inner: WeakGc::new(&self.inner),
+        }
+    }
+}
+
+/// A weak reference to a [`Realm`], created by [`Realm::downgrade`].
+#[derive(Clone)]
+pub(crate) struct WeakRealm {
+    inner: WeakGc<Inner>,
+}
+
+impl WeakRealm {
+    /// Gets the realm, if it's still alive.
+    pub(crate) fn upgrade(&self) -> Option<Realm> {
+        self.inner.upgrade().map(|inner| Realm { inner })
+    }
 }
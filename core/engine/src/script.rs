@@ -8,10 +8,19 @@
 //! [spec]: https://tc39.es/ecma262/#sec-scripts
 //! [script]: https://tc39.es/ecma262/#sec-script-records
 
-use std::path::{Path, PathBuf};
+use std::{
+    collections::VecDeque,
+    hash::{Hash, Hasher},
+    ops::ControlFlow,
+    path::{Path, PathBuf},
+};
 
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHasher};
 
+use boa_ast::{
+    expression::TaggedTemplate,
+    visitor::{VisitWith, Visitor},
+};
 use boa_gc::{Finalize, Gc, GcRefCell, Trace};
 use boa_parser::{Parser, Source, source::ReadChar};
 
@@ -19,7 +28,8 @@ use crate::{
     Context, HostDefined, JsResult, JsString, JsValue, Module, SpannedSourceText,
     bytecompiler::{ByteCompiler, global_declaration_instantiation_context},
     js_string,
-    realm::Realm,
+    optimizer::OptimizerOptions,
+    realm::{Realm, WeakRealm},
     spanned_source_text::SourceText,
     vm::{ActiveRunnable, CallFrame, CallFrameFlags, CodeBlock},
 };
@@ -54,6 +64,80 @@ struct Inner {
     path: Option<PathBuf>,
 }
 
+/// The maximum number of scripts kept by a [`ParseCache`].
+const MAX_CACHED_SCRIPTS: usize = 64;
+
+/// A cache of parsed scripts, keyed by a hash of their source text and of the options used to
+/// parse them.
+///
+/// Once full, the oldest script is evicted to make room for a new one. See
+/// [`Context::parse_cached`].
+#[derive(Default)]
+pub(crate) struct ParseCache {
+    scripts: FxHashMap<u64, CachedScript>,
+    insertion_order: VecDeque<u64>,
+    hits: u64,
+}
+
+impl ParseCache {
+    /// Gets the number of times a cached AST was reused.
+    pub(crate) const fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Removes all the cached scripts.
+    pub(crate) fn clear(&mut self) {
+        self.scripts.clear();
+        self.insertion_order.clear();
+    }
+
+    /// Gets the cached script parsed from `source` with the given `options` in `realm`.
+    fn get(
+        &self,
+        hash: u64,
+        source: &str,
+        options: ParseOptions,
+        realm: &Realm,
+    ) -> Option<&CachedScript> {
+        self.scripts.get(&hash).filter(|cached| {
+            &*cached.source == source
+                && cached.options == options
+                && cached.realm.upgrade().as_ref() == Some(realm)
+        })
+    }
+
+    /// Caches a script, evicting the oldest one if the cache is full.
+    fn insert(&mut self, hash: u64, script: CachedScript) {
+        if self.scripts.insert(hash, script).is_some() {
+            return;
+        }
+        self.insertion_order.push_back(hash);
+        if self.insertion_order.len() > MAX_CACHED_SCRIPTS
+            && let Some(oldest) = self.insertion_order.pop_front()
+        {
+            self.scripts.remove(&oldest);
+        }
+    }
+}
+
+/// The options of a context that change the AST produced for a script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct ParseOptions {
+    strict: bool,
+    optimizer_options: OptimizerOptions,
+}
+
+/// A script AST stored in the [`ParseCache`].
+struct CachedScript {
+    /// The source text the AST was parsed from.
+    source: Box<str>,
+    options: ParseOptions,
+    /// The realm whose scope was used to analyze the AST.
+    realm: WeakRealm,
+    code: boa_ast::Script,
+    source_text: SourceText,
+}
+
 impl Script {
     /// Gets the realm of this script.
     #[must_use]
@@ -69,6 +153,12 @@ impl Script {
         &self.inner.host_defined
     }
 
+    /// Gets the parsed code of this script.
+    #[cfg(test)]
+    pub(crate) fn code(&self) -> &boa_ast::Script {
+        &self.inner.source
+    }
+
     /// Gets the loaded modules of this script.
     pub(crate) fn loaded_modules(&self) -> &GcRefCell<FxHashMap<JsString, Module>> {
         &self.inner.loaded_modules
@@ -85,6 +175,54 @@ impl Script {
         context: &mut Context,
     ) -> JsResult<Self> {
         let path = src.path().map(Path::to_path_buf);
+        let (code, source_text) = Self::parse_code(src, context)?;
+        let realm = realm.unwrap_or_else(|| context.realm().clone());
+        Ok(Self::from_parts(code, source_text, realm, path))
+    }
+
+    /// Parses the provided `src` as an ECMAScript script in the current realm, reusing the AST
+    /// of a previous parse of the same source text if there is one in the parse cache of the
+    /// context.
+    pub(crate) fn parse_cached(src: &str, context: &mut Context) -> JsResult<Self> {
+        let options = ParseOptions {
+            strict: context.is_strict(),
+            optimizer_options: context.optimizer_options(),
+        };
+        let mut hasher = FxHasher::default();
+        (src, options).hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let realm = context.realm().clone();
+        if let Some(cached) = context.parse_cache.get(hash, src, options, &realm) {
+            let (code, source_text) = (cached.code.clone(), cached.source_text.clone());
+            context.parse_cache.hits += 1;
+            return Ok(Self::from_parts(code, source_text, realm, None));
+        }
+
+        let (code, source_text) = Self::parse_code(Source::from_bytes(src), context)?;
+
+        // Every evaluation of a tagged template gets the template object of its parse node, so
+        // the AST of a script with tagged templates cannot be shared by several scripts.
+        if !contains_tagged_template(&code) {
+            context.parse_cache.insert(
+                hash,
+                CachedScript {
+                    source: src.into(),
+                    options,
+                    realm: realm.downgrade(),
+                    code: code.clone(),
+                    source_text: source_text.clone(),
+                },
+            );
+        }
+        Ok(Self::from_parts(code, source_text, realm, None))
+    }
+
+    /// Parses the provided `src` into an optimized AST, in the scope of the current realm.
+    fn parse_code<R: ReadChar>(
+        src: Source<'_, R>,
+        context: &mut Context,
+    ) -> JsResult<(boa_ast::Script, SourceText)> {
         let mut parser = Parser::new(src);
         parser.set_identifier(context.next_parser_identifier());
         if context.is_strict() {
@@ -96,11 +234,19 @@ impl Script {
             context.optimize_statement_list(code.statements_mut());
         }
 
-        let source_text = SourceText::new(source);
+        Ok((code, SourceText::new(source)))
+    }
 
-        Ok(Self {
+    /// Creates a new script record from its parsed code.
+    fn from_parts(
+        code: boa_ast::Script,
+        source_text: SourceText,
+        realm: Realm,
+        path: Option<PathBuf>,
+    ) -> Self {
+        Self {
             inner: Gc::new(Inner {
-                realm: realm.unwrap_or_else(|| context.realm().clone()),
+                realm,
                 source: code,
                 source_text,
                 codeblock: GcRefCell::default(),
@@ -108,7 +254,7 @@ impl Script {
                 host_defined: HostDefined::default(),
                 path,
             }),
-        })
+        }
     }
 
     /// Compiles the codeblock of this script.
@@ -243,3 +389,19 @@ impl Script {
         self.inner.source_text.clone()
     }
 }
+
+/// Returns `true` if the script contains a tagged template, including in nested functions.
+fn contains_tagged_template(code: &boa_ast::Script) -> bool {
+    /// Visitor that breaks on the first tagged template.
+    struct TaggedTemplateVisitor;
+
+    impl<'ast> Visitor<'ast> for TaggedTemplateVisitor {
+        type BreakTy = ();
+
+        fn visit_tagged_template(&mut self, _: &'ast TaggedTemplate) -> ControlFlow<()> {
+            ControlFlow::Break(())
+        }
+    }
+
+    code.visit_with(&mut TaggedTemplateVisitor).is_break()
+}
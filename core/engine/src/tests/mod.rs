@@ -14,7 +14,9 @@ mod promise;
 mod spread;
 mod to_string;
 
-use crate::{JsNativeErrorKind, JsValue, TestAction, run_test_actions};
+use crate::{
+    Context, JsNativeErrorKind, JsValue, TestAction, optimizer::OptimizerOptions, run_test_actions,
+};
 
 #[test]
 fn length_correct_value_on_string_literal() {
@@ -26,6 +28,73 @@ fn empty_let_decl_undefined() {
     run_test_actions([TestAction::assert_eq("let a; a", JsValue::undefined())]);
 }
 
+#[test]
+fn run_script_from_file() {
+    run_test_actions([
+        TestAction::run_file("tests/assets/counter.js"),
+        TestAction::assert_eq("counter.value", 12),
+        TestAction::assert_eq("counter.increment()", 13),
+    ]);
+}
+
+#[test]
+fn parse_cached_reuses_ast() {
+    let context = &mut Context::default();
+    let source = "var x = 1; x + 1";
+
+    let first = context.parse_cached(source).unwrap();
+    assert_eq!(context.parse_cache_hits(), 0);
+    let second = context.parse_cached(source).unwrap();
+    assert_eq!(context.parse_cache_hits(), 1);
+    assert_eq!(first.code().statements(), second.code().statements());
+    assert_eq!(second.evaluate(context).unwrap(), JsValue::new(2));
+
+    context.parse_cached("x + 2").unwrap();
+    assert_eq!(context.parse_cache_hits(), 1);
+
+    context.clear_parse_cache();
+    context.parse_cached(source).unwrap();
+    assert_eq!(context.parse_cache_hits(), 1);
+}
+
+#[test]
+fn parse_cached_keys_and_bound() {
+    let context = &mut Context::default();
+
+    // Scripts parsed with other optimizer options are not reused.
+    context.parse_cached("1 + 1").unwrap();
+    context.set_optimizer_options(OptimizerOptions::empty());
+    context.parse_cached("1 + 1").unwrap();
+    assert_eq!(context.parse_cache_hits(), 0);
+    context.parse_cached("1 + 1").unwrap();
+    assert_eq!(context.parse_cache_hits(), 1);
+
+    // Each parse of a tagged template needs its own template object.
+    let source = "(s => s)`a`";
+    let first = context
+        .parse_cached(source)
+        .unwrap()
+        .evaluate(context)
+        .unwrap();
+    let second = context
+        .parse_cached(source)
+        .unwrap()
+        .evaluate(context)
+        .unwrap();
+    assert_eq!(context.parse_cache_hits(), 1);
+    assert_ne!(first, second);
+
+    // The oldest scripts are evicted once the cache is full.
+    context.clear_parse_cache();
+    for i in 0..=64 {
+        context.parse_cached(&format!("{i}")).unwrap();
+    }
+    context.parse_cached("64").unwrap();
+    assert_eq!(context.parse_cache_hits(), 2);
+    context.parse_cached("0").unwrap();
+    assert_eq!(context.parse_cache_hits(), 2);
+}
+
 #[test]
 fn semicolon_expression_stop() {
     run_test_actions([TestAction::assert_eq(
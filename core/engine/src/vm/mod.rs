@@ -880,6 +880,24 @@ impl Context {
 
         Ok(())
     }
+
+    /// Charges one tick to the budget set by [`Context::eval_with_budget`], if any.
+    ///
+    /// Ticks are charged on loop iterations and function calls, the same places where the
+    /// runtime limits are checked, so that unbudgeted code doesn't pay for it on every
+    /// instruction.
+    pub(crate) fn charge_tick_budget(&mut self) -> JsResult<()> {
+        if let Some(budget) = &mut self.tick_budget {
+            if *budget == 0 {
+                return Err(JsNativeError::runtime_limit()
+                    .with_message("exceeded the tick budget")
+                    .into());
+            }
+            *budget -= 1;
+        }
+
+        Ok(())
+    }
 }
 
 /// Yields once to the executor.
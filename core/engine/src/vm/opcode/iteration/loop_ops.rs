@@ -4,7 +4,7 @@ use crate::{Context, JsResult, vm::opcode::Operation};
 /// `IncrementLoopIteration` implements the Opcode Operation for `Opcode::IncrementLoopIteration`.
 ///
 /// Operation:
-///  - Increment loop iteration count.
+///  - Increment loop iteration count and charge the tick budget.
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct IncrementLoopIteration;
 
@@ -22,7 +22,7 @@ impl IncrementLoopIteration {
         }
 
         frame.loop_iteration_count = previous_iteration_count.wrapping_add(1);
-        Ok(())
+        context.charge_tick_budget()
     }
 }
 
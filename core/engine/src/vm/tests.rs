@@ -1,5 +1,5 @@
 use crate::{
-    Context, JsNativeErrorKind, JsValue, TestAction, js_string, property::Attribute,
+    Context, JsNativeErrorKind, JsValue, Script, TestAction, js_string, property::Attribute,
     run_test_actions,
 };
 use boa_macros::js_str;
@@ -278,6 +278,74 @@ fn loop_runtime_limit() {
     ]);
 }
 
+#[test]
+fn tick_budget_interrupts_script() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let script = Script::parse(
+            Source::from_bytes("try { while (true) {} } catch { 'caught' }"),
+            None,
+            context,
+        )
+        .unwrap();
+        let err = context
+            .eval_with_budget(&script, 100)
+            .unwrap_err()
+            .try_native(context)
+            .unwrap();
+        assert_eq!(err.kind, JsNativeErrorKind::RuntimeLimit);
+        assert_eq!(err.message(), "exceeded the tick budget");
+
+        // The budget only applies to the interrupted evaluation.
+        let script = Script::parse(
+            Source::from_bytes("let i = 0; while (i < 1000) { i++ } i"),
+            None,
+            context,
+        )
+        .unwrap();
+        assert_eq!(script.evaluate(context).unwrap(), JsValue::new(1000));
+
+        // Calls are charged as well, so unbounded recursion is interrupted by the budget.
+        let script = Script::parse(
+            Source::from_bytes("function f() { return f() } f()"),
+            None,
+            context,
+        )
+        .unwrap();
+        let err = context
+            .eval_with_budget(&script, 100)
+            .unwrap_err()
+            .try_native(context)
+            .unwrap();
+        assert_eq!(err.message(), "exceeded the tick budget");
+    })]);
+}
+
+#[test]
+fn nested_tick_budget_charges_outer_budget() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let inner = Script::parse(
+            Source::from_bytes("for (let i = 0; i < 50; i++) {}"),
+            None,
+            context,
+        )
+        .unwrap();
+        context.tick_budget = Some(80);
+        context.eval_with_budget(&inner, 1_000).unwrap();
+        let remaining = context.tick_budget.unwrap();
+        assert!(remaining <= 80 - 50, "remaining budget: {remaining}");
+
+        // The inner evaluation can't spend more than what's left of the outer budget.
+        let err = context
+            .eval_with_budget(&inner, 1_000)
+            .unwrap_err()
+            .try_native(context)
+            .unwrap();
+        assert_eq!(err.message(), "exceeded the tick budget");
+        assert_eq!(context.tick_budget, Some(0));
+        context.tick_budget = None;
+    })]);
+}
+
 #[test]
 fn recursion_runtime_limit() {
     run_test_actions([
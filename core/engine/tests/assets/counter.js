@@ -0,0 +1,16 @@
+function makeCounter(start) {
+  let count = start;
+  return {
+    increment() {
+      count += 1;
+      return count;
+    },
+    get value() {
+      return count;
+    },
+  };
+}
+
+var counter = makeCounter(10);
+counter.increment();
+counter.increment();
@@ -160,6 +160,17 @@ impl core::fmt::Display for JSInternedStrRef<'_, '_> {
     }
 }
 
+/// Statistics about the memory used by an [`Interner`], returned by [`Interner::stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct InternerStats {
+    /// The number of interned symbols, including the static strings every interner starts with.
+    pub symbols: usize,
+
+    /// The approximate number of bytes allocated to store the interned strings and to look them
+    /// up. Static strings are not counted, since they are not stored by the interner.
+    pub bytes: usize,
+}
+
 /// The string interner for Boa.
 #[derive(Debug, Default)]
 pub struct Interner {
@@ -185,6 +196,27 @@ impl Interner {
         }
     }
 
+    /// Creates a new [`Interner`] with the given strings already interned.
+    ///
+    /// This is useful when parsing many sources that share common identifiers, like `console` or
+    /// `log`, since the warmed-up interner can be reused without hashing those strings again.
+    /// The symbols of the prefilled strings can be obtained with [`Interner::get`], and they stay
+    /// the same for the whole lifetime of the returned interner: interning any of these strings
+    /// later on, with [`Interner::get_or_intern`] or [`Interner::get_or_intern_static`], returns
+    /// the same symbol.
+    ///
+    /// # Panics
+    ///
+    /// If the interner interns the maximum number of strings possible by the chosen symbol type.
+    #[must_use]
+    pub fn with_prefilled(strings: &[&str]) -> Self {
+        let mut interner = Self::with_capacity(strings.len());
+        for &string in strings {
+            interner.get_or_intern(string);
+        }
+        interner
+    }
+
     /// Returns the number of strings interned by the interner.
     #[inline]
     #[must_use]
@@ -201,6 +233,17 @@ impl Interner {
         COMMON_STRINGS_UTF8.is_empty() && self.utf16_interner.is_empty()
     }
 
+    /// Returns statistics about the memory used by the [`Interner`].
+    ///
+    /// This is useful to monitor the growth of an interner reused across many sources.
+    #[must_use]
+    pub fn stats(&self) -> InternerStats {
+        InternerStats {
+            symbols: self.len(),
+            bytes: self.utf8_interner.allocated_bytes() + self.utf16_interner.allocated_bytes(),
+        }
+    }
+
     /// Returns the symbol for the given string if any.
     ///
     /// Can be used to query if a string has already been interned without interning.
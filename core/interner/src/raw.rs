@@ -58,6 +58,18 @@ impl<Char> RawInterner<Char> {
     pub(super) fn is_empty(&self) -> bool {
         self.spans.is_empty()
     }
+
+    /// Returns the approximate number of bytes allocated by the interner, including the storage of
+    /// the interned strings and the lookup tables.
+    pub(super) fn allocated_bytes(&self) -> usize {
+        let strings =
+            self.head.capacity() + self.full.iter().map(FixedString::capacity).sum::<usize>();
+        let span_size = size_of::<InternedStr<Char>>();
+
+        strings * size_of::<Char>()
+            + self.spans.capacity() * span_size
+            + self.symbol_cache.capacity() * (span_size + size_of::<usize>())
+    }
 }
 
 impl<Char> RawInterner<Char>
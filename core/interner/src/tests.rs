@@ -130,3 +130,48 @@ fn check_capacity() {
 
     assert!(interner.resolve(sym).is_none());
 }
+
+#[test]
+fn check_prefilled() {
+    let strings = ["console", "log", "this", "console"];
+    let interner = Interner::with_prefilled(&strings);
+
+    // `this` is a common string, and `console` is only interned once.
+    assert_eq!(interner.len(), COMMON_STRINGS_UTF8.len() + 2);
+
+    let console = interner
+        .get("console")
+        .expect("`console` must be prefilled");
+    let log = interner.get("log").expect("`log` must be prefilled");
+    let this = interner.get("this").expect("`this` must be prefilled");
+    assert_eq!(this, Sym::THIS);
+
+    let mut interner = interner;
+    interner.get_or_intern("unrelated");
+    assert_eq!(
+        interner.get_or_intern_static("console", utf16!("console")),
+        console
+    );
+    assert_eq!(interner.get_or_intern_static("log", utf16!("log")), log);
+    assert_eq!(interner.get_or_intern(utf16!("log")), log);
+    assert_eq!(interner.get_or_intern_static("this", utf16!("this")), this);
+    assert_eq!(interner.len(), COMMON_STRINGS_UTF8.len() + 3);
+}
+
+#[test]
+fn check_stats() {
+    let mut interner = Interner::default();
+    let initial = interner.stats();
+    assert_eq!(initial.symbols, COMMON_STRINGS_UTF8.len());
+
+    interner.get_or_intern("first string");
+    interner.get_or_intern(utf16!("second string"));
+    let grown = interner.stats();
+    assert_eq!(grown.symbols, initial.symbols + 2);
+    assert!(grown.bytes > initial.bytes);
+
+    interner.get_or_intern("first string");
+    interner.get_or_intern("second string");
+    interner.get_or_intern("arguments");
+    assert_eq!(interner.stats(), grown);
+}
@@ -18,6 +18,9 @@ pub(crate) trait ErrorContext {
     /// Gets the context of the error, if any.
     #[allow(dead_code)]
     fn context(&self) -> Option<&'static str>;
+
+    /// Records the production enclosing the error, e.g. `"if statement condition"`.
+    fn enclosed_in(self, production: &'static str) -> Self;
 }
 
 impl<T> ErrorContext for ParseResult<T> {
@@ -25,6 +28,10 @@ impl<T> ErrorContext for ParseResult<T> {
         self.map_err(|e| e.set_context(context))
     }
 
+    fn enclosed_in(self, production: &'static str) -> Self {
+        self.map_err(|e| e.enclosed_in(production))
+    }
+
     fn context(&self) -> Option<&'static str> {
         self.as_ref().err().and_then(Error::context)
     }
@@ -38,7 +45,11 @@ impl From<LexError> for Error {
 }
 
 /// An enum which represents errors encountered during parsing an expression
+///
+/// New kinds of errors may be added in the future, so matches on this enum must include a
+/// wildcard arm.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
     /// When it expected a certain kind of token, but got another as part of something
     Expected {
@@ -84,6 +95,20 @@ pub enum Error {
         /// Position of the source code where the error occurred.
         position: Position,
     },
+
+    /// An error that occurred inside of one or more enclosing productions.
+    ///
+    /// This is displayed as the inner error with the productions inserted before its position,
+    /// like `... in if statement body at line 1, col 10`. The productions are available through
+    /// [`Error::enclosing`], and the inner error through [`Error::innermost`], which is what code
+    /// checking for a specific kind of error, like [`Error::AbruptEnd`], should match on.
+    Nested {
+        /// The error that occurred.
+        err: Box<Error>,
+
+        /// The enclosing productions, from the innermost to the outermost.
+        enclosing: Vec<&'static str>,
+    },
 }
 
 impl Error {
@@ -96,16 +121,56 @@ impl Error {
                 span,
                 ..
             } => Self::expected(expected, found, span, new_context),
+            Self::Nested { err, enclosing } => Self::Nested {
+                err: Box::new(err.set_context(new_context)),
+                enclosing,
+            },
             e => e,
         }
     }
 
     /// Gets the context of the error, if any.
-    const fn context(&self) -> Option<&'static str> {
-        if let Self::Expected { context, .. } = self {
-            Some(context)
+    fn context(&self) -> Option<&'static str> {
+        match self {
+            Self::Expected { context, .. } => Some(context),
+            Self::Nested { err, .. } => err.context(),
+            _ => None,
+        }
+    }
+
+    /// Records a production enclosing the error.
+    fn enclosed_in(self, production: &'static str) -> Self {
+        match self {
+            Self::Nested { err, mut enclosing } => {
+                enclosing.push(production);
+                Self::Nested { err, enclosing }
+            }
+            err => Self::Nested {
+                err: Box::new(err),
+                enclosing: vec![production],
+            },
+        }
+    }
+
+    /// Gets the productions enclosing the error, from the innermost to the outermost.
+    #[must_use]
+    pub fn enclosing(&self) -> &[&'static str] {
+        if let Self::Nested { enclosing, .. } = self {
+            enclosing
+        } else {
+            &[]
+        }
+    }
+
+    /// Gets the error without the productions enclosing it.
+    ///
+    /// This is the error itself, unless it is an [`Error::Nested`] error.
+    #[must_use]
+    pub fn innermost(&self) -> &Self {
+        if let Self::Nested { err, .. } = self {
+            err
         } else {
-            None
+            self
         }
     }
 
@@ -178,14 +243,15 @@ impl Error {
     }
 }
 
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl Error {
+    /// Formats the message of the error, without its position.
+    fn fmt_message(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Expected {
                 expected,
                 found,
-                span,
                 context,
+                ..
             } => {
                 write!(f, "expected ")?;
                 match &**expected {
@@ -204,33 +270,99 @@ impl fmt::Display for Error {
                         }
                     }
                 }
-                write!(
-                    f,
-                    ", got '{found}' in {context} at line {}, col {}",
-                    span.start().line_number(),
-                    span.start().column_number()
-                )
+                write!(f, ", got '{found}' in {context}")
+            }
+            Self::Unexpected { found, message, .. } => {
+                write!(f, "unexpected token '{found}', {message}")
             }
-            Self::Unexpected {
-                found,
-                span,
-                message,
-            } => write!(
-                f,
-                "unexpected token '{found}', {message} at line {}, col {}",
-                span.start().line_number(),
-                span.start().column_number()
-            ),
             Self::AbruptEnd => f.write_str("abrupt end"),
-            Self::General { message, position } => write!(
+            Self::General { message, .. } => f.write_str(message),
+            Self::Lex { err } => match err {
+                LexError::IO(e) => write!(f, "{e}"),
+                LexError::Syntax(message, _) => f.write_str(message),
+                LexError::Unterminated(kind, _) => f.write_str(kind.message()),
+            },
+            Self::Nested { err, .. } => err.fmt_message(f),
+        }
+    }
+
+    /// Gets the position where the error occurred, if any.
+    fn position(&self) -> Option<Position> {
+        match self {
+            Self::Expected { span, .. } | Self::Unexpected { span, .. } => Some(span.start()),
+            Self::General { position, .. }
+            | Self::Lex {
+                err: LexError::Syntax(_, position) | LexError::Unterminated(_, position),
+            } => Some(*position),
+            Self::AbruptEnd | Self::Lex { err: LexError::IO(_) } => None,
+            Self::Nested { err, .. } => err.position(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_message(f)?;
+        for production in self.enclosing() {
+            write!(f, " in {production}")?;
+        }
+        if let Some(position) = self.position() {
+            write!(
                 f,
-                "{message} at line {}, col {}",
+                " at line {}, col {}",
                 position.line_number(),
                 position.column_number()
-            ),
-            Self::Lex { err } => err.fmt(f),
+            )?;
         }
+        Ok(())
     }
 }
 
 impl std::error::Error for Error {}
+
+/// A non-fatal diagnostic encountered during parsing.
+///
+/// Warnings don't abort parsing, but point at source code that may not behave as intended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    message: Box<str>,
+    position: Position,
+}
+
+impl Warning {
+    /// Creates a new warning with the given message and position.
+    pub(crate) fn new<S, P>(message: S, position: P) -> Self
+    where
+        S: Into<Box<str>>,
+        P: Into<Position>,
+    {
+        Self {
+            message: message.into(),
+            position: position.into(),
+        }
+    }
+
+    /// Gets the message of the warning.
+    #[must_use]
+    pub const fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Gets the position of the source code where the warning was produced.
+    #[must_use]
+    pub const fn position(&self) -> Position {
+        self.position
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {}, col {}",
+            self.message,
+            self.position.line_number(),
+            self.position.column_number()
+        )
+    }
+}
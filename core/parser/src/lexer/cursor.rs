@@ -1,18 +1,38 @@
 //! Boa's lexer cursor that manages the input byte stream.
 
-use crate::source::{ReadChar, UTF8Input};
+use crate::{
+    Warning,
+    source::{ReadChar, UTF8Input},
+};
 use boa_ast::{LinearPosition, Position, PositionGroup, SourceText};
-use std::io::{self, Error, ErrorKind};
+use std::{
+    io::{self, Error, ErrorKind},
+    sync::Arc,
+};
 
 /// Cursor over the source code.
 #[derive(Debug)]
+#[allow(clippy::struct_excessive_bools)]
 pub(super) struct Cursor<R> {
     iter: R,
     pos: Position,
     module: bool,
     strict: bool,
+    force_strict_numbers: bool,
+    normalize_line_endings: bool,
+    after_carriage_return: bool,
     peeked: [Option<u32>; 4],
+    replay: Vec<u32>,
+    replay_pos: usize,
+    checkpoints: Arc<()>,
     source_collector: SourceText,
+    warnings: Vec<Warning>,
+    #[cfg(feature = "numeric-suffixes")]
+    numeric_suffixes: Vec<Box<str>>,
+    #[cfg(feature = "numeric-suffixes")]
+    numeric_suffix_pos: Option<Position>,
+    #[cfg(feature = "exact-decimals")]
+    preserve_decimals: bool,
 }
 
 impl<R> Cursor<R> {
@@ -34,6 +54,12 @@ impl<R> Cursor<R> {
         self.source_collector.cur_linear_position()
     }
 
+    /// Gets the source text collected from the given linear position up to the cursor.
+    #[cfg(feature = "source-text")]
+    pub(super) fn source_since(&self, start: LinearPosition) -> String {
+        String::from_utf16_lossy(self.source_collector.get_code_points_from_pos(start))
+    }
+
     pub(super) fn take_source(&mut self) -> SourceText {
         let replace_with = SourceText::with_capacity(0);
         std::mem::replace(&mut self.source_collector, replace_with)
@@ -53,7 +79,14 @@ impl<R> Cursor<R> {
     }
 
     /// Returns if strict mode is currently active.
-    pub(super) const fn strict(&self) -> bool {
+    ///
+    /// Tokenizers should use this for any decision that depends on strict mode, like rejecting
+    /// legacy octal literals. Strict mode is enabled from the start for modules, but for scripts
+    /// and functions it is set by the parser only after the whole `"use strict"` directive
+    /// statement has been parsed, including its semicolon. Any token the parser peeked before
+    /// that point was lexed in sloppy mode: if the directive has no semicolon, this is the case
+    /// for the first token of the next line.
+    pub(crate) const fn is_strict(&self) -> bool {
         self.strict
     }
 
@@ -62,8 +95,31 @@ impl<R> Cursor<R> {
         self.strict = strict;
     }
 
-    /// Returns if the module mode is currently active.
-    pub(super) const fn module(&self) -> bool {
+    /// Returns if strict mode rules are enforced for numeric literals regardless of the current
+    /// strict mode.
+    pub(super) const fn force_strict_numbers(&self) -> bool {
+        self.force_strict_numbers
+    }
+
+    /// Sets if strict mode rules are enforced for numeric literals.
+    pub(super) fn set_force_strict_numbers(&mut self, force_strict_numbers: bool) {
+        self.force_strict_numbers = force_strict_numbers;
+    }
+
+    /// Returns if `\r\n` and `\r` line endings are read as `\n`.
+    pub(super) const fn normalize_line_endings(&self) -> bool {
+        self.normalize_line_endings
+    }
+
+    /// Sets if `\r\n` and `\r` line endings are read as `\n`.
+    pub(super) fn set_normalize_line_endings(&mut self, normalize_line_endings: bool) {
+        self.normalize_line_endings = normalize_line_endings;
+    }
+
+    /// Returns if the source is being lexed as a module.
+    ///
+    /// This is set before lexing the first token, and doesn't change for the rest of the source.
+    pub(crate) const fn in_module(&self) -> bool {
         self.module
     }
 
@@ -72,6 +128,145 @@ impl<R> Cursor<R> {
         self.module = module;
         self.strict = module;
     }
+
+    /// Records a warning at the given position.
+    pub(super) fn push_warning<S>(&mut self, message: S, position: Position)
+    where
+        S: Into<Box<str>>,
+    {
+        self.warnings.push(Warning::new(message, position));
+    }
+
+    /// Gets the warnings recorded so far.
+    pub(super) fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+
+    /// Registers an identifier allowed right after a numeric literal.
+    #[cfg(feature = "numeric-suffixes")]
+    pub(super) fn register_numeric_suffix(&mut self, suffix: Box<str>) {
+        if !self.is_numeric_suffix(&suffix) {
+            self.numeric_suffixes.push(suffix);
+        }
+    }
+
+    /// Returns if any identifier is allowed right after a numeric literal.
+    #[cfg(feature = "numeric-suffixes")]
+    pub(super) fn has_numeric_suffixes(&self) -> bool {
+        !self.numeric_suffixes.is_empty()
+    }
+
+    /// Returns if `suffix` is allowed right after a numeric literal.
+    #[cfg(feature = "numeric-suffixes")]
+    pub(super) fn is_numeric_suffix(&self, suffix: &str) -> bool {
+        self.numeric_suffixes.iter().any(|s| &**s == suffix)
+    }
+
+    /// Marks that the next token directly follows a numeric literal, and must be a registered
+    /// suffix.
+    #[cfg(feature = "numeric-suffixes")]
+    pub(super) fn set_numeric_suffix_pos(&mut self, pos: Position) {
+        self.numeric_suffix_pos = Some(pos);
+    }
+
+    /// Takes the position of the pending numeric suffix, if the next token directly follows a
+    /// numeric literal.
+    #[cfg(feature = "numeric-suffixes")]
+    pub(super) fn take_numeric_suffix_pos(&mut self) -> Option<Position> {
+        self.numeric_suffix_pos.take()
+    }
+
+    /// Sets whether the exact decimal value of rational numeric literals is recorded.
+    #[cfg(feature = "exact-decimals")]
+    pub(super) fn set_preserve_decimals(&mut self, preserve_decimals: bool) {
+        self.preserve_decimals = preserve_decimals;
+    }
+
+    /// Returns if the exact decimal value of rational numeric literals is recorded.
+    #[cfg(feature = "exact-decimals")]
+    pub(super) const fn preserve_decimals(&self) -> bool {
+        self.preserve_decimals
+    }
+}
+
+/// A saved state of the lexer, created by [`Lexer::checkpoint`][super::Lexer::checkpoint].
+///
+/// Restoring it with [`Lexer::restore`][super::Lexer::restore] rewinds the lexer to the point
+/// where the checkpoint was taken, while [`Lexer::release`][super::Lexer::release] discards it.
+/// Dropping the state also discards it, so the lexer knows when no checkpoint is alive anymore.
+#[derive(Debug)]
+pub struct CursorState {
+    _alive: Arc<()>,
+    replay_pos: usize,
+    pos: Position,
+    module: bool,
+    strict: bool,
+    peeked: [Option<u32>; 4],
+    linear_pos: LinearPosition,
+    warnings: usize,
+    #[cfg(feature = "numeric-suffixes")]
+    numeric_suffix_pos: Option<Position>,
+}
+
+impl<R> Cursor<R> {
+    /// Saves the current state of the cursor.
+    ///
+    /// While a checkpoint is alive, every character read from the input is recorded, so that it
+    /// can be replayed after restoring a checkpoint.
+    pub(super) fn checkpoint(&mut self) -> CursorState {
+        CursorState {
+            _alive: Arc::clone(&self.checkpoints),
+            replay_pos: self.replay_pos,
+            pos: self.pos,
+            module: self.module,
+            strict: self.strict,
+            peeked: self.peeked,
+            linear_pos: self.linear_pos(),
+            warnings: self.warnings.len(),
+            #[cfg(feature = "numeric-suffixes")]
+            numeric_suffix_pos: self.numeric_suffix_pos,
+        }
+    }
+
+    /// Rewinds the cursor to a previously saved state, consuming it.
+    pub(super) fn restore(&mut self, state: CursorState) {
+        self.replay_pos = state.replay_pos;
+        self.pos = state.pos;
+        self.module = state.module;
+        self.strict = state.strict;
+        self.peeked = state.peeked;
+        self.source_collector.truncate(state.linear_pos);
+        self.warnings.truncate(state.warnings);
+        #[cfg(feature = "numeric-suffixes")]
+        {
+            self.numeric_suffix_pos = state.numeric_suffix_pos;
+        }
+        self.release(state);
+    }
+
+    /// Returns if any checkpoint is alive.
+    fn has_checkpoints(&self) -> bool {
+        Arc::strong_count(&self.checkpoints) > 1
+    }
+
+    /// Returns the number of characters kept in memory to be replayed after a restore.
+    #[cfg(test)]
+    pub(super) fn recorded_chars(&self) -> usize {
+        self.replay.len()
+    }
+
+    /// Discards a previously saved state.
+    ///
+    /// Once no checkpoint is alive, the recorded characters that were already replayed are
+    /// dropped, and new characters are no longer recorded.
+    pub(super) fn release(&mut self, state: CursorState) {
+        drop(state);
+        if !self.has_checkpoints() {
+            self.replay.drain(..self.replay_pos);
+            self.replay.shrink_to_fit();
+            self.replay_pos = 0;
+        }
+    }
 }
 
 impl<R: ReadChar> Cursor<R> {
@@ -81,10 +276,71 @@ impl<R: ReadChar> Cursor<R> {
             iter: inner,
             pos: Position::new(1, 1),
             strict: false,
+            force_strict_numbers: false,
+            normalize_line_endings: false,
+            after_carriage_return: false,
             module: false,
             peeked: [None; 4],
+            replay: Vec::new(),
+            replay_pos: 0,
+            checkpoints: Arc::default(),
             source_collector: SourceText::default(),
+            warnings: Vec::new(),
+            #[cfg(feature = "numeric-suffixes")]
+            numeric_suffixes: Vec::new(),
+            #[cfg(feature = "numeric-suffixes")]
+            numeric_suffix_pos: None,
+            #[cfg(feature = "exact-decimals")]
+            preserve_decimals: false,
+        }
+    }
+
+    /// Reads the next character from the input, replaying the characters read after a restored
+    /// checkpoint first.
+    fn read_char(&mut self) -> io::Result<Option<u32>> {
+        if let Some(&ch) = self.replay.get(self.replay_pos) {
+            self.replay_pos += 1;
+            if !self.has_checkpoints() && self.replay_pos == self.replay.len() {
+                self.replay = Vec::new();
+                self.replay_pos = 0;
+            }
+            return Ok(Some(ch));
+        }
+
+        let ch = self.read_input_char()?;
+        if self.has_checkpoints() {
+            if let Some(ch) = ch {
+                self.replay.push(ch);
+                self.replay_pos += 1;
+            }
+        } else if !self.replay.is_empty() {
+            // The last checkpoint was dropped without being released, and every recorded
+            // character was replayed.
+            self.replay = Vec::new();
+            self.replay_pos = 0;
         }
+        Ok(ch)
+    }
+
+    /// Reads the next character from the input, normalizing line endings if enabled.
+    ///
+    /// The normalization happens before the character is recorded or peeked, so every part of
+    /// the lexer sees the same characters.
+    fn read_input_char(&mut self) -> io::Result<Option<u32>> {
+        let mut ch = self.iter.next_char()?;
+        if !self.normalize_line_endings {
+            return Ok(ch);
+        }
+
+        // '\n' following a '\r' was already read as part of the "\r\n" line ending.
+        if self.after_carriage_return && ch == Some(0xA) {
+            ch = self.iter.next_char()?;
+        }
+        self.after_carriage_return = ch == Some(0xD);
+        if self.after_carriage_return {
+            ch = Some(0xA);
+        }
+        Ok(ch)
     }
 
     /// Peeks the next n bytes, the maximum number of peeked bytes is 4 (n <= 4).
@@ -93,7 +349,7 @@ impl<R: ReadChar> Cursor<R> {
         let needs_peek = n as usize - peeked;
 
         for i in 0..needs_peek {
-            let next = self.iter.next_char()?;
+            let next = self.read_char()?;
             self.peeked[i + peeked] = next;
         }
 
@@ -106,7 +362,7 @@ impl<R: ReadChar> Cursor<R> {
             return Ok(Some(c));
         }
 
-        let next = self.iter.next_char()?;
+        let next = self.read_char()?;
         self.peeked[0] = next;
         Ok(next)
     }
@@ -186,7 +442,7 @@ impl<R: ReadChar> Cursor<R> {
             self.peeked.rotate_left(1);
             Some(c)
         } else {
-            self.iter.next_char()?
+            self.read_char()?
         };
 
         if let Some(ch) = ch {
@@ -9,7 +9,11 @@ use boa_ast::Position;
 use std::{error, fmt, io};
 
 /// An error that occurred during the lexing.
+///
+/// New kinds of errors may be added in the future, so matches on this enum must include a
+/// wildcard arm.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
     /// An IO error is raised to indicate an issue when the lexer is reading data that isn't
     /// related to the sourcecode itself.
@@ -22,6 +26,36 @@ pub enum Error {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard-syntaxerror
     Syntax(Box<str>, Position),
+
+    /// Indicates that the input ended abruptly inside of a construct, leaving it unterminated.
+    ///
+    /// The kind of the construct is kept, so it can be handled without inspecting the message.
+    Unterminated(UnterminatedKind, Position),
+}
+
+/// The kind of construct left unterminated by an [`Error::Unterminated`] error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnterminatedKind {
+    /// A spread punctuator, such as a lone `..` at the end of the input.
+    Spread,
+
+    /// The exponent of a numeric literal, such as `1e` or `1e+` at the end of the input.
+    Exponent,
+
+    /// The digits of a numeric literal after its base prefix, such as `0x` at the end of the input.
+    NumericBasePrefix,
+}
+
+impl UnterminatedKind {
+    /// Gets the error message describing the unterminated construct.
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::Spread => "Abrupt end: Expecting Token '.' as part of spread",
+            Self::Exponent => "Abrupt end: No exponential value found",
+            Self::NumericBasePrefix => "Abrupt end: No digit found after number base prefix",
+        }
+    }
 }
 
 impl From<io::Error> for Error {
@@ -41,6 +75,15 @@ impl Error {
     {
         Self::Syntax(err.into(), pos.into())
     }
+
+    /// Creates a new error for a construct left unterminated by the end of the input.
+    #[inline]
+    pub(crate) fn unterminated<P>(kind: UnterminatedKind, pos: P) -> Self
+    where
+        P: Into<Position>,
+    {
+        Self::Unterminated(kind, pos.into())
+    }
 }
 
 impl fmt::Display for Error {
@@ -54,6 +97,13 @@ impl fmt::Display for Error {
                 pos.line_number(),
                 pos.column_number()
             ),
+            Self::Unterminated(kind, pos) => write!(
+                f,
+                "{} at line {}, col {}",
+                kind.message(),
+                pos.line_number(),
+                pos.column_number()
+            ),
         }
     }
 }
@@ -63,7 +113,7 @@ impl error::Error for Error {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match self {
             Self::IO(err) => Some(err),
-            Self::Syntax(_, _) => None,
+            Self::Syntax(_, _) | Self::Unterminated(_, _) => None,
         }
     }
 }
@@ -88,6 +138,20 @@ mod tests {
         assert!(err.source().is_none());
     }
 
+    #[test]
+    fn unterminated() {
+        let err = Error::unterminated(UnterminatedKind::Exponent, Position::new(1, 3));
+        assert!(matches!(
+            err,
+            Error::Unterminated(UnterminatedKind::Exponent, pos) if pos == Position::new(1, 3)
+        ));
+        assert_eq!(
+            err.to_string(),
+            "Abrupt end: No exponential value found at line 1, col 3"
+        );
+        assert!(err.source().is_none());
+    }
+
     #[test]
     fn io() {
         let custom_error = io::Error::other("I/O error");
@@ -41,15 +41,22 @@ use self::{
     string::StringLiteral,
     template::TemplateLiteral,
 };
-use crate::source::{ReadChar, UTF8Input};
-use boa_ast::{PositionGroup, Punctuator};
+use crate::{
+    Source, Warning,
+    source::{ReadChar, UTF8Input},
+};
+use boa_ast::{Keyword, Position, PositionGroup, Punctuator};
 use boa_interner::Interner;
 
 pub use self::{
-    error::Error,
+    cursor::CursorState,
+    error::{Error, UnterminatedKind},
     token::{Token, TokenKind},
 };
 
+#[cfg(feature = "numeric-suffixes")]
+use self::token::ContainsEscapeSequence;
+
 trait Tokenizer<R> {
     /// Lexes the next token.
     fn lex(
@@ -82,7 +89,7 @@ impl<R> Lexer<R> {
 
     /// Returns if strict mode is currently active.
     pub(super) const fn strict(&self) -> bool {
-        self.cursor.strict()
+        self.cursor.is_strict()
     }
 
     /// Sets the current strict mode.
@@ -90,9 +97,137 @@ impl<R> Lexer<R> {
         self.cursor.set_strict(strict);
     }
 
+    /// Returns if strict mode rules are enforced for numeric literals regardless of the
+    /// current strict mode.
+    pub const fn force_strict_numbers(&self) -> bool {
+        self.cursor.force_strict_numbers()
+    }
+
+    /// Sets if strict mode rules are enforced for numeric literals.
+    ///
+    /// When set, legacy octal literals (`0777`) and decimal literals with leading zeros (`08`)
+    /// are rejected even if no `"use strict"` directive was found. This is useful when linting
+    /// code that may later run in a strict context.
+    pub fn set_force_strict_numbers(&mut self, force_strict_numbers: bool) {
+        self.cursor.set_force_strict_numbers(force_strict_numbers);
+    }
+
+    /// Returns if `\r\n` and `\r` line endings are normalized to `\n`.
+    pub const fn normalize_line_endings(&self) -> bool {
+        self.cursor.normalize_line_endings()
+    }
+
+    /// Sets if `\r\n` and `\r` line endings are normalized to `\n`.
+    ///
+    /// When set, both sequences are read as a single `\n` before they reach the lexer, so linear
+    /// positions count every line ending as one character, and the collected source text, used
+    /// by `Function.prototype.toString`, only contains `\n` line endings. This gives the same
+    /// positions and source text for a file regardless of the line endings used by the platform
+    /// that wrote it. This must be set before lexing the first token.
+    ///
+    /// Line and column positions are the same with or without this option, since every line
+    /// terminator sequence (`\n`, `\r\n`, `\r`, `U+2028` and `U+2029`) starts a new line at
+    /// column `1`. `U+2028` and `U+2029` are never normalized, since they are allowed inside
+    /// string literals, where `\n` is not.
+    pub fn set_normalize_line_endings(&mut self, normalize_line_endings: bool) {
+        self.cursor
+            .set_normalize_line_endings(normalize_line_endings);
+    }
+
+    /// Gets the warnings produced by the lexer so far.
+    #[must_use]
+    pub fn warnings(&self) -> &[Warning] {
+        self.cursor.warnings()
+    }
+
+    /// Saves the current state of the lexer, so that it can later be rewound with
+    /// [`Lexer::restore`].
+    ///
+    /// This lets speculative parsers backtrack over any number of tokens without re-reading the
+    /// source from the start. A checkpoint is given back either to [`Lexer::restore`], or to
+    /// [`Lexer::release`] once the speculation succeeds. Dropping it is the same as releasing it.
+    ///
+    /// Readers are never seeked, so checkpoints also work for non-seekable readers like pipes and
+    /// sockets. Instead, while a checkpoint is alive, the lexer keeps every character it reads in
+    /// memory to replay it after a restore. This memory is only freed once all checkpoints are
+    /// given back, so a checkpoint should not be kept alive over a large part of the source.
+    ///
+    /// The goal symbol is not part of the checkpoint, since it's set by the parser before
+    /// lexing each token.
+    #[must_use]
+    pub fn checkpoint(&mut self) -> CursorState {
+        self.cursor.checkpoint()
+    }
+
+    /// Rewinds the lexer to a state saved with [`Lexer::checkpoint`].
+    ///
+    /// Any tokens lexed and warnings recorded since the checkpoint was taken are discarded.
+    pub fn restore(&mut self, state: CursorState) {
+        self.cursor.restore(state);
+    }
+
+    /// Discards a state saved with [`Lexer::checkpoint`] without rewinding the lexer.
+    pub fn release(&mut self, state: CursorState) {
+        self.cursor.release(state);
+    }
+
+    /// Registers an identifier that is allowed to directly follow a numeric literal, like `px`
+    /// in `5px`.
+    ///
+    /// By default, numeric literals must not be followed by identifier characters. Once a suffix
+    /// is registered, `5px` is lexed as a numeric literal followed by the identifier `px`, while
+    /// unregistered suffixes are still rejected. Note that suffixes starting with `e`, `E` or `n`
+    /// are read as part of the numeric literal.
+    #[cfg(feature = "numeric-suffixes")]
+    pub fn register_numeric_suffix<S>(&mut self, suffix: S)
+    where
+        S: Into<Box<str>>,
+    {
+        self.cursor.register_numeric_suffix(suffix.into());
+    }
+
+    /// Sets whether the exact decimal value of rational numeric literals is recorded.
+    ///
+    /// Numeric literals are always lexed as `f64` values, which cannot represent most decimals,
+    /// like `0.1`, exactly. Once enabled, tokens of rational literals also carry their normalized
+    /// decimal digits, available through [`Token::decimal`], so they can be converted to an exact
+    /// decimal type instead. This is disabled by default.
+    #[cfg(feature = "exact-decimals")]
+    pub fn set_preserve_decimals(&mut self, preserve_decimals: bool) {
+        self.cursor.set_preserve_decimals(preserve_decimals);
+    }
+
+    /// Checks that a token directly following a numeric literal is a registered suffix.
+    #[cfg(feature = "numeric-suffixes")]
+    fn check_numeric_suffix(
+        &self,
+        token: &Token,
+        position: Position,
+        interner: &Interner,
+    ) -> Result<(), Error> {
+        let is_suffix = match token.kind() {
+            TokenKind::IdentifierName((sym, ContainsEscapeSequence(false))) => interner
+                .resolve_expect(*sym)
+                .utf8()
+                .is_some_and(|suffix| self.cursor.is_numeric_suffix(suffix)),
+            _ => false,
+        };
+
+        if is_suffix {
+            Ok(())
+        } else {
+            Err(Error::syntax(number::NUMERIC_ADJACENCY_ERROR, position))
+        }
+    }
+
+    /// Gets the current position of the lexer in the source code.
+    pub(crate) const fn pos(&self) -> Position {
+        self.cursor.pos()
+    }
+
     /// Returns if module mode is currently active.
     pub(super) const fn module(&self) -> bool {
-        self.cursor.module()
+        self.cursor.in_module()
     }
 
     /// Signals that the goal symbol is a module
@@ -219,6 +354,9 @@ impl<R> Lexer<R> {
     where
         R: ReadChar,
     {
+        #[cfg(feature = "numeric-suffixes")]
+        let numeric_suffix_pos = self.cursor.take_numeric_suffix_pos();
+
         let mut start = self.cursor.pos_group();
         let Some(mut next_ch) = self.cursor.next_char()? else {
             return Ok(None);
@@ -351,6 +489,11 @@ impl<R> Lexer<R> {
                 }
             }?;
 
+            #[cfg(feature = "numeric-suffixes")]
+            if let Some(position) = numeric_suffix_pos {
+                self.check_numeric_suffix(&token, position, interner)?;
+            }
+
             Ok(Some(token))
         } else {
             Err(Error::syntax(
@@ -386,6 +529,108 @@ impl<R> Lexer<R> {
         }
     }
 
+    /// Lexes the tokens of a source one by one, without parsing it.
+    ///
+    /// This lets tools like syntax highlighters consume tokens lazily, passing the interner along
+    /// to the lexer on every step. The iterator stops at the end of the source, or after yielding
+    /// the first error. Comments are skipped, while line terminators are yielded as
+    /// [`TokenKind::LineTerminator`] tokens.
+    ///
+    /// Without a parser, the lexer cannot know if a `/` starts a regular expression or is a
+    /// division operator, so this is decided from the previous token: a `/` after an identifier,
+    /// a literal, `this`, `super`, `)`, `]`, `++` or `--` is a division. Braces are tracked to
+    /// find the end of template substitutions, so the rest of a template literal is lexed as such.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_interner::Interner;
+    /// # use boa_parser::{Lexer, Source, lexer::TokenKind};
+    /// let mut interner = Interner::default();
+    /// let tokens = Lexer::tokens(Source::from_bytes("a / b"), &mut interner)
+    ///     .collect::<Result<Vec<_>, _>>()
+    ///     .unwrap();
+    /// assert_eq!(tokens.len(), 3);
+    /// assert!(matches!(tokens[0].kind(), TokenKind::IdentifierName(_)));
+    /// ```
+    pub fn tokens<'a>(
+        source: Source<'_, R>,
+        interner: &'a mut Interner,
+    ) -> impl Iterator<Item = Result<Token, Error>> + 'a
+    where
+        R: ReadChar + 'a,
+    {
+        let mut lexer = Self::new(source.reader);
+        lexer.set_goal(InputElement::HashbangOrRegExp);
+        // Whether each open brace starts a template substitution.
+        let mut braces = Vec::new();
+        let mut done = false;
+
+        std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+
+            let mut token = match lexer.next(interner) {
+                Ok(Some(token)) => token,
+                Ok(None) => {
+                    done = true;
+                    return None;
+                }
+                Err(err) => {
+                    done = true;
+                    return Some(Err(err));
+                }
+            };
+
+            match token.kind() {
+                TokenKind::Punctuator(Punctuator::OpenBlock) => braces.push(false),
+                TokenKind::Punctuator(Punctuator::CloseBlock) if braces.pop() == Some(true) => {
+                    token = match lexer.lex_template(token.start_group(), interner) {
+                        Ok(token) => token,
+                        Err(err) => {
+                            done = true;
+                            return Some(Err(err));
+                        }
+                    };
+                }
+                _ => {}
+            }
+
+            match token.kind() {
+                TokenKind::TemplateMiddle(_) => braces.push(true),
+                TokenKind::LineTerminator => return Some(Ok(token)),
+                _ => {}
+            }
+
+            let ends_expression = matches!(
+                token.kind(),
+                TokenKind::BooleanLiteral(_)
+                    | TokenKind::IdentifierName(_)
+                    | TokenKind::PrivateIdentifier(_)
+                    | TokenKind::NullLiteral(_)
+                    | TokenKind::NumericLiteral(_)
+                    | TokenKind::StringLiteral(_)
+                    | TokenKind::TemplateNoSubstitution(_)
+                    | TokenKind::RegularExpressionLiteral(_, _)
+                    | TokenKind::Keyword((Keyword::This | Keyword::Super, _))
+                    | TokenKind::Punctuator(
+                        Punctuator::CloseParen
+                            | Punctuator::CloseBracket
+                            | Punctuator::Inc
+                            | Punctuator::Dec
+                    )
+            );
+            lexer.set_goal(if ends_expression {
+                InputElement::Div
+            } else {
+                InputElement::RegExp
+            });
+
+            Some(Ok(token))
+        })
+    }
+
     /// Performs the lexing of a template literal.
     pub(crate) fn lex_template(
         &mut self,
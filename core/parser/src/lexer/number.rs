@@ -1,9 +1,11 @@
 //! This module implements lexing for number literals (123, 787) used in ECMAScript.
 
-use crate::lexer::{Cursor, Error, Token, TokenKind, Tokenizer, token::Numeric};
+use crate::lexer::{Cursor, Error, Token, TokenKind, Tokenizer, UnterminatedKind, token::Numeric};
 use crate::source::ReadChar;
-use boa_ast::PositionGroup;
+use boa_ast::{Position, PositionGroup};
 use boa_interner::Interner;
+use icu_properties::props::GeneralCategory;
+use icu_properties::{CodePointMapData, CodePointMapDataBorrowed};
 use num_bigint::BigInt;
 use num_traits::{ToPrimitive, Zero};
 use std::str;
@@ -34,7 +36,7 @@ impl NumberLiteral {
 ///
 /// This structure helps with identifying what numerical type it is and what base is it.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum NumericKind {
+pub(super) enum NumericKind {
     Rational,
     Integer(u32),
     BigInt(u32),
@@ -58,6 +60,54 @@ impl NumericKind {
     }
 }
 
+/// Maximum number of digits accumulated by the decimal integer fast path.
+///
+/// Any integer with this many decimal digits fits in an `i64`.
+const FAST_INTEGER_MAX_DIGITS: usize = 18;
+
+/// Result of the decimal integer fast path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FastInteger {
+    /// The literal is a plain decimal integer with the given value.
+    Complete(i64),
+    /// The literal continues past the consumed digits (separators, decimal point, exponent,
+    /// `BigInt` suffix or too many digits) and must be lexed by the general path, starting
+    /// with the digits of the given value.
+    Partial(i64),
+}
+
+/// Consumes the digits of a decimal integer starting with the non-zero digit `init`,
+/// accumulating them directly into an integer instead of a buffer.
+///
+/// This is a fast path for short integers, which are the most common numeric literals.
+fn take_decimal_integer_fast<R>(init: u8, cursor: &mut Cursor<R>) -> Result<FastInteger, Error>
+where
+    R: ReadChar,
+{
+    debug_assert!(matches!(init, b'1'..=b'9'));
+
+    let mut value = i64::from(init - b'0');
+    let mut digits = 1;
+    loop {
+        match cursor.peek_char()? {
+            Some(c @ 0x30..=0x39 /* 0..9 */) if digits < FAST_INTEGER_MAX_DIGITS => {
+                cursor.next_char()?.expect("digit character vanished");
+                value = value * 10 + i64::from(c - 0x30);
+                digits += 1;
+            }
+            Some(
+                0x30..=0x39 /* 0..9 */
+                | 0x5F /* _ */
+                | 0x2E /* . */
+                | 0x65 /* e */
+                | 0x45 /* E */
+                | 0x6E, /* n */
+            ) => return Ok(FastInteger::Partial(value)),
+            _ => return Ok(FastInteger::Complete(value)),
+        }
+    }
+}
+
 fn take_signed_integer<R>(
     buf: &mut Vec<u8>,
     cursor: &mut Cursor<R>,
@@ -73,6 +123,12 @@ where
             buf.push(b'+');
             if !cursor.next_is_ascii_pred(&|ch| ch.is_digit(kind.base()))? {
                 // A digit must follow the + or - symbol.
+                if cursor.peek_char()?.is_none() {
+                    return Err(Error::unterminated(
+                        UnterminatedKind::Exponent,
+                        cursor.pos(),
+                    ));
+                }
                 return Err(Error::syntax("No digit found after + symbol", cursor.pos()));
             }
         }
@@ -80,6 +136,12 @@ where
             buf.push(b'-');
             if !cursor.next_is_ascii_pred(&|ch| ch.is_digit(kind.base()))? {
                 // A digit must follow the + or - symbol.
+                if cursor.peek_char()?.is_none() {
+                    return Err(Error::unterminated(
+                        UnterminatedKind::Exponent,
+                        cursor.pos(),
+                    ));
+                }
                 return Err(Error::syntax("No digit found after - symbol", cursor.pos()));
             }
         }
@@ -102,8 +164,8 @@ where
             }
         }
         None => {
-            return Err(Error::syntax(
-                "Abrupt end: No exponential value found",
+            return Err(Error::unterminated(
+                UnterminatedKind::Exponent,
                 cursor.pos(),
             ));
         }
@@ -160,7 +222,23 @@ where
     Ok(())
 }
 
-/// Utility function for checking the `NumericLiteral` is not followed by an `IdentifierStart` or `DecimalDigit` character.
+/// Checks if a character is a non-ASCII Unicode decimal digit (general category `Nd`), such as
+/// the full-width digit `１`.
+fn is_non_ascii_decimal_digit(ch: u32) -> bool {
+    const GENERAL_CATEGORY: CodePointMapDataBorrowed<'static, GeneralCategory> =
+        CodePointMapData::<GeneralCategory>::new();
+    ch > 0x7F && GENERAL_CATEGORY.get32(ch) == GeneralCategory::DecimalNumber
+}
+
+/// The error message for identifier characters directly following a numeric literal.
+pub(super) const NUMERIC_ADJACENCY_ERROR: &str =
+    "a numeric literal must not be followed by an alphanumeric, $ or _ characters";
+
+/// Utility function for checking the `NumericLiteral` is not followed by an `IdentifierStart` or
+/// `DecimalDigit` character.
+///
+/// Non-ASCII decimal digits are not part of numeric literals, but are reported with a dedicated
+/// error, since they usually come from copy-pasted text.
 ///
 /// More information:
 ///  - [ECMAScript Specification][spec]
@@ -170,16 +248,129 @@ fn check_after_numeric_literal<R>(cursor: &mut Cursor<R>) -> Result<(), Error>
 where
     R: ReadChar,
 {
-    if cursor.next_is_ascii_pred(&|ch| ch.is_ascii_alphanumeric() || ch == '$' || ch == '_')? {
+    if cursor.peek_char()?.is_some_and(is_non_ascii_decimal_digit) {
         Err(Error::syntax(
-            "a numeric literal must not be followed by an alphanumeric, $ or _ characters",
+            "non-ASCII digits are not allowed in numeric literals",
             cursor.pos(),
         ))
+    } else if cursor
+        .next_is_ascii_pred(&|ch| ch.is_ascii_alphanumeric() || ch == '$' || ch == '_')?
+    {
+        // The identifier after the literal is checked against the registered suffixes once lexed.
+        #[cfg(feature = "numeric-suffixes")]
+        if cursor.has_numeric_suffixes() && !cursor.next_is_ascii_pred(&|ch| ch.is_ascii_digit())? {
+            cursor.set_numeric_suffix_pos(cursor.pos());
+            return Ok(());
+        }
+
+        Err(Error::syntax(NUMERIC_ADJACENCY_ERROR, cursor.pos()))
     } else {
         Ok(())
     }
 }
 
+/// Creates the token of a numeric literal that ends at the current position of the cursor.
+fn numeric_token<R>(kind: TokenKind, start_pos: PositionGroup, cursor: &Cursor<R>) -> Token {
+    let token = Token::new_by_position_group(kind, start_pos, cursor.pos_group());
+    #[cfg(feature = "source-text")]
+    let token = token.with_raw(cursor.source_since(start_pos.linear_position()).into());
+    token
+}
+
+/// Records the exact decimal value of a rational numeric literal, if the lexer preserves exact
+/// decimals.
+///
+/// `digits` are the digits of a decimal literal accumulated by [`NumberLiteral::lex`].
+#[cfg(feature = "exact-decimals")]
+fn with_decimal<R>(token: Token, digits: &[u8], cursor: &Cursor<R>) -> Token {
+    if !cursor.preserve_decimals()
+        || !matches!(
+            token.kind(),
+            TokenKind::NumericLiteral(Numeric::Rational(_))
+        )
+    {
+        return token;
+    }
+
+    let (mantissa, exponent) = match digits.iter().position(|&c| c == b'E') {
+        Some(index) => (&digits[..index], Some(&digits[index + 1..])),
+        None => (digits, None),
+    };
+
+    let mut decimal = String::with_capacity(digits.len() + 1);
+    let mantissa = mantissa.strip_suffix(b".").unwrap_or(mantissa);
+    let integer_len = mantissa
+        .iter()
+        .position(|&c| c == b'.')
+        .unwrap_or(mantissa.len());
+    // Remove the leading zeros of legacy literals like `08.5`, keeping the one before the `.`.
+    let leading_zeros = mantissa[..integer_len]
+        .iter()
+        .take_while(|&&c| c == b'0')
+        .count()
+        .min(integer_len.saturating_sub(1));
+    let mantissa = &mantissa[leading_zeros..];
+    if mantissa.first() == Some(&b'.') {
+        decimal.push('0');
+    }
+    decimal.extend(mantissa.iter().map(|&c| char::from(c)));
+    if let Some(exponent) = exponent {
+        decimal.push('e');
+        let exponent = exponent.strip_prefix(b"+").unwrap_or(exponent);
+        decimal.extend(exponent.iter().map(|&c| char::from(c)));
+    }
+
+    token.with_decimal(decimal.into_boxed_str())
+}
+
+/// Parses the digits of a numeric literal accumulated by [`NumberLiteral::lex`].
+///
+/// The buffer is validated while lexing, so this should never fail; a malformed buffer is
+/// still reported as a syntax error at the start of the literal instead of panicking.
+pub(super) fn parse_numeric<R>(
+    buf: &[u8],
+    kind: NumericKind,
+    start_pos: Position,
+    cursor: &mut Cursor<R>,
+) -> Result<Numeric, Error> {
+    let invalid = || Error::syntax("invalid numeric literal", start_pos);
+    let num_str = str::from_utf8(buf).map_err(|_| invalid())?;
+
+    let num = match kind {
+        NumericKind::BigInt(base) => Numeric::BigInt(
+            BigInt::parse_bytes(buf, base)
+                .ok_or_else(invalid)?
+                .into(),
+        ),
+        NumericKind::Rational /* base: 10 */ => {
+            let val: f64 = fast_float2::parse(num_str).map_err(|_| invalid())?;
+
+            // For performance reasons we attempt to store values as integers if possible.
+            Numeric::from_f64(val)
+        }
+        NumericKind::Integer(base) => {
+            if let Ok(num) = i32::from_str_radix(num_str, base) {
+                Numeric::Integer(num)
+            } else {
+                let num = BigInt::parse_bytes(buf, base).ok_or_else(invalid)?;
+                match num.to_f64() {
+                    Some(value) if value.is_finite() => Numeric::Rational(value),
+                    // The literal is too big to be represented as a finite number.
+                    _ => {
+                        cursor.push_warning(
+                            "integer literal is too large to be represented, and evaluates to Infinity",
+                            start_pos,
+                        );
+                        Numeric::Rational(f64::INFINITY)
+                    }
+                }
+            }
+        }
+    };
+
+    Ok(num)
+}
+
 impl<R> Tokenizer<R> for NumberLiteral {
     fn lex(
         &mut self,
@@ -190,7 +381,26 @@ impl<R> Tokenizer<R> for NumberLiteral {
     where
         R: ReadChar,
     {
-        let mut buf = vec![self.init];
+        let mut buf = if matches!(self.init, b'1'..=b'9') {
+            match take_decimal_integer_fast(self.init, cursor)? {
+                FastInteger::Complete(value) => {
+                    check_after_numeric_literal(cursor)?;
+
+                    // Values outside of the `i32` range are stored as rationals, like in the general path.
+                    #[allow(clippy::cast_precision_loss)]
+                    let num = i32::try_from(value)
+                        .map_or_else(|_| Numeric::Rational(value as f64), Numeric::Integer);
+
+                    let token = numeric_token(TokenKind::NumericLiteral(num), start_pos, cursor);
+                    #[cfg(feature = "exact-decimals")]
+                    let token = with_decimal(token, value.to_string().as_bytes(), cursor);
+                    return Ok(token);
+                }
+                FastInteger::Partial(value) => value.to_string().into_bytes(),
+            }
+        } else {
+            vec![self.init]
+        };
 
         // Default assume the number is a base 10 integer.
         let mut kind = NumericKind::Integer(10);
@@ -212,6 +422,12 @@ impl<R> Tokenizer<R> for NumberLiteral {
 
                         // Checks if the next char after '0x' is a digit of that base. if not return an error.
                         if !cursor.next_is_ascii_pred(&|ch| ch.is_ascii_hexdigit())? {
+                            if cursor.peek_char()?.is_none() {
+                                return Err(Error::unterminated(
+                                    UnterminatedKind::NumericBasePrefix,
+                                    cursor.pos(),
+                                ));
+                            }
                             return Err(Error::syntax(
                                 "expected hexadecimal digit after number base prefix",
                                 cursor.pos(),
@@ -229,6 +445,12 @@ impl<R> Tokenizer<R> for NumberLiteral {
 
                         // Checks if the next char after '0o' is a digit of that base. if not return an error.
                         if !cursor.next_is_ascii_pred(&|ch| ch.is_digit(8))? {
+                            if cursor.peek_char()?.is_none() {
+                                return Err(Error::unterminated(
+                                    UnterminatedKind::NumericBasePrefix,
+                                    cursor.pos(),
+                                ));
+                            }
                             return Err(Error::syntax(
                                 "expected octal digit after number base prefix",
                                 cursor.pos(),
@@ -246,6 +468,12 @@ impl<R> Tokenizer<R> for NumberLiteral {
 
                         // Checks if the next char after '0b' is a digit of that base. if not return an error.
                         if !cursor.next_is_ascii_pred(&|ch| ch.is_digit(2))? {
+                            if cursor.peek_char()?.is_none() {
+                                return Err(Error::unterminated(
+                                    UnterminatedKind::NumericBasePrefix,
+                                    cursor.pos(),
+                                ));
+                            }
                             return Err(Error::syntax(
                                 "expected binary digit after number base prefix",
                                 cursor.pos(),
@@ -257,10 +485,10 @@ impl<R> Tokenizer<R> for NumberLiteral {
                         cursor.next_char()?.expect("n character vanished");
 
                         // DecimalBigIntegerLiteral '0n'
-                        return Ok(Token::new_by_position_group(
+                        return Ok(numeric_token(
                             TokenKind::NumericLiteral(Numeric::BigInt(BigInt::zero().into())),
                             start_pos,
-                            cursor.pos_group(),
+                            cursor,
                         ));
                     }
                     byte => {
@@ -268,13 +496,19 @@ impl<R> Tokenizer<R> for NumberLiteral {
                         if let Some(ch) = char::from_u32(byte) {
                             if ch.is_digit(8) {
                                 // LegacyOctalIntegerLiteral, or a number with leading 0s.
-                                if cursor.strict() {
-                                    // LegacyOctalIntegerLiteral is forbidden with strict mode true.
+                                // LegacyOctalIntegerLiteral is forbidden with strict mode true.
+                                if cursor.is_strict() {
                                     return Err(Error::syntax(
                                         "implicit octal literals are not allowed in strict mode",
                                         start_pos,
                                     ));
                                 }
+                                if cursor.force_strict_numbers() {
+                                    return Err(Error::syntax(
+                                        "implicit octal literals are not allowed",
+                                        start_pos,
+                                    ));
+                                }
 
                                 // Remove the initial '0' from buffer.
                                 buf.pop();
@@ -294,12 +528,18 @@ impl<R> Tokenizer<R> for NumberLiteral {
                                 // Indicates a numerical digit comes after then 0 but it isn't an octal digit
                                 // so therefore this must be a number with an unneeded leading 0. This is
                                 // forbidden in strict mode.
-                                if cursor.strict() {
+                                if cursor.is_strict() {
                                     return Err(Error::syntax(
                                         "leading 0's are not allowed in strict mode",
                                         start_pos,
                                     ));
                                 }
+                                if cursor.force_strict_numbers() {
+                                    return Err(Error::syntax(
+                                        "leading 0's are not allowed",
+                                        start_pos,
+                                    ));
+                                }
                             }
                         } // Else indicates that the symbol is a non-number.
                     }
@@ -307,10 +547,10 @@ impl<R> Tokenizer<R> for NumberLiteral {
             } else {
                 // DecimalLiteral lexing.
                 // Indicates that the number is just a single 0.
-                return Ok(Token::new_by_position_group(
+                return Ok(numeric_token(
                     TokenKind::NumericLiteral(Numeric::Integer(0)),
                     start_pos,
-                    cursor.pos_group(),
+                    cursor,
                 ));
             }
         }
@@ -391,41 +631,15 @@ impl<R> Tokenizer<R> for NumberLiteral {
 
         check_after_numeric_literal(cursor)?;
 
-        let num_str = unsafe { str::from_utf8_unchecked(buf.as_slice()) };
-        let num = match kind {
-            NumericKind::BigInt(base) => {
-                Numeric::BigInt(
-                    BigInt::parse_bytes(num_str.as_bytes(), base).expect("Could not convert to BigInt").into()
-                    )
-            }
-            // casting precisely to check if the float doesn't lose info on truncation
-            #[allow(clippy::cast_possible_truncation)]
-            NumericKind::Rational /* base: 10 */ => {
-                let val: f64 = fast_float2::parse(num_str).expect("Failed to parse float after checks");
-                let int_val = val as i32;
-
-                // The truncated float should be identically to the non-truncated float for the conversion to be loss-less,
-                // any other different and the number must be stored as a rational.
-                #[allow(clippy::float_cmp)]
-                if f64::from(int_val) == val {
-                    // For performance reasons we attempt to store values as integers if possible.
-                    Numeric::Integer(int_val)
-                } else {
-                    Numeric::Rational(val)
-                }
-            },
-            NumericKind::Integer(base) => {
-                i32::from_str_radix(num_str, base).map_or_else(|_| {
-                    let num = BigInt::parse_bytes(num_str.as_bytes(), base).expect("Failed to parse integer after checks");
-                    Numeric::Rational(num.to_f64().unwrap_or(f64::INFINITY))
-                }, Numeric::Integer)
-            }
-        };
+        let num = parse_numeric(&buf, kind, start_pos.position(), cursor)?;
 
-        Ok(Token::new_by_position_group(
-            TokenKind::NumericLiteral(num),
-            start_pos,
-            cursor.pos_group(),
-        ))
+        let token = numeric_token(TokenKind::NumericLiteral(num), start_pos, cursor);
+        #[cfg(feature = "exact-decimals")]
+        let token = if matches!(kind, NumericKind::Rational | NumericKind::Integer(10)) {
+            with_decimal(token, &buf, cursor)
+        } else {
+            token
+        };
+        Ok(token)
     }
 }
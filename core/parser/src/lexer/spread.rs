@@ -1,6 +1,6 @@
 //! Boa's lexing for ECMAScript spread (...) literals.
 
-use crate::lexer::{Cursor, Error, Token, Tokenizer};
+use crate::lexer::{Cursor, Error, Token, Tokenizer, UnterminatedKind};
 use crate::source::ReadChar;
 use boa_ast::{PositionGroup, Punctuator};
 use boa_interner::Interner;
@@ -43,6 +43,8 @@ impl<R> Tokenizer<R> for SpreadLiteral {
                     start_pos,
                     cursor.pos_group(),
                 ))
+            } else if cursor.peek_char()?.is_none() {
+                Err(Error::unterminated(UnterminatedKind::Spread, cursor.pos()))
             } else {
                 Err(Error::syntax(
                     "Expecting Token '.' as part of spread",
@@ -88,7 +88,7 @@ impl<R> Tokenizer<R> for StringLiteral {
             cursor,
             start_pos.position(),
             self.terminator,
-            cursor.strict(),
+            cursor.is_strict(),
         )?;
 
         Ok(Token::new(
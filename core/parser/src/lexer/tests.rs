@@ -1,12 +1,16 @@
 //! Tests for the lexer.
 
 use crate::lexer::{
-    Cursor, Error, Interner, Lexer, Punctuator, TokenKind,
+    Cursor, Error, Interner, Lexer, Punctuator, TokenKind, UnterminatedKind,
+    number::{NumericKind, parse_numeric},
     template::TemplateString,
     token::{ContainsEscapeSequence, EscapeSequence, Numeric},
 };
-use crate::source::ReadChar;
-use boa_ast::{Keyword, Position, Span, Spanned};
+use crate::{
+    Parser,
+    source::{ReadChar, Source, UTF8Input},
+};
+use boa_ast::{Keyword, Position, Span, Spanned, scope::Scope};
 use boa_interner::Sym;
 use boa_macros::utf16;
 use std::str;
@@ -253,6 +257,35 @@ fn check_punctuators() {
     expect_tokens(&mut lexer, &expected, interner);
 }
 
+/// Checks that `?.` is only lexed as optional chaining when not followed by a digit, since
+/// `a?.5:.6` is a conditional expression with decimal literals.
+#[test]
+fn check_optional_chaining_before_digit() {
+    let interner = &mut Interner::default();
+    let a = interner.get_or_intern_static("a", utf16!("a"));
+    let b = interner.get_or_intern_static("b", utf16!("b"));
+
+    let mut lexer = Lexer::from(&b"a?.b"[..]);
+    let expected = [
+        TokenKind::identifier(a),
+        TokenKind::Punctuator(Punctuator::Optional),
+        TokenKind::identifier(b),
+    ];
+    expect_tokens(&mut lexer, &expected, interner);
+
+    for source in ["a?.5:.6", "a ? .5 : .6"] {
+        let mut lexer = Lexer::from(source.as_bytes());
+        let expected = [
+            TokenKind::identifier(a),
+            TokenKind::Punctuator(Punctuator::Question),
+            TokenKind::numeric_literal(0.5),
+            TokenKind::Punctuator(Punctuator::Colon),
+            TokenKind::numeric_literal(0.6),
+        ];
+        expect_tokens(&mut lexer, &expected, interner);
+    }
+}
+
 #[test]
 fn check_punctuator_at_the_end() {
     //TODO: maybe just use `strum` (`EnumIter`)?
@@ -595,6 +628,45 @@ fn numbers() {
     expect_tokens(&mut lexer, &expected, interner);
 }
 
+#[test]
+fn numeric_token_queries() {
+    let mut lexer = Lexer::from(&b"1 2.5 10000000000 7n x"[..]);
+    let interner = &mut Interner::default();
+
+    let mut tokens = Vec::new();
+    while let Some(token) = lexer.next(interner).unwrap() {
+        tokens.push(token);
+    }
+
+    let numeric = tokens
+        .iter()
+        .map(|token| {
+            if let TokenKind::NumericLiteral(num) = token.kind() {
+                Some((
+                    token.is_numeric(),
+                    num.is_integer(),
+                    num.is_rational(),
+                    num.is_bigint(),
+                ))
+            } else {
+                assert!(!token.is_numeric());
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        numeric,
+        [
+            Some((true, true, false, false)),
+            Some((true, false, true, false)),
+            Some((true, false, true, false)),
+            Some((true, false, false, true)),
+            None,
+        ]
+    );
+}
+
 #[test]
 fn numbers_with_separators() {
     let mut lexer = Lexer::from(
@@ -677,6 +749,277 @@ fn implicit_octal_edge_case() {
     expect_tokens(&mut lexer, &expected, interner);
 }
 
+#[test]
+fn force_strict_numbers() {
+    for source in ["0777", "08"] {
+        let interner = &mut Interner::default();
+
+        let mut lexer = Lexer::from(source.as_bytes());
+        assert!(lexer.next(interner).is_ok());
+
+        let mut lexer = Lexer::from(source.as_bytes());
+        lexer.set_force_strict_numbers(true);
+        assert!(lexer.next(interner).is_err());
+    }
+
+    let mut lexer = Lexer::from(&b"0 0.5 0x1F"[..]);
+    lexer.set_force_strict_numbers(true);
+    let interner = &mut Interner::default();
+
+    let expected = [
+        TokenKind::numeric_literal(0),
+        TokenKind::numeric_literal(0.5),
+        TokenKind::numeric_literal(31),
+    ];
+
+    expect_tokens(&mut lexer, &expected, interner);
+}
+
+#[test]
+fn infinite_integer_literal() {
+    let literal = "1".repeat(400);
+    let mut lexer = Lexer::from(literal.as_bytes());
+    let interner = &mut Interner::default();
+
+    let token = lexer.next(interner).unwrap().expect("missing token");
+    assert_eq!(
+        token.kind(),
+        &TokenKind::NumericLiteral(Numeric::Rational(f64::INFINITY))
+    );
+
+    let [warning] = lexer.warnings() else {
+        panic!("expected a single warning, got {:?}", lexer.warnings());
+    };
+    assert_eq!(warning.position(), Position::new(1, 1));
+    assert!(
+        warning.message().contains("Infinity"),
+        "{}",
+        warning.message()
+    );
+
+    // Finite literals, even if they lose precision, don't produce warnings.
+    let literal = "1".repeat(300);
+    let mut lexer = Lexer::from(literal.as_bytes());
+    let token = lexer.next(interner).unwrap().expect("missing token");
+    assert!(matches!(
+        token.kind(),
+        TokenKind::NumericLiteral(Numeric::Rational(value)) if value.is_finite()
+    ));
+    assert!(lexer.warnings().is_empty());
+}
+
+#[test]
+fn decimal_integer_fast_path() {
+    // The separated literals go through the general path, and must give the same values.
+    let cases = [
+        ("7", "7"),
+        ("42", "4_2"),
+        ("2147483647", "2_147_483_647"),
+        ("2147483648", "2_147_483_648"),
+        ("123456789012345678", "123_456_789_012_345_678"),
+        ("999999999999999999", "999_999_999_999_999_999"),
+        ("1234567890123456789", "1_234_567_890_123_456_789"),
+        ("98765432109876543210987", "98_765_432_109_876_543_210_987"),
+    ];
+
+    for (fast, slow) in cases {
+        let interner = &mut Interner::default();
+        let fast_token = Lexer::from(fast.as_bytes())
+            .next(interner)
+            .unwrap()
+            .expect("missing token");
+        let slow_token = Lexer::from(slow.as_bytes())
+            .next(interner)
+            .unwrap()
+            .expect("missing token");
+        assert_eq!(fast_token.kind(), slow_token.kind(), "{fast} vs {slow}");
+        assert_eq!(
+            fast_token.span(),
+            span((1, 1), (1, u32::try_from(fast.len()).unwrap() + 1)),
+            "{fast}"
+        );
+    }
+
+    let mut lexer = Lexer::from(&b"12;34 56.5 78e1 90n 3_1"[..]);
+    let interner = &mut Interner::default();
+
+    let expected = [
+        TokenKind::numeric_literal(12),
+        TokenKind::Punctuator(Punctuator::Semicolon),
+        TokenKind::numeric_literal(34),
+        TokenKind::numeric_literal(56.5),
+        TokenKind::numeric_literal(780),
+        TokenKind::numeric_literal(num_bigint::BigInt::from(90)),
+        TokenKind::numeric_literal(31),
+    ];
+
+    expect_tokens(&mut lexer, &expected, interner);
+
+    assert!(Lexer::from(&b"12a"[..]).next(interner).is_err());
+}
+
+#[test]
+fn numeric_display() {
+    let cases = [
+        (Numeric::Integer(0), "0"),
+        (Numeric::Integer(-42), "-42"),
+        (Numeric::Rational(0.1), "0.1"),
+        (Numeric::Rational(0.1 + 0.2), "0.30000000000000004"),
+        (Numeric::Rational(-0.0), "0"),
+        (Numeric::Rational(1e21), "1e+21"),
+        (
+            Numeric::Rational(123_456_789_012_345_680_000.0),
+            "123456789012345680000",
+        ),
+        (Numeric::Rational(0.000_001), "0.000001"),
+        (Numeric::Rational(1e-7), "1e-7"),
+        (Numeric::Rational(5e-324), "5e-324"),
+        (Numeric::Rational(f64::MAX), "1.7976931348623157e+308"),
+        (
+            Numeric::Rational(9_007_199_254_740_993.0),
+            "9007199254740992",
+        ),
+        (Numeric::Rational(f64::NAN), "NaN"),
+        (Numeric::Rational(f64::NEG_INFINITY), "-Infinity"),
+        (num_bigint::BigInt::from(10).into(), "10n"),
+    ];
+
+    for (num, expected) in cases {
+        assert_eq!(num.to_string(), expected);
+    }
+}
+
+#[test]
+fn non_ascii_digit_in_numeric_literal() {
+    for (source, column) in [("1２3", 2), ("0x1F١", 5), ("1.5\u{0966}", 4)] {
+        let interner = &mut Interner::default();
+        let error = Lexer::from(source.as_bytes())
+            .next(interner)
+            .expect_err(source);
+        if let Error::Syntax(message, pos) = error {
+            assert_eq!(
+                &*message,
+                "non-ASCII digits are not allowed in numeric literals"
+            );
+            assert_eq!(pos, Position::new(1, column), "{source}");
+        } else {
+            panic!("invalid error type for {source}");
+        }
+    }
+}
+
+#[cfg(feature = "source-text")]
+#[test]
+fn numeric_raw_source_text() {
+    let interner = &mut Interner::default();
+    let source = "0xFF + 1_000_000 * 123n - 0 .5 0b1_0n 0n 1e+3 007 12345 x";
+    let mut lexer = Lexer::from(source.as_bytes());
+
+    let mut raws = Vec::new();
+    while let Some(token) = lexer.next(interner).unwrap() {
+        if token.is_numeric() {
+            raws.push(
+                token
+                    .raw()
+                    .expect("numeric tokens must have a raw slice")
+                    .to_owned(),
+            );
+        } else {
+            assert_eq!(token.raw(), None);
+        }
+    }
+
+    assert_eq!(
+        raws,
+        [
+            "0xFF",
+            "1_000_000",
+            "123n",
+            "0",
+            ".5",
+            "0b1_0n",
+            "0n",
+            "1e+3",
+            "007",
+            "12345"
+        ]
+    );
+}
+
+#[cfg(feature = "exact-decimals")]
+#[test]
+fn numeric_exact_decimals() {
+    fn decimals(source: &str, preserve: bool) -> Vec<Option<String>> {
+        let interner = &mut Interner::default();
+        let mut lexer = Lexer::from(source.as_bytes());
+        lexer.set_preserve_decimals(preserve);
+
+        let mut decimals = Vec::new();
+        while let Some(token) = lexer.next(interner).unwrap() {
+            decimals.push(token.decimal().map(str::to_owned));
+        }
+        decimals
+    }
+
+    assert_eq!(
+        decimals("0.1 3.14159", true),
+        [Some("0.1".to_owned()), Some("3.14159".to_owned())]
+    );
+    assert_eq!(
+        decimals(".5 1. 1_000.000_1 1E+3 2.5e-7 08.5 4294967296", true),
+        [
+            Some("0.5".to_owned()),
+            None,
+            Some("1000.0001".to_owned()),
+            None,
+            Some("2.5e-7".to_owned()),
+            Some("8.5".to_owned()),
+            Some("4294967296".to_owned()),
+        ]
+    );
+    assert_eq!(decimals("1 0x1F 10n x", true), [None, None, None, None]);
+    assert_eq!(decimals("0.1 3.14159", false), [None, None]);
+}
+
+#[cfg(feature = "numeric-suffixes")]
+#[test]
+fn numeric_suffix() {
+    let interner = &mut Interner::default();
+    let px = interner.get_or_intern_static("px", utf16!("px"));
+
+    let mut lexer = Lexer::from(&b"5px 1.5px 0x10px"[..]);
+    lexer.register_numeric_suffix("px");
+    let expected = [
+        TokenKind::numeric_literal(5),
+        TokenKind::identifier(px),
+        TokenKind::numeric_literal(1.5),
+        TokenKind::identifier(px),
+        TokenKind::numeric_literal(16),
+        TokenKind::identifier(px),
+    ];
+    expect_tokens(&mut lexer, &expected, interner);
+
+    for source in ["5pt", "5pxx", "5in", "5$"] {
+        let mut lexer = Lexer::from(source.as_bytes());
+        lexer.register_numeric_suffix("px");
+        assert!(lexer.next(interner).is_ok(), "{source}");
+        let error = lexer.next(interner).expect_err(source);
+        if let Error::Syntax(message, pos) = error {
+            assert_eq!(
+                &*message,
+                "a numeric literal must not be followed by an alphanumeric, $ or _ characters"
+            );
+            assert_eq!(pos, Position::new(1, 2), "{source}");
+        } else {
+            panic!("invalid error type for {source}");
+        }
+    }
+
+    // Without registered suffixes, the literal is rejected right away.
+    let error = Lexer::from(&b"5px"[..]).next(interner).expect_err("5px");
+    assert!(matches!(error, Error::Syntax(_, pos) if pos == Position::new(1, 2)));
+}
+
 #[test]
 fn hexadecimal_edge_case() {
     let mut lexer = Lexer::from(&b"0xffff.ff 0xffffff"[..]);
@@ -1197,3 +1540,418 @@ mod carriage_return {
         expect_tokens_with_lines(3, "-\r\n\n\r3");
     }
 }
+
+#[test]
+fn checkpoint_restore() {
+    let interner = &mut Interner::default();
+    let a = interner.get_or_intern_static("a", utf16!("a"));
+    let b = interner.get_or_intern_static("b", utf16!("b"));
+    let big = "1".repeat(400);
+    let source = format!("a < b > {big}; c");
+    let mut lexer = Lexer::from(source.as_bytes());
+
+    assert_eq!(
+        lexer.next(interner).unwrap().unwrap().kind(),
+        &TokenKind::IdentifierName((a, ContainsEscapeSequence(false)))
+    );
+    let checkpoint = lexer.checkpoint();
+
+    // Speculatively read `< b >` as a type argument list, which must be followed by `(`.
+    for expected in [
+        TokenKind::Punctuator(Punctuator::LessThan),
+        TokenKind::IdentifierName((b, ContainsEscapeSequence(false))),
+        TokenKind::Punctuator(Punctuator::GreaterThan),
+    ] {
+        assert_eq!(lexer.next(interner).unwrap().unwrap().kind(), &expected);
+    }
+    let next = lexer.next(interner).unwrap().unwrap();
+    assert!(matches!(next.kind(), TokenKind::NumericLiteral(_)));
+    assert_eq!(lexer.warnings().len(), 1);
+
+    // The speculation failed, backtrack and lex it as a relational expression.
+    lexer.restore(checkpoint);
+    assert!(lexer.warnings().is_empty());
+
+    let token = lexer.next(interner).unwrap().unwrap();
+    assert_eq!(token.kind(), &TokenKind::Punctuator(Punctuator::LessThan));
+    assert_eq!(token.span(), span((1, 3), (1, 4)));
+    assert_eq!(token.linear_span().start().pos(), 2);
+
+    let checkpoint = lexer.checkpoint();
+    let first = lexer.next(interner).unwrap().unwrap();
+    lexer.restore(checkpoint);
+    let checkpoint = lexer.checkpoint();
+    assert_eq!(lexer.next(interner).unwrap().unwrap(), first);
+    lexer.restore(checkpoint);
+    assert_eq!(lexer.next(interner).unwrap().unwrap(), first);
+}
+
+#[test]
+fn checkpoint_release_drops_recorded_chars() {
+    let interner = &mut Interner::default();
+    let mut lexer = Lexer::from("a + b + c".as_bytes());
+
+    // A successful speculation keeps nothing once its checkpoint is released.
+    let checkpoint = lexer.checkpoint();
+    lexer.next(interner).unwrap().unwrap();
+    assert!(lexer.cursor.recorded_chars() > 0);
+    lexer.release(checkpoint);
+    assert_eq!(lexer.cursor.recorded_chars(), 0);
+
+    // After a restore, the recorded characters are dropped once they are replayed.
+    let outer = lexer.checkpoint();
+    let inner = lexer.checkpoint();
+    let plus = lexer.next(interner).unwrap().unwrap();
+    lexer.release(inner);
+    assert!(lexer.cursor.recorded_chars() > 0);
+    lexer.restore(outer);
+    assert_eq!(lexer.next(interner).unwrap().unwrap(), plus);
+    lexer.next(interner).unwrap().unwrap();
+    assert_eq!(lexer.next(interner).unwrap().unwrap().kind(), plus.kind());
+    assert_eq!(lexer.cursor.recorded_chars(), 0);
+}
+
+#[test]
+fn checkpoint_drop_releases_it() {
+    let interner = &mut Interner::default();
+    let mut lexer = Lexer::from("a + b + c".as_bytes());
+
+    // A dropped checkpoint is released, so characters stop being recorded.
+    let checkpoint = lexer.checkpoint();
+    lexer.next(interner).unwrap().unwrap();
+    assert!(lexer.cursor.recorded_chars() > 0);
+    drop(checkpoint);
+    lexer.next(interner).unwrap().unwrap();
+    assert_eq!(lexer.cursor.recorded_chars(), 0);
+    lexer.next(interner).unwrap().unwrap();
+    assert_eq!(lexer.cursor.recorded_chars(), 0);
+}
+
+#[test]
+fn checkpoint_restore_shared_reader() {
+    let interner = &mut Interner::default();
+    // A `&mut &[u8]` reader cannot be cloned, and reading from it consumes the shared slice.
+    let mut bytes = "a + b".as_bytes();
+    let mut lexer = Lexer::new(Source::from_reader(&mut bytes, None).reader);
+
+    let checkpoint = lexer.checkpoint();
+    let tokens: Vec<_> = (0..3)
+        .map(|_| lexer.next(interner).unwrap().unwrap())
+        .collect();
+    assert!(lexer.next(interner).unwrap().is_none());
+
+    lexer.restore(checkpoint);
+    for token in tokens {
+        assert_eq!(lexer.next(interner).unwrap().unwrap(), token);
+    }
+    assert!(lexer.next(interner).unwrap().is_none());
+}
+
+#[test]
+fn checkpoint_restore_utf16() {
+    let interner = &mut Interner::default();
+    let source: Vec<u16> = "'𝒳' + 1".encode_utf16().collect();
+    let mut lexer = Lexer::new(Source::from_utf16(&source).reader);
+
+    let checkpoint = lexer.checkpoint();
+    let string = lexer.next(interner).unwrap().unwrap();
+    assert_eq!(
+        lexer.next(interner).unwrap().unwrap().span(),
+        span((1, 5), (1, 6))
+    );
+
+    lexer.restore(checkpoint);
+    assert_eq!(lexer.next(interner).unwrap().unwrap(), string);
+    expect_tokens(
+        &mut lexer,
+        &[
+            TokenKind::Punctuator(Punctuator::Add),
+            TokenKind::NumericLiteral(Numeric::Integer(1)),
+        ],
+        interner,
+    );
+}
+
+#[test]
+fn numeric_canonicalization() {
+    assert_eq!(Numeric::from_f64(3.0), Numeric::Integer(3));
+    assert_eq!(Numeric::from_f64(-7.0), Numeric::Integer(-7));
+    assert_eq!(Numeric::from_f64(0.5), Numeric::Rational(0.5));
+    assert_eq!(
+        Numeric::from_f64(9_007_199_254_740_992.0),
+        Numeric::Rational(2f64.powi(53))
+    );
+    assert_eq!(
+        Numeric::from_f64(f64::INFINITY),
+        Numeric::Rational(f64::INFINITY)
+    );
+    assert!(matches!(Numeric::from_f64(-0.0), Numeric::Rational(n) if n.is_sign_negative()));
+    assert!(matches!(Numeric::from_f64(f64::NAN), Numeric::Rational(n) if n.is_nan()));
+
+    assert_eq!(Numeric::from_i64(3), Numeric::Integer(3));
+    assert_eq!(
+        Numeric::from_i64(i64::from(i32::MIN)),
+        Numeric::Integer(i32::MIN)
+    );
+    assert_eq!(Numeric::from_i64(1 << 53), Numeric::Rational(2f64.powi(53)));
+
+    // Both constructors agree with the lexer.
+    let interner = &mut Interner::default();
+    for (source, value) in [
+        ("3.0", 3.0),
+        ("2.5e1", 25.0),
+        ("9007199254740992", 2f64.powi(53)),
+    ] {
+        let mut lexer = Lexer::from(source.as_bytes());
+        let token = lexer.next(interner).unwrap().unwrap();
+        assert_eq!(
+            token.kind(),
+            &TokenKind::NumericLiteral(Numeric::from_f64(value))
+        );
+    }
+}
+
+/// Checks that every line terminator sequence starts a new line at column 1, so positions are
+/// the same regardless of the line endings of the source, and that normalizing line endings
+/// counts `\r\n` as a single character in linear positions.
+#[test]
+fn normalize_line_endings() {
+    let source = "a\r\nb\u{2028}c\rd\u{2029}e\r\n'f\u{2028}g'\r\n`h\r\ni`";
+
+    let lex = |normalize: bool| {
+        let interner = &mut Interner::default();
+        let mut lexer = Lexer::from(source.as_bytes());
+        lexer.set_normalize_line_endings(normalize);
+        assert_eq!(lexer.normalize_line_endings(), normalize);
+
+        let mut tokens = Vec::new();
+        while let Some(token) = lexer.next(interner).unwrap() {
+            if token.kind() != &TokenKind::LineTerminator {
+                let linear_span = token.linear_span();
+                tokens.push((
+                    token.to_string(interner),
+                    token.span(),
+                    (linear_span.start().pos(), linear_span.end().pos()),
+                ));
+            }
+        }
+        tokens
+    };
+
+    let expected = [
+        ("a", span((1, 1), (1, 2)), (0, 1), (0, 1)),
+        ("b", span((2, 1), (2, 2)), (2, 3), (3, 4)),
+        ("c", span((3, 1), (3, 2)), (4, 5), (5, 6)),
+        ("d", span((4, 1), (4, 2)), (6, 7), (7, 8)),
+        ("e", span((5, 1), (5, 2)), (8, 9), (9, 10)),
+        ("f\u{2028}g", span((6, 1), (7, 3)), (10, 15), (12, 17)),
+        ("h\ni", span((8, 1), (9, 3)), (16, 21), (19, 25)),
+    ];
+    let normalized = expected.map(|(value, span, linear, _)| (value.to_owned(), span, linear));
+    let raw = expected.map(|(value, span, _, linear)| (value.to_owned(), span, linear));
+    assert_eq!(lex(true), normalized);
+    assert_eq!(lex(false), raw);
+}
+
+#[test]
+fn numeric_bit_width() {
+    let max_safe = 2f64.powi(53) - 1.0;
+    let cases = [
+        (Numeric::Integer(i32::MAX), true, true),
+        (Numeric::Integer(i32::MIN), true, true),
+        (Numeric::Rational(f64::from(i32::MAX)), true, true),
+        (Numeric::Rational(f64::from(i32::MAX) + 1.0), false, true),
+        (Numeric::Rational(f64::from(i32::MIN)), true, true),
+        (Numeric::Rational(f64::from(i32::MIN) - 1.0), false, true),
+        (Numeric::Rational(max_safe), false, true),
+        (Numeric::Rational(-max_safe), false, true),
+        (Numeric::Rational(2f64.powi(53)), false, false),
+        (Numeric::Rational(-2f64.powi(53)), false, false),
+        (Numeric::Rational(1.5), false, false),
+        (Numeric::Rational(-0.0), false, true),
+        (Numeric::Rational(f64::NAN), false, false),
+        (Numeric::Rational(f64::INFINITY), false, false),
+        (Numeric::BigInt(Box::new(1.into())), false, false),
+    ];
+
+    for (numeric, fits_i32, fits_safe_integer) in cases {
+        assert_eq!(numeric.fits_i32(), fits_i32, "{numeric:?}");
+        assert_eq!(
+            numeric.fits_safe_integer(),
+            fits_safe_integer,
+            "{numeric:?}"
+        );
+    }
+}
+
+#[test]
+fn tokens_iterator() {
+    let interner = &mut Interner::default();
+    let source = "f(...args, 1.5, 0x10) / 2;\n/re/g";
+    let kinds = Lexer::tokens(Source::from_bytes(source), interner)
+        .map(|token| token.map(|token| token.kind().clone()))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    let f = interner.get_or_intern_static("f", utf16!("f"));
+    let args = interner.get_or_intern_static("args", utf16!("args"));
+    let re = interner.get_or_intern_static("re", utf16!("re"));
+    let g = interner.get_or_intern_static("g", utf16!("g"));
+    assert_eq!(
+        kinds,
+        [
+            TokenKind::identifier(f),
+            TokenKind::Punctuator(Punctuator::OpenParen),
+            TokenKind::Punctuator(Punctuator::Spread),
+            TokenKind::identifier(args),
+            TokenKind::Punctuator(Punctuator::Comma),
+            TokenKind::numeric_literal(1.5),
+            TokenKind::Punctuator(Punctuator::Comma),
+            TokenKind::numeric_literal(16),
+            TokenKind::Punctuator(Punctuator::CloseParen),
+            TokenKind::Punctuator(Punctuator::Div),
+            TokenKind::numeric_literal(2),
+            TokenKind::Punctuator(Punctuator::Semicolon),
+            TokenKind::LineTerminator,
+            TokenKind::RegularExpressionLiteral(re, g),
+        ]
+    );
+}
+
+#[test]
+fn tokens_iterator_template_substitutions() {
+    let interner = &mut Interner::default();
+    let tokens = Lexer::tokens(Source::from_bytes("`a${ {b} }c${d}e` / 2"), interner)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let tokens = tokens
+        .iter()
+        .map(|token| token.to_string(interner))
+        .collect::<Vec<_>>();
+
+    assert_eq!(tokens, ["a", "{", "b", "}", "c", "d", "e", "/", "2"]);
+}
+
+#[test]
+fn tokens_iterator_stops_at_error() {
+    let interner = &mut Interner::default();
+    let mut tokens = Lexer::tokens(Source::from_bytes("a 1x b"), interner);
+
+    assert!(matches!(
+        tokens.next(),
+        Some(Ok(token)) if matches!(token.kind(), TokenKind::IdentifierName(_))
+    ));
+    assert!(matches!(tokens.next(), Some(Err(Error::Syntax(_, _)))));
+    assert!(tokens.next().is_none());
+}
+
+#[test]
+fn strictness_after_use_strict_prologue() {
+    let mut lexer = Lexer::from(&b"010"[..]);
+    assert!(!lexer.cursor.is_strict());
+    assert!(!lexer.cursor.in_module());
+
+    lexer.set_module(true);
+    assert!(lexer.cursor.is_strict());
+    assert!(lexer.cursor.in_module());
+
+    let parse = |source: &str| {
+        Parser::new(Source::from_bytes(source))
+            .parse_script(&Scope::new_global(), &mut Interner::default())
+    };
+    assert!(parse("010;").is_ok());
+    assert!(parse("\"use strict\"; 010;").is_err());
+    assert!(parse("\"use strict\"; function f() { 010; }").is_err());
+    assert!(parse("function f() { \"use strict\"; } 010;").is_ok());
+}
+
+#[test]
+fn fractional_part_edge_cases() {
+    let interner = &mut Interner::default();
+
+    // A decimal point can be followed directly by the exponent, or by nothing at all.
+    let mut lexer = Lexer::from(&b"1.e3 .5 5. 5.;"[..]);
+    let expected = [
+        TokenKind::numeric_literal(1000),
+        TokenKind::numeric_literal(0.5),
+        TokenKind::numeric_literal(5),
+        TokenKind::numeric_literal(5),
+        TokenKind::Punctuator(Punctuator::Semicolon),
+    ];
+    expect_tokens(&mut lexer, &expected, interner);
+
+    // `1..2` is the literal `1.` followed by the literal `.2`, which the parser rejects.
+    let mut lexer = Lexer::from(&b"1..2"[..]);
+    let expected = [
+        TokenKind::numeric_literal(1),
+        TokenKind::numeric_literal(0.2),
+    ];
+    expect_tokens(&mut lexer, &expected, interner);
+
+    for (source, message, column) in [
+        ("1._e3", "numeric separator not allowed after '.'", 3),
+        ("1.e+x", "No digit found after + symbol", 5),
+    ] {
+        let mut lexer = Lexer::from(source.as_bytes());
+        let Err(Error::Syntax(msg, pos)) = lexer.next(interner) else {
+            panic!("expected a syntax error for {source}");
+        };
+        assert_eq!(&*msg, message, "wrong error for {source}");
+        assert_eq!(pos, Position::new(1, column), "wrong position for {source}");
+    }
+}
+
+#[test]
+fn unterminated_constructs() {
+    let interner = &mut Interner::default();
+
+    for (source, kind, column) in [
+        ("1e", UnterminatedKind::Exponent, 3),
+        ("1.e", UnterminatedKind::Exponent, 4),
+        ("1e-", UnterminatedKind::Exponent, 4),
+        ("0x", UnterminatedKind::NumericBasePrefix, 3),
+        ("0b", UnterminatedKind::NumericBasePrefix, 3),
+        ("..", UnterminatedKind::Spread, 3),
+        ("a ..", UnterminatedKind::Spread, 5),
+    ] {
+        let mut lexer = Lexer::from(source.as_bytes());
+        let error = loop {
+            match lexer.next(interner) {
+                Ok(Some(_)) => {}
+                Ok(None) => panic!("expected an error for {source}"),
+                Err(error) => break error,
+            }
+        };
+        let Error::Unterminated(found, pos) = error else {
+            panic!("expected an unterminated error for {source}, found {error:?}");
+        };
+        assert_eq!(found, kind, "wrong construct for {source}");
+        assert_eq!(pos, Position::new(1, column), "wrong position for {source}");
+    }
+
+    // A `..` followed by anything else is not cut short by the end of the input.
+    let mut lexer = Lexer::from(&b"..x"[..]);
+    assert!(matches!(lexer.next(interner), Err(Error::Syntax(_, _))));
+}
+
+#[test]
+fn malformed_numeric_buffer_is_a_syntax_error() {
+    let mut cursor = Cursor::new(UTF8Input::new(&b""[..]));
+    let start_pos = Position::new(3, 7);
+
+    for (buf, kind) in [
+        (&b"1z"[..], NumericKind::BigInt(10)),
+        (b"9z", NumericKind::Integer(10)),
+        (b"99999999999z", NumericKind::Integer(10)),
+        (b"1..2", NumericKind::Rational),
+        (b"\xFF", NumericKind::Integer(16)),
+    ] {
+        let err = parse_numeric(buf, kind, start_pos, &mut cursor).unwrap_err();
+        assert_eq!(err.to_string(), "invalid numeric literal at line 3, col 7");
+    }
+
+    assert!(matches!(
+        parse_numeric(b"ff", NumericKind::Integer(16), start_pos, &mut cursor),
+        Ok(Numeric::Integer(255))
+    ));
+}
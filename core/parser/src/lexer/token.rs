@@ -10,6 +10,7 @@ use bitflags::bitflags;
 use boa_ast::{Keyword, LinearSpan, PositionGroup, Punctuator, Span, Spanned};
 use boa_interner::{Interner, Sym};
 use num_bigint::BigInt;
+use std::fmt;
 
 /// This represents the smallest individual words, phrases, or characters that JavaScript can understand.
 ///
@@ -25,6 +26,12 @@ pub struct Token {
     span: Span,
     /// The token linear position in the original source code.
     linear_span: LinearSpan,
+    /// The exact source text of the token, only recorded for numeric literals.
+    #[cfg(feature = "source-text")]
+    raw: Option<Box<str>>,
+    /// The normalized decimal digits of the token, only recorded for rational numeric literals.
+    #[cfg(feature = "exact-decimals")]
+    decimal: Option<Box<str>>,
 }
 
 impl Token {
@@ -36,6 +43,10 @@ impl Token {
             kind,
             span,
             linear_span,
+            #[cfg(feature = "source-text")]
+            raw: None,
+            #[cfg(feature = "exact-decimals")]
+            decimal: None,
         }
     }
 
@@ -75,6 +86,56 @@ impl Token {
         self.linear_span
     }
 
+    /// Gets the exact source text the token was lexed from, like `0xFF` or `1_000n`.
+    ///
+    /// This is only recorded for numeric literals, and returns `None` for any other token.
+    #[cfg(feature = "source-text")]
+    #[inline]
+    #[must_use]
+    pub fn raw(&self) -> Option<&str> {
+        self.raw.as_deref()
+    }
+
+    /// Sets the exact source text the token was lexed from.
+    #[cfg(feature = "source-text")]
+    #[inline]
+    #[must_use]
+    pub(crate) fn with_raw(mut self, raw: Box<str>) -> Self {
+        self.raw = Some(raw);
+        self
+    }
+
+    /// Gets the exact decimal value of a rational numeric literal, like `0.1` or `1.5e-7`.
+    ///
+    /// This is only recorded by lexers preserving exact decimals (see
+    /// [`Lexer::set_preserve_decimals`][super::Lexer::set_preserve_decimals]), and only for
+    /// decimal literals lexed as [`Numeric::Rational`], which may not represent their exact
+    /// value. Numeric separators are removed, a leading `0` is added to literals starting with
+    /// `.`, a trailing `.` is removed and the exponent is written as `e` followed by its digits,
+    /// with an optional `-` sign.
+    #[cfg(feature = "exact-decimals")]
+    #[inline]
+    #[must_use]
+    pub fn decimal(&self) -> Option<&str> {
+        self.decimal.as_deref()
+    }
+
+    /// Sets the exact decimal value of a rational numeric literal.
+    #[cfg(feature = "exact-decimals")]
+    #[inline]
+    #[must_use]
+    pub(crate) fn with_decimal(mut self, decimal: Box<str>) -> Self {
+        self.decimal = Some(decimal);
+        self
+    }
+
+    /// Returns `true` if the token is a numeric literal.
+    #[inline]
+    #[must_use]
+    pub const fn is_numeric(&self) -> bool {
+        matches!(self.kind, TokenKind::NumericLiteral(_))
+    }
+
     /// Converts the token to a `String`.
     #[inline]
     pub(crate) fn to_string(&self, interner: &Interner) -> String {
@@ -102,6 +163,111 @@ pub enum Numeric {
     BigInt(Box<BigInt>),
 }
 
+impl Numeric {
+    /// Creates a numeric literal from a floating point number, using the same representation as
+    /// the lexer.
+    ///
+    /// Values that can be losslessly represented as an `i32` are stored as an [`Numeric::Integer`],
+    /// while any other value (including `-0`) is stored as a [`Numeric::Rational`].
+    #[must_use]
+    pub fn from_f64(value: f64) -> Self {
+        // casting precisely to check if the float doesn't lose info on truncation
+        #[allow(clippy::cast_possible_truncation)]
+        let int_val = value as i32;
+
+        // The truncated float should be identical to the non-truncated float for the conversion
+        // to be lossless, and `-0` must keep its sign.
+        #[allow(clippy::float_cmp)]
+        if f64::from(int_val) == value && !(value == 0.0 && value.is_sign_negative()) {
+            Self::Integer(int_val)
+        } else {
+            Self::Rational(value)
+        }
+    }
+
+    /// Creates a numeric literal from an integer, using the same representation as the lexer.
+    ///
+    /// Values in the `i32` range are stored as an [`Numeric::Integer`], while any other value is
+    /// stored as the nearest [`Numeric::Rational`].
+    #[must_use]
+    pub fn from_i64(value: i64) -> Self {
+        // Values outside of the `i32` range are stored as rationals, like the lexer does.
+        #[allow(clippy::cast_precision_loss)]
+        i32::try_from(value).map_or_else(|_| Self::Rational(value as f64), Self::Integer)
+    }
+
+    /// Returns `true` if the numeric literal is a floating point number.
+    #[inline]
+    #[must_use]
+    pub const fn is_rational(&self) -> bool {
+        matches!(self, Self::Rational(_))
+    }
+
+    /// Returns `true` if the numeric literal is an integer.
+    #[inline]
+    #[must_use]
+    pub const fn is_integer(&self) -> bool {
+        matches!(self, Self::Integer(_))
+    }
+
+    /// Returns `true` if the numeric literal is a `BigInt`.
+    #[inline]
+    #[must_use]
+    pub const fn is_bigint(&self) -> bool {
+        matches!(self, Self::BigInt(_))
+    }
+
+    /// Returns `true` if the numeric literal is a number that can be losslessly represented as
+    /// an `i32`.
+    ///
+    /// Rationals qualify if they are integral and within the `i32` range, except for `-0`, which
+    /// has no `i32` representation. `BigInt`s never qualify, since they are a different type.
+    #[must_use]
+    pub fn fits_i32(&self) -> bool {
+        match self {
+            Self::Integer(_) => true,
+            Self::Rational(num) => matches!(Self::from_f64(*num), Self::Integer(_)),
+            Self::BigInt(_) => false,
+        }
+    }
+
+    /// Returns `true` if the numeric literal is a number that is a safe integer, as defined by
+    /// [`Number.isSafeInteger`][spec].
+    ///
+    /// Safe integers are the integral numbers in the `-(2^53 - 1)..=2^53 - 1` range, which can be
+    /// represented exactly as an `f64`, and so as an `i64`. `BigInt`s never qualify, since they are
+    /// a different type.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-number.issafeinteger
+    #[must_use]
+    pub fn fits_safe_integer(&self) -> bool {
+        const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+        match self {
+            Self::Integer(_) => true,
+            // Checking if the value is integral, truncation is exact for integral floats.
+            #[allow(clippy::float_cmp)]
+            Self::Rational(num) => num.trunc() == *num && num.abs() <= MAX_SAFE_INTEGER,
+            Self::BigInt(_) => false,
+        }
+    }
+}
+
+impl fmt::Display for Numeric {
+    /// Formats the numeric literal like the ECMAScript [`Number::toString`][spec] abstract
+    /// operation, using the shortest representation that round-trips for rationals. `BigInt`s
+    /// are suffixed with `n`.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-numeric-types-number-tostring
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rational(num) => f.write_str(ryu_js::Buffer::new().format(*num)),
+            Self::Integer(num) => write!(f, "{num}"),
+            Self::BigInt(num) => write!(f, "{num}n"),
+        }
+    }
+}
+
 impl From<f64> for Numeric {
     #[inline]
     fn from(n: f64) -> Self {
@@ -299,9 +465,7 @@ impl TokenKind {
             Self::PrivateIdentifier(ident) => format!("#{}", interner.resolve_expect(ident)),
             Self::Keyword((word, _)) => word.to_string(),
             Self::NullLiteral(_) => "null".to_owned(),
-            Self::NumericLiteral(Numeric::Rational(num)) => num.to_string(),
-            Self::NumericLiteral(Numeric::Integer(num)) => num.to_string(),
-            Self::NumericLiteral(Numeric::BigInt(ref num)) => format!("{num}n"),
+            Self::NumericLiteral(ref num) => num.to_string(),
             Self::Punctuator(punc) => punc.to_string(),
             Self::StringLiteral((lit, _)) => interner.resolve_expect(lit).to_string(),
             Self::TemplateNoSubstitution(ts) | Self::TemplateMiddle(ts) => {
@@ -1,17 +1,17 @@
 use crate::{
-    Error,
+    Error, Warning,
     lexer::{InputElement, Lexer, Token, TokenKind},
     parser::ParseResult,
     source::{ReadChar, UTF8Input},
 };
-use boa_ast::{LinearPosition, PositionGroup};
+use boa_ast::{LinearPosition, Position, PositionGroup, Spanned};
 use boa_interner::Interner;
 
 #[cfg(test)]
 mod tests;
 
 /// The maximum number of tokens which can be peeked ahead.
-const MAX_PEEK_SKIP: usize = 3;
+pub(super) const MAX_PEEK_SKIP: usize = 3;
 
 /// The fixed size of the buffer used for storing values that are peeked ahead.
 ///
@@ -30,6 +30,7 @@ pub(super) struct BufferedLexer<R> {
     read_index: usize,
     write_index: usize,
     last_linear_pos: LinearPosition,
+    last_pos: Position,
 }
 
 impl<R> From<Lexer<R>> for BufferedLexer<R>
@@ -53,6 +54,7 @@ where
             read_index: 0,
             write_index: 0,
             last_linear_pos: LinearPosition::default(),
+            last_pos: Position::new(1, 1),
         }
     }
 }
@@ -115,6 +117,10 @@ where
         self.lexer.set_strict(strict);
     }
 
+    pub(super) fn set_force_strict_numbers(&mut self, force_strict_numbers: bool) {
+        self.lexer.set_force_strict_numbers(force_strict_numbers);
+    }
+
     pub(super) const fn module(&self) -> bool {
         self.lexer.module()
     }
@@ -201,6 +207,9 @@ where
 
             if let Some(tok) = &tok {
                 self.last_linear_pos = tok.linear_span().end();
+                if tok.kind() != &TokenKind::LineTerminator {
+                    self.last_pos = tok.span().end();
+                }
             }
 
             Ok(tok)
@@ -267,12 +276,29 @@ where
         Ok(res_token)
     }
 
+    /// Gets the position in the source code up to which the lexer has read tokens.
+    #[inline]
+    pub(super) const fn lexer_pos(&self) -> Position {
+        self.lexer.pos()
+    }
+
     /// Gets current linear position in the source code.
     #[inline]
     pub(super) fn linear_pos(&self) -> LinearPosition {
         self.last_linear_pos
     }
 
+    /// Gets the end position of the last consumed token, ignoring line terminators.
+    #[inline]
+    pub(super) const fn last_pos(&self) -> Position {
+        self.last_pos
+    }
+
+    /// Gets the warnings produced by the lexer so far.
+    pub(super) fn warnings(&self) -> &[Warning] {
+        self.lexer.warnings()
+    }
+
     pub(super) fn take_source(&mut self) -> boa_ast::SourceText {
         self.lexer.take_source()
     }
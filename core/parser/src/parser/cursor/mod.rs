@@ -1,15 +1,32 @@
 //! Cursor implementation for the parser.
 mod buffered_lexer;
 
+#[cfg(test)]
+mod tests;
+
 use crate::{
-    Error,
+    Error, Warning,
     lexer::{InputElement, Lexer, Token, TokenKind},
     parser::{OrAbrupt, ParseResult},
     source::ReadChar,
 };
-use boa_ast::{LinearPosition, PositionGroup, Punctuator, Spanned};
+use boa_ast::{LinearPosition, Position, PositionGroup, Punctuator, Spanned};
 use boa_interner::Interner;
-use buffered_lexer::BufferedLexer;
+use buffered_lexer::{BufferedLexer, MAX_PEEK_SKIP};
+
+/// The default maximum nesting depth of expressions.
+const DEFAULT_MAX_EXPRESSION_DEPTH: usize = 4096;
+
+/// The minimum number of bytes of stack that must remain to enter a deeply nested expression.
+///
+/// A single nesting level can take over a hundred kilobytes of stack in unoptimized builds, so
+/// this leaves room for the deepest level and for reporting the error.
+const MIN_EXPRESSION_STACK: usize = 256 * 1024;
+
+/// The nesting depth of expressions from which the remaining stack is checked.
+///
+/// Shallow expressions are always parsed, even on threads with a small stack.
+const STACK_CHECK_DEPTH: usize = 8;
 
 /// The result of a peek for a semicolon.
 #[derive(Debug)]
@@ -21,6 +38,7 @@ pub(super) enum SemicolonResult<'s> {
 /// Token cursor.
 ///
 /// This internal structure gives basic testable operations to the parser.
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Debug)]
 pub(super) struct Cursor<R> {
     buffered_lexer: BufferedLexer<R>,
@@ -31,6 +49,27 @@ pub(super) struct Cursor<R> {
     /// Indicate if the cursor is used in `JSON.parse`.
     json_parse: bool,
 
+    /// Indicate if `yield*` delegation is rejected inside async generator declarations.
+    reject_yield_delegation: bool,
+
+    /// Indicate if the Annex B function declarations in `if` statement clauses are allowed.
+    annex_b: bool,
+
+    /// The maximum number of AST nodes that may be constructed, if any.
+    max_nodes: Option<usize>,
+
+    /// The number of AST nodes constructed so far.
+    node_count: usize,
+
+    /// The maximum nesting depth of expressions.
+    max_expression_depth: usize,
+
+    /// The current nesting depth of expressions.
+    expression_depth: usize,
+
+    /// The position of the last trailing comma found in an argument list.
+    arguments_trailing_comma: Option<Position>,
+
     /// A unique identifier for each parser instance.
     /// This is used to generate unique identifiers tagged template literals.
     identifier: u32,
@@ -49,6 +88,13 @@ where
             buffered_lexer: Lexer::new(reader).into(),
             arrow: false,
             json_parse: false,
+            reject_yield_delegation: false,
+            annex_b: cfg!(feature = "annex-b"),
+            max_nodes: None,
+            node_count: 0,
+            max_expression_depth: DEFAULT_MAX_EXPRESSION_DEPTH,
+            expression_depth: 0,
+            arguments_trailing_comma: None,
             identifier: 0,
             tagged_templates_count: 0,
         }
@@ -115,6 +161,36 @@ where
         self.buffered_lexer.peek(skip_n, true, interner)
     }
 
+    /// Peeks the `n`th token after the next token, without consuming it or advancing the cursor.
+    /// This peeking **skips** line terminators.
+    ///
+    /// The cursor only buffers a fixed amount of tokens, so the lookahead is limited to
+    /// [`MAX_PEEK_SKIP`] tokens after the next one (`n <= MAX_PEEK_SKIP`). Unlike [`Cursor::peek`],
+    /// which panics if the limit is exceeded, this returns an error, making it the preferred
+    /// method for productions that need a multi-token lookahead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `n` is bigger than [`MAX_PEEK_SKIP`], or if lexing the peeked tokens fails.
+    pub(super) fn peek_n(
+        &mut self,
+        n: usize,
+        interner: &mut Interner,
+    ) -> ParseResult<Option<&Token>> {
+        if n > MAX_PEEK_SKIP {
+            let position = match self.peek(0, interner)? {
+                Some(token) => token.span().start(),
+                None => self.buffered_lexer.lexer_pos(),
+            };
+            return Err(Error::general(
+                format!("cannot peek more than {MAX_PEEK_SKIP} tokens after the next token"),
+                position,
+            ));
+        }
+
+        self.peek(n, interner)
+    }
+
     /// Peeks a future token, without consuming it or advancing the cursor.
     /// This peeking **does not skips** line terminators.
     ///
@@ -137,6 +213,13 @@ where
         self.buffered_lexer.set_strict(strict);
     }
 
+    /// Sets if legacy octal and leading zero decimal literals are rejected regardless of the
+    /// strict mode.
+    pub(super) fn set_no_legacy_octal(&mut self, no_legacy_octal: bool) {
+        self.buffered_lexer
+            .set_force_strict_numbers(no_legacy_octal);
+    }
+
     /// Returns if the cursor is currently in an arrow function declaration.
     pub(super) const fn arrow(&self) -> bool {
         self.arrow
@@ -157,6 +240,100 @@ where
         self.json_parse = json_parse;
     }
 
+    /// Returns if `yield*` delegation is rejected inside async generator declarations.
+    pub(super) const fn reject_yield_delegation(&self) -> bool {
+        self.reject_yield_delegation
+    }
+
+    /// Set if `yield*` delegation is rejected inside async generator declarations.
+    pub(super) fn set_reject_yield_delegation(&mut self, reject_yield_delegation: bool) {
+        self.reject_yield_delegation = reject_yield_delegation;
+    }
+
+    /// Returns if the Annex B function declarations in `if` statement clauses are allowed.
+    pub(super) const fn annex_b(&self) -> bool {
+        self.annex_b
+    }
+
+    /// Set if the Annex B function declarations in `if` statement clauses are allowed.
+    pub(super) fn set_annex_b(&mut self, annex_b: bool) {
+        self.annex_b = annex_b;
+    }
+
+    /// Set the maximum number of AST nodes that may be constructed.
+    pub(super) fn set_max_nodes(&mut self, max_nodes: Option<usize>) {
+        self.max_nodes = max_nodes;
+    }
+
+    /// Records the construction of an AST node at `position`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number of constructed nodes exceeds the configured maximum.
+    pub(super) fn count_node<P>(&mut self, position: P) -> ParseResult<()>
+    where
+        P: Into<Position>,
+    {
+        self.node_count += 1;
+        match self.max_nodes {
+            Some(max_nodes) if self.node_count > max_nodes => {
+                Err(Error::general("AST node limit exceeded", position))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Set the maximum nesting depth of expressions.
+    pub(super) fn set_max_expression_depth(&mut self, max_expression_depth: usize) {
+        self.max_expression_depth = max_expression_depth;
+    }
+
+    /// Enters a nested expression starting at `position`.
+    ///
+    /// Every call must be paired with a call to [`Cursor::exit_expression`]. The nesting depth
+    /// is not limited while parsing JSON, since `JSON.parse` must accept any valid document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the maximum nesting depth of expressions is exceeded, or if less than
+    /// [`MIN_EXPRESSION_STACK`] bytes of stack remain once the depth reaches
+    /// [`STACK_CHECK_DEPTH`].
+    pub(super) fn enter_expression<P>(&mut self, position: P) -> ParseResult<()>
+    where
+        P: Into<Position>,
+    {
+        if (!self.json_parse && self.expression_depth >= self.max_expression_depth)
+            || (self.expression_depth >= STACK_CHECK_DEPTH
+                && stacker::remaining_stack()
+                    .is_some_and(|remaining| remaining < MIN_EXPRESSION_STACK))
+        {
+            return Err(Error::general(
+                "maximum expression nesting depth exceeded",
+                position,
+            ));
+        }
+        self.expression_depth += 1;
+        Ok(())
+    }
+
+    /// Exits a nested expression entered with [`Cursor::enter_expression`].
+    pub(super) fn exit_expression(&mut self) {
+        self.expression_depth -= 1;
+    }
+
+    /// Records the position of a trailing comma found in an argument list.
+    pub(super) fn set_arguments_trailing_comma(&mut self, position: Position) {
+        self.arguments_trailing_comma = Some(position);
+    }
+
+    /// Returns the position of the last trailing comma found in an argument list, if any.
+    ///
+    /// This is needed to reject a trailing comma after a rest parameter when the arguments of a
+    /// call to `async` turn out to be the parameters of an async arrow function.
+    pub(super) const fn arguments_trailing_comma(&self) -> Option<Position> {
+        self.arguments_trailing_comma
+    }
+
     /// Set the identifier of the cursor.
     #[inline]
     pub(super) fn set_identifier(&mut self, identifier: u32) {
@@ -309,6 +486,17 @@ where
         self.buffered_lexer.linear_pos()
     }
 
+    /// Gets the end position of the last consumed token, ignoring line terminators.
+    #[inline]
+    pub(super) const fn last_pos(&self) -> Position {
+        self.buffered_lexer.last_pos()
+    }
+
+    /// Gets the warnings produced so far.
+    pub(super) fn warnings(&self) -> &[Warning] {
+        self.buffered_lexer.warnings()
+    }
+
     pub(super) fn take_source(&mut self) -> boa_ast::SourceText {
         self.buffered_lexer.take_source()
     }
@@ -0,0 +1,68 @@
+use crate::{
+    lexer::TokenKind,
+    parser::cursor::{Cursor, MAX_PEEK_SKIP},
+    source::UTF8Input,
+};
+use boa_interner::Interner;
+use boa_macros::utf16;
+
+#[test]
+fn peek_n_within_limit() {
+    let mut cursor = Cursor::new(UTF8Input::new(&b"a\nb c d e"[..]));
+    let interner = &mut Interner::default();
+
+    for (n, name) in [(0, "a"), (1, "b"), (MAX_PEEK_SKIP, "d"), (2, "c")] {
+        let expected = TokenKind::identifier(interner.get_or_intern(name));
+        assert_eq!(
+            *cursor
+                .peek_n(n, interner)
+                .unwrap()
+                .expect("Some value expected")
+                .kind(),
+            expected
+        );
+    }
+
+    // Peeking does not consume any token.
+    assert_eq!(
+        *cursor
+            .next(interner)
+            .unwrap()
+            .expect("Some value expected")
+            .kind(),
+        TokenKind::identifier(interner.get_or_intern_static("a", utf16!("a")))
+    );
+}
+
+#[test]
+fn peek_n_past_limit() {
+    let mut cursor = Cursor::new(UTF8Input::new(&b"  a b c d e"[..]));
+    let interner = &mut Interner::default();
+
+    let error = cursor
+        .peek_n(MAX_PEEK_SKIP + 1, interner)
+        .expect_err("peeking past the limit should fail");
+    assert_eq!(
+        error.to_string(),
+        "cannot peek more than 3 tokens after the next token at line 1, col 3"
+    );
+
+    // The cursor is still usable after the error.
+    assert_eq!(
+        *cursor
+            .peek_n(0, interner)
+            .unwrap()
+            .expect("Some value expected")
+            .kind(),
+        TokenKind::identifier(interner.get_or_intern_static("a", utf16!("a")))
+    );
+}
+
+#[test]
+fn peek_n_past_limit_at_end_of_input() {
+    let mut cursor = Cursor::new(UTF8Input::new(&b"a"[..]));
+    let interner = &mut Interner::default();
+
+    cursor.advance(interner);
+    assert!(cursor.peek_n(MAX_PEEK_SKIP + 1, interner).is_err());
+}
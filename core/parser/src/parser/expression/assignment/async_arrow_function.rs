@@ -13,7 +13,8 @@ use crate::{
     lexer::{Error as LexError, TokenKind},
     parser::{
         AllowIn, AllowYield, Cursor, OrAbrupt, TokenParser,
-        expression::BindingIdentifier,
+        expression::{BindingIdentifier, primary::expression_to_formal_parameters},
+        find_outside_functions,
         function::{FormalParameters, FunctionBody},
         name_in_lexically_declared_names,
     },
@@ -24,12 +25,15 @@ use ast::{
     operations::{ContainsSymbol, bound_names, contains, lexically_declared_names},
 };
 use boa_ast::{
-    self as ast, Punctuator, Span, Spanned, StatementList,
-    declaration::Variable,
+    self as ast, LinearSpan, Position, Punctuator, Span, Spanned, StatementList,
+    declaration::{Binding, Variable},
+    expression::Call,
     function::{FormalParameter, FormalParameterList},
+    pattern::{ArrayPatternElement, ObjectPatternElement, Pattern},
     statement::Return,
+    visitor::NodeRef,
 };
-use boa_interner::Interner;
+use boa_interner::{Interner, Sym};
 
 /// Async arrow function parsing.
 ///
@@ -57,46 +61,142 @@ impl AsyncArrowFunction {
             allow_yield: allow_yield.into(),
         }
     }
-}
 
-impl<R> TokenParser<R> for AsyncArrowFunction
-where
-    R: ReadChar,
-{
-    type Output = ast::function::AsyncArrowFunction;
+    /// Parses an async arrow function whose head was parsed as a call to `async`.
+    ///
+    /// The arguments of the call are converted into the formal parameters of the function, as
+    /// described by the `CoverCallExpressionAndAsyncArrowHead` production. The cursor must be
+    /// placed just after the closing parenthesis of the call.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-CoverCallExpressionAndAsyncArrowHead
+    pub(in crate::parser) fn parse_cover_call<R>(
+        self,
+        call: &Call,
+        params_start_position: Position,
+        start_linear_span: LinearSpan,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> ParseResult<ast::function::AsyncArrowFunction>
+    where
+        R: ReadChar,
+    {
+        let mut parameters = Vec::with_capacity(call.args().len());
+        for (index, arg) in call.args().iter().enumerate() {
+            // Early Error: CoverCallExpressionAndAsyncArrowHead must cover an AsyncArrowHead, whose
+            // parameters are parsed with [+Await], so `await` cannot be an identifier in them.
+            if let Some(span) = find_await_identifier(arg) {
+                return Err(Error::general(
+                    "keyword `await` not allowed in this context",
+                    span.start(),
+                ));
+            }
 
-    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
-        let async_token =
-            cursor.expect((Keyword::Async, false), "async arrow function", interner)?;
-        let start_linear_span = async_token.linear_span();
-        let async_token_span = async_token.span();
-        cursor.peek_expect_no_lineterminator(0, "async arrow function", interner)?;
+            let ast::Expression::Spread(spread) = arg else {
+                expression_to_formal_parameters(arg, &mut parameters, cursor.strict(), arg.span())?;
+                continue;
+            };
 
-        let next_token = cursor.peek(0, interner).or_abrupt()?;
-        let (params, params_start_position) =
-            if next_token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
-                let params_start_position = cursor
-                    .expect(Punctuator::OpenParen, "async arrow function", interner)?
-                    .span()
-                    .end();
+            if index + 1 != call.args().len() {
+                return Err(Error::general(
+                    "rest parameter must be last formal parameter",
+                    spread.span().start(),
+                ));
+            }
 
-                let params = FormalParameters::new(false, true).parse(cursor, interner)?;
-                cursor.expect(Punctuator::CloseParen, "async arrow function", interner)?;
-                (params, params_start_position)
-            } else {
-                let params_start_position = next_token.span().start();
-                let param = BindingIdentifier::new(self.allow_yield, true)
-                    .parse(cursor, interner)
-                    .set_context("async arrow function")?;
-                (
-                    FormalParameterList::from(FormalParameter::new(
-                        Variable::from_identifier(param, None),
-                        false,
-                    )),
-                    params_start_position,
-                )
+            // The arguments of a call may end with a trailing comma, but a rest parameter may not.
+            if let Some(comma) = cursor.arguments_trailing_comma()
+                && comma >= spread.span().end()
+            {
+                return Err(Error::general(
+                    "rest parameter must be last formal parameter",
+                    comma,
+                ));
+            }
+            let variable = match spread.target() {
+                ast::Expression::Identifier(ident) => Variable::from_identifier(*ident, None),
+                ast::Expression::ArrayLiteral(array) => Variable::from_pattern(
+                    array
+                        .to_pattern(cursor.strict())
+                        .ok_or_else(|| {
+                            Error::general(
+                                "invalid array binding pattern in formal parameter list",
+                                spread.target().span().start(),
+                            )
+                        })?
+                        .into(),
+                    None,
+                ),
+                ast::Expression::ObjectLiteral(object) => Variable::from_pattern(
+                    object
+                        .to_pattern(cursor.strict())
+                        .ok_or_else(|| {
+                            Error::general(
+                                "invalid object binding pattern in formal parameter list",
+                                spread.target().span().start(),
+                            )
+                        })?
+                        .into(),
+                    None,
+                ),
+                _ => {
+                    return Err(Error::general(
+                        "invalid rest parameter in formal parameter list",
+                        spread.target().span().start(),
+                    ));
+                }
             };
+            parameters.push(FormalParameter::new(variable, true));
+        }
+        let params = FormalParameterList::from(parameters);
+
+        // The arguments were parsed as assignment patterns, which may assign to property
+        // accesses, but the parameters of an async arrow function can only bind identifiers.
+        for parameter in params.as_ref() {
+            if let Binding::Pattern(pattern) = parameter.variable().binding()
+                && let Some(span) = find_property_access(pattern)
+            {
+                return Err(Error::general(
+                    "invalid binding pattern in formal parameter list",
+                    span.start(),
+                ));
+            }
+        }
+
+        // Early Error: It is a Syntax Error if any element of the BoundNames of
+        // CoverCallExpressionAndAsyncArrowHead is "await".
+        if bound_names(&params).contains(&Sym::AWAIT) {
+            return Err(Error::general(
+                "keyword `await` not allowed in this context",
+                params_start_position,
+            ));
+        }
 
+        self.parse_body(
+            params,
+            params_start_position,
+            call.function().span().start(),
+            start_linear_span,
+            cursor,
+            interner,
+        )
+    }
+
+    /// Parses the arrow and the body of an async arrow function, once its parameters are known.
+    fn parse_body<R>(
+        self,
+        params: FormalParameterList,
+        params_start_position: Position,
+        start_position: Position,
+        start_linear_span: LinearSpan,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> ParseResult<ast::function::AsyncArrowFunction>
+    where
+        R: ReadChar,
+    {
         cursor.peek_expect_no_lineterminator(0, "async arrow function", interner)?;
         cursor.expect(Punctuator::Arrow, "async arrow function", interner)?;
 
@@ -153,11 +253,60 @@ where
             params,
             body,
             linear_span,
-            Span::new(async_token_span.start(), body_span_end),
+            Span::new(start_position, body_span_end),
         ))
     }
 }
 
+impl<R> TokenParser<R> for AsyncArrowFunction
+where
+    R: ReadChar,
+{
+    type Output = ast::function::AsyncArrowFunction;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let async_token =
+            cursor.expect((Keyword::Async, false), "async arrow function", interner)?;
+        let start_linear_span = async_token.linear_span();
+        let async_token_span = async_token.span();
+        cursor.peek_expect_no_lineterminator(0, "async arrow function", interner)?;
+
+        let next_token = cursor.peek(0, interner).or_abrupt()?;
+        let (params, params_start_position) =
+            if next_token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
+                let params_start_position = cursor
+                    .expect(Punctuator::OpenParen, "async arrow function", interner)?
+                    .span()
+                    .end();
+
+                let params = FormalParameters::new(false, true).parse(cursor, interner)?;
+                cursor.expect(Punctuator::CloseParen, "async arrow function", interner)?;
+                (params, params_start_position)
+            } else {
+                let params_start_position = next_token.span().start();
+                let param = BindingIdentifier::new(self.allow_yield, true)
+                    .parse(cursor, interner)
+                    .set_context("async arrow function")?;
+                (
+                    FormalParameterList::from(FormalParameter::new(
+                        Variable::from_identifier(param, None),
+                        false,
+                    )),
+                    params_start_position,
+                )
+            };
+
+        self.parse_body(
+            params,
+            params_start_position,
+            async_token_span.start(),
+            start_linear_span,
+            cursor,
+            interner,
+        )
+    }
+}
+
 /// <https://tc39.es/ecma262/#prod-AsyncConciseBody>
 #[derive(Debug, Clone, Copy)]
 pub(in crate::parser) struct AsyncConciseBody {
@@ -203,3 +352,35 @@ where
         Ok(body)
     }
 }
+
+/// Returns the span of the first `await` identifier in `node`, ignoring the nested functions
+/// that don't inherit the [Await] parameter of the enclosing code.
+fn find_await_identifier(node: &ast::Expression) -> Option<Span> {
+    find_outside_functions(node, |node| match node {
+        NodeRef::Identifier(ident) if ident.sym() == Sym::AWAIT => Some(ident.span()),
+        _ => None,
+    })
+}
+
+/// Returns the span of the first property access that `pattern` assigns to, if any.
+fn find_property_access(pattern: &Pattern) -> Option<Span> {
+    match pattern {
+        Pattern::Object(object) => object.bindings().iter().find_map(|element| match element {
+            ObjectPatternElement::AssignmentPropertyAccess { access, .. }
+            | ObjectPatternElement::AssignmentRestPropertyAccess { access } => Some(access.span()),
+            ObjectPatternElement::Pattern { pattern, .. } => find_property_access(pattern),
+            ObjectPatternElement::SingleName { .. } | ObjectPatternElement::RestProperty { .. } => {
+                None
+            }
+        }),
+        Pattern::Array(array) => array.bindings().iter().find_map(|element| match element {
+            ArrayPatternElement::PropertyAccess { access, .. }
+            | ArrayPatternElement::PropertyAccessRest { access } => Some(access.span()),
+            ArrayPatternElement::Pattern { pattern, .. }
+            | ArrayPatternElement::PatternRest { pattern } => find_property_access(pattern),
+            ArrayPatternElement::Elision
+            | ArrayPatternElement::SingleName { .. }
+            | ArrayPatternElement::SingleNameRest { .. } => None,
+        }),
+    }
+}
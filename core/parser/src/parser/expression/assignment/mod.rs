@@ -15,7 +15,7 @@ mod r#yield;
 
 use crate::{
     Error,
-    lexer::{Error as LexError, InputElement, TokenKind},
+    lexer::{Error as LexError, InputElement, Token, TokenKind},
     parser::{
         AllowAwait, AllowIn, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
         expression::assignment::{
@@ -33,7 +33,7 @@ use boa_ast::{
     expression::operator::assign::{Assign, AssignOp, AssignTarget},
     operations::{ContainsSymbol, bound_names, contains, lexically_declared_names},
 };
-use boa_interner::Interner;
+use boa_interner::{Interner, Sym};
 
 pub(super) use exponentiation::ExponentiationExpression;
 
@@ -87,6 +87,25 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Expression> {
         cursor.set_goal(InputElement::RegExp);
 
+        let position = cursor.peek(0, interner).or_abrupt()?.span().start();
+        cursor.enter_expression(position)?;
+        let expression = self.parse_assignment(cursor, interner);
+        cursor.exit_expression();
+        expression
+    }
+}
+
+impl AssignmentExpression {
+    /// Parses the assignment expression, once the nesting depth has been accounted for.
+    fn parse_assignment<R>(
+        self,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> ParseResult<Expression>
+    where
+        R: ReadChar,
+    {
+        let mut async_arrow_head = None;
         match cursor.peek(0, interner).or_abrupt()?.kind() {
             // [+Yield]YieldExpression[?In, ?Await]
             TokenKind::Keyword((Keyword::Yield, _)) if self.allow_yield.0 => {
@@ -121,26 +140,32 @@ where
                     1
                 };
 
-                let peek_1 = cursor.peek(1, interner).or_abrupt()?.kind().clone();
+                let peek_1 = cursor.peek(1, interner).or_abrupt()?;
+                let peek_1_end = peek_1.span().end();
+                let peek_1 = peek_1.kind().clone();
                 if !cursor
                     .peek_is_line_terminator(skip_n, interner)
                     .or_abrupt()?
-                    && (matches!(peek_1, TokenKind::Punctuator(Punctuator::OpenParen))
-                        || (matches!(
-                            peek_1,
-                            TokenKind::IdentifierName(_)
-                                | TokenKind::Keyword((
-                                    Keyword::Yield | Keyword::Await | Keyword::Of,
-                                    _
-                                ))
-                        ) && matches!(
-                            cursor.peek(2, interner).or_abrupt()?.kind(),
-                            TokenKind::Punctuator(Punctuator::Arrow)
-                        )))
                 {
-                    return Ok(AsyncArrowFunction::new(self.allow_in, self.allow_yield)
-                        .parse(cursor, interner)?
-                        .into());
+                    if matches!(peek_1, TokenKind::Punctuator(Punctuator::OpenParen)) {
+                        // `async (` starts either a call to `async` or an async arrow function,
+                        // which is only known after the closing parenthesis.
+                        async_arrow_head = Some(peek_1_end);
+                    } else if matches!(
+                        peek_1,
+                        TokenKind::IdentifierName(_)
+                            | TokenKind::Keyword((
+                                Keyword::Yield | Keyword::Await | Keyword::Of,
+                                _
+                            ))
+                    ) && matches!(
+                        cursor.peek(2, interner).or_abrupt()?.kind(),
+                        TokenKind::Punctuator(Punctuator::Arrow)
+                    ) {
+                        return Ok(AsyncArrowFunction::new(self.allow_in, self.allow_yield)
+                            .parse(cursor, interner)?
+                            .into());
+                    }
                 }
             }
             _ => {}
@@ -154,6 +179,29 @@ where
         let mut lhs = ConditionalExpression::new(self.allow_in, self.allow_yield, self.allow_await)
             .parse(cursor, interner)?;
 
+        // If the left hand side is a call to `async` followed by an arrow, the call is the head
+        // of an async arrow function.
+        if let Some(params_start_position) = async_arrow_head
+            && let Expression::Call(call) = &lhs
+            && matches!(
+                call.function(),
+                Expression::Identifier(ident)
+                    if ident.sym() == Sym::ASYNC && ident.span().start() == position
+            )
+            && cursor.peek(0, interner)?.map(Token::kind)
+                == Some(&TokenKind::Punctuator(Punctuator::Arrow))
+        {
+            return Ok(AsyncArrowFunction::new(self.allow_in, self.allow_yield)
+                .parse_cover_call(
+                    call,
+                    params_start_position,
+                    start_linear_span,
+                    cursor,
+                    interner,
+                )?
+                .into());
+        }
+
         // If the left hand side is a parameter list, we must parse an arrow function.
         if let Expression::FormalParameterList(parameters) = lhs {
             cursor.peek_expect_no_lineterminator(0, "arrow function", interner)?;
@@ -48,21 +48,21 @@ where
     type Output = Await;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
-        let await_span_start = cursor
+        let await_span = cursor
             .expect(
                 TokenKind::Keyword((Keyword::Await, false)),
                 "Await expression parsing",
                 interner,
             )?
-            .span()
-            .start();
+            .span();
 
         let expr = UnaryExpression::new(self.allow_yield, true).parse(cursor, interner)?;
         let expr_span_end = expr.span().end();
 
         Ok(Await::new(
             expr.into(),
-            Span::new(await_span_start, expr_span_end),
+            await_span,
+            Span::new(await_span.start(), expr_span_end),
         ))
     }
 }
@@ -83,6 +83,7 @@ where
                     }
 
                     if let Some(next) = cursor.next_if(Punctuator::CloseParen, interner)? {
+                        cursor.set_arguments_trailing_comma(next_token.span().start());
                         break next.span().end();
                     }
                 }
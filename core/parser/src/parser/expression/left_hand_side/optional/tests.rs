@@ -4,7 +4,7 @@ use boa_ast::{
     Span, Statement,
     expression::{
         Identifier, Optional, OptionalOperation, OptionalOperationKind,
-        access::PropertyAccessField, literal::Literal,
+        access::PropertyAccessField, literal::Literal, operator::Conditional,
     },
 };
 use boa_interner::Interner;
@@ -119,3 +119,26 @@ fn private_identifier_early_error() {
     check_invalid_script("this?.a?.#a");
     check_invalid_script("this.a.#a");
 }
+
+/// `?.` followed by a digit is a conditional operator followed by a decimal literal.
+#[test]
+fn conditional_with_decimal_literal() {
+    let interner = &mut Interner::default();
+    let a = interner.get_or_intern_static("a", utf16!("a"));
+
+    check_script_parser(
+        "a?.5:.6",
+        vec![
+            Statement::Expression(
+                Conditional::new(
+                    Identifier::new(a, Span::new((1, 1), (1, 2))).into(),
+                    Literal::new(0.5, Span::new((1, 3), (1, 5))).into(),
+                    Literal::new(0.6, Span::new((1, 6), (1, 8))).into(),
+                )
+                .into(),
+            )
+            .into(),
+        ],
+        interner,
+    );
+}
@@ -85,7 +85,9 @@ macro_rules! expression {
                 while let Some(tok) = cursor.peek(0, interner)? {
                     match *tok.kind() {
                         TokenKind::Punctuator(op) if $( op == $op )||* => {
+                            let position = tok.span().start();
                             cursor.advance(interner);
+                            cursor.count_node(position)?;
                             lhs = Binary::new(
                                 op.as_binary_op().expect("Could not get binary operation."),
                                 lhs,
@@ -145,6 +147,7 @@ where
         while let Some(tok) = cursor.peek(0, interner)? {
             match *tok.kind() {
                 TokenKind::Punctuator(Punctuator::Comma) => {
+                    let position = tok.span().start();
                     if cursor.peek(1, interner).or_abrupt()?.kind()
                         == &TokenKind::Punctuator(Punctuator::CloseParen)
                     {
@@ -158,6 +161,7 @@ where
                     }
 
                     cursor.advance(interner);
+                    cursor.count_node(position)?;
 
                     lhs = Binary::new(
                         Punctuator::Comma
@@ -1,9 +1,10 @@
-use crate::parser::tests::check_script_parser;
+use crate::{Parser, Source, parser::tests::check_script_parser};
 use boa_ast::{
-    Declaration, Span, Statement, StatementList, StatementListItem,
+    Declaration, Expression, Span, Statement, StatementList, StatementListItem,
     declaration::{LexicalDeclaration, Variable},
     expression::{Identifier, literal::Literal},
     function::{FormalParameterList, FunctionBody, FunctionExpression},
+    scope::Scope,
     statement::Return,
 };
 use boa_interner::Interner;
@@ -284,3 +285,53 @@ fn check_function_non_reserved_keyword() {
         interner,
     );
 }
+
+/// Checks the signature of function expressions with default and rest parameters.
+#[test]
+fn function_expression_signature_only() {
+    let interner = &mut Interner::default();
+    let script = Parser::new(Source::from_bytes(indoc! {"
+        (function add(a, b = 1, ...rest) { return a + b; });
+        (async function* () {});
+    "}))
+    .parse_script(&Scope::new_global(), interner)
+    .expect("failed to parse");
+
+    let expression = |index: usize| {
+        let Some(StatementListItem::Statement(statement)) = script.statements().get(index) else {
+            panic!("expected a statement");
+        };
+        let Statement::Expression(Expression::Parenthesized(expression)) = statement.as_ref()
+        else {
+            panic!("expected a parenthesized expression");
+        };
+        expression.expression()
+    };
+
+    let Expression::FunctionExpression(function) = expression(0) else {
+        panic!("expected a function expression");
+    };
+    let signature = function.signature_only();
+    assert_eq!(
+        signature
+            .name()
+            .map(|name| interner.resolve_expect(name.sym()).to_string()),
+        Some("add".to_owned())
+    );
+    assert_eq!(signature.parameters(), function.parameters());
+    assert_eq!(signature.parameters().as_ref().len(), 3);
+    assert_eq!(signature.parameters().length(), 1);
+    assert!(signature.parameters().has_rest_parameter());
+    assert!(signature.parameters().has_expressions());
+    assert!(!signature.is_async());
+    assert!(!signature.is_generator());
+
+    let Expression::AsyncGeneratorExpression(function) = expression(1) else {
+        panic!("expected an async generator expression");
+    };
+    let signature = function.signature_only();
+    assert_eq!(signature.name(), None);
+    assert!(signature.parameters().as_ref().is_empty());
+    assert!(signature.is_async());
+    assert!(signature.is_generator());
+}
@@ -96,8 +96,9 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         // TODO: tok currently consumes the token instead of peeking, so the token
         // isn't passed and consumed by parsers according to spec (EX: GeneratorExpression)
+        let tok_position = cursor.peek(0, interner).or_abrupt()?.span().start();
+        cursor.count_node(tok_position)?;
         let tok = cursor.peek(0, interner).or_abrupt()?;
-        let tok_position = tok.span().start();
 
         match tok.kind() {
             TokenKind::Keyword((Keyword::This, true))
@@ -147,7 +148,7 @@ where
                     Some(TokenKind::Keyword((Keyword::Function, _)))
                         if !is_line_terminator && !contain_escaped_char =>
                     {
-                        match cursor.peek(2, interner)?.map(Token::kind) {
+                        match cursor.peek_n(2, interner)?.map(Token::kind) {
                             Some(TokenKind::Punctuator(Punctuator::Mul)) => {
                                 AsyncGeneratorExpression::new()
                                     .parse(cursor, interner)
@@ -552,7 +553,7 @@ where
 }
 
 /// Convert an expression to a formal parameter and append it to the given parameter list.
-fn expression_to_formal_parameters(
+pub(super) fn expression_to_formal_parameters(
     node: &ast::Expression,
     parameters: &mut Vec<FormalParameter>,
     strict: bool,
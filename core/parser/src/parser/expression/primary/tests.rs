@@ -1,4 +1,4 @@
-use crate::parser::tests::{check_invalid_script, check_script_parser};
+use crate::parser::tests::{check_invalid_script, check_invalid_script_msg, check_script_parser};
 use boa_ast::{
     Expression, Span, Statement,
     expression::{
@@ -46,6 +46,16 @@ fn check_string() {
     );
 }
 
+#[test]
+fn check_fractional_part_edge_cases() {
+    // The lexer reads `1..2` as the literal `1.` followed by the literal `.2`, which can't follow
+    // an expression.
+    check_invalid_script_msg(
+        "1..2",
+        "expected token ';', got '0.2' in expression statement at line 1, col 3",
+    );
+}
+
 #[test]
 fn check_destructuring_assignment_object_assignment_operator() {
     let interner = &mut Interner::default();
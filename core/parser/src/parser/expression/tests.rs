@@ -1,9 +1,13 @@
-use crate::parser::tests::{check_invalid_script, check_script_parser};
+use crate::{
+    Parser, Source,
+    parser::tests::{check_invalid_script, check_script_parser},
+};
 use boa_ast::{
-    Declaration, Expression, LinearPosition, LinearSpan, Span, Statement, StatementList,
+    Declaration, Expression, LinearPosition, LinearSpan, Position, Span, Spanned, Statement,
+    StatementList, StatementListItem,
     declaration::{LexicalDeclaration, Variable},
     expression::{
-        Call, Identifier, Parenthesized, RegExpLiteral,
+        Await, Call, Identifier, Parenthesized, RegExpLiteral,
         literal::Literal,
         operator::{
             Assign, Binary,
@@ -12,9 +16,12 @@ use boa_ast::{
         },
     },
     function::{AsyncArrowFunction, FormalParameter, FormalParameterList, FunctionBody},
+    scope::Scope,
+    visitor::{VisitWith, Visitor},
 };
 use boa_interner::{Interner, Sym};
 use boa_macros::utf16;
+use std::ops::ControlFlow;
 
 /// Checks numeric operations
 #[test]
@@ -1202,6 +1209,141 @@ fn parse_async_arrow_function_named_of() {
     );
 }
 
+/// Checks that `async` is disambiguated between async arrow functions, async function
+/// declarations and the `async` identifier, including when followed by a line terminator.
+#[test]
+fn check_async_disambiguation() {
+    fn kinds(source: &str) -> Vec<&'static str> {
+        let interner = &mut Interner::default();
+        let script = Parser::new(Source::from_bytes(source))
+            .parse_script(&Scope::new_global(), interner)
+            .unwrap_or_else(|err| panic!("failed to parse `{source}`: {err}"));
+        let async_sym = interner.get("async");
+        script
+            .statements()
+            .statements()
+            .iter()
+            .map(|item| match item {
+                StatementListItem::Declaration(declaration) => match declaration.as_ref() {
+                    Declaration::AsyncFunctionDeclaration(_) => "async function declaration",
+                    Declaration::FunctionDeclaration(_) => "function declaration",
+                    _ => "other declaration",
+                },
+                StatementListItem::Statement(statement) => match statement.as_ref() {
+                    Statement::Expression(Expression::AsyncArrowFunction(_)) => {
+                        "async arrow function"
+                    }
+                    Statement::Expression(Expression::ArrowFunction(_)) => "arrow function",
+                    Statement::Expression(Expression::Identifier(ident))
+                        if Some(ident.sym()) == async_sym =>
+                    {
+                        "async identifier"
+                    }
+                    Statement::Expression(Expression::Call(call))
+                        if matches!(
+                            call.function(),
+                            Expression::Identifier(ident) if Some(ident.sym()) == async_sym
+                        ) =>
+                    {
+                        "call to async"
+                    }
+                    _ => "other statement",
+                },
+            })
+            .collect()
+    }
+
+    assert_eq!(kinds("async x => x"), ["async arrow function"]);
+    assert_eq!(kinds("async (x) => x"), ["async arrow function"]);
+    assert_eq!(
+        kinds("async (x, y) => { return x; }"),
+        ["async arrow function"]
+    );
+    assert_eq!(
+        kinds("async function f() {}"),
+        ["async function declaration"]
+    );
+    assert_eq!(
+        kinds("async ({ a = 1 }, ...b) => a"),
+        ["async arrow function"]
+    );
+    assert_eq!(kinds("async (x)"), ["call to async"]);
+    assert_eq!(kinds("async (x, ...y);"), ["call to async"]);
+    assert_eq!(kinds("async\n(x)"), ["call to async"]);
+    assert_eq!(
+        kinds("async\nx => x"),
+        ["async identifier", "arrow function"]
+    );
+    assert_eq!(
+        kinds("async\nfunction f() {}"),
+        ["async identifier", "function declaration"]
+    );
+
+    // `async` followed by a line terminator is an identifier, so `async\n(x)` is a call, which
+    // is not a valid arrow function head.
+    check_invalid_script("async\n(x) => x");
+    check_invalid_script("async x\n=> x");
+    check_invalid_script("async (x)\n=> x");
+    check_invalid_script("async (x)(y) => x");
+    check_invalid_script("async (await) => 1");
+    check_invalid_script("async (...a, b) => 1");
+}
+
+/// Checks the early errors of async arrow function heads parsed as a call to `async`.
+#[test]
+fn check_async_arrow_cover_early_errors() {
+    fn error_position(source: &str) -> Position {
+        let interner = &mut Interner::default();
+        match Parser::new(Source::from_bytes(source)).parse_script(&Scope::new_global(), interner) {
+            Ok(_) => panic!("`{source}` should not parse"),
+            Err(err) => match err.innermost() {
+                crate::Error::General { position, .. } => *position,
+                _ => panic!("unexpected error for `{source}`: {err}"),
+            },
+        }
+    }
+
+    // The parameters of an async arrow function are parsed with [+Await].
+    check_invalid_script("async (x = await) => x");
+    check_invalid_script("async ([await]) => 1");
+    check_invalid_script("async ({ a: await }) => 1");
+    check_invalid_script("async ({ await }) => 1");
+    check_invalid_script("async (x = () => await) => x");
+    check_invalid_script("async (x = class { [await]() {} }) => x");
+    assert_eq!(
+        error_position("async (a, b = await) => 1"),
+        Position::new(1, 15)
+    );
+
+    // The parameters can only bind identifiers, not assign to property accesses.
+    check_invalid_script("async ({a: b.c}) => 1");
+    check_invalid_script("async ([a.b]) => 1");
+
+    // A rest parameter can't be followed by a trailing comma.
+    check_invalid_script("async (...a,) => 1");
+    check_invalid_script("async (...[a],) => 1");
+    assert_eq!(
+        error_position("async (a, ...b,) => 1"),
+        Position::new(1, 15)
+    );
+    assert_eq!(error_position("async (...a, b) => 1"), Position::new(1, 8));
+
+    // These are all valid outside of the parameters, or in nested functions.
+    for source in [
+        "async (await, ...a,)",
+        "async (x = y.await, { await: z }) => x",
+        "async (x = function await() { return await; }) => x",
+        "async (x = { await() {} }) => x",
+    ] {
+        assert!(
+            Parser::new(Source::from_bytes(source))
+                .parse_script(&Scope::new_global(), &mut Interner::default())
+                .is_ok(),
+            "`{source}` should parse"
+        );
+    }
+}
+
 macro_rules! check_non_reserved_identifier {
     ($keyword:literal) => {{
         let interner = &mut Interner::default();
@@ -1248,3 +1390,161 @@ fn check_non_reserved_identifiers() {
     check_non_reserved_identifier!("set");
     check_non_reserved_identifier!("target");
 }
+
+#[test]
+fn check_await_keyword_span() {
+    struct AwaitFinder(Option<Await>);
+
+    impl<'ast> Visitor<'ast> for AwaitFinder {
+        type BreakTy = ();
+
+        fn visit_await(&mut self, node: &'ast Await) -> ControlFlow<Self::BreakTy> {
+            self.0 = Some(node.clone());
+            ControlFlow::Break(())
+        }
+    }
+
+    let interner = &mut Interner::default();
+    let module = Parser::new(Source::from_bytes("let x = 1;\nawait   f(x);"))
+        .parse_module(&Scope::new_global(), interner)
+        .expect("failed to parse");
+
+    let mut finder = AwaitFinder(None);
+    let _ = module.visit_with(&mut finder);
+    let node = finder.0.expect("await expression not found");
+
+    assert_eq!(node.keyword_span(), Span::new((2, 1), (2, 6)));
+    assert_eq!(node.span(), Span::new((2, 1), (2, 13)));
+}
+
+#[test]
+fn max_nodes_limit() {
+    let parse = |source: &str, max_nodes: Option<usize>| {
+        let mut parser = Parser::new(Source::from_bytes(source));
+        parser.set_max_nodes(max_nodes);
+        parser.parse_script(&Scope::new_global(), &mut Interner::default())
+    };
+
+    // Each source is followed by the number of nodes it is made of.
+    for (source, nodes) in [
+        ("1;", 2),
+        ("1; 2;", 4),
+        ("a + b * c;", 6),
+        ("a, b;", 4),
+        ("((((1))));", 6),
+        ("let x = 1;", 2),
+        ("if (a) { b; }", 5),
+    ] {
+        assert!(parse(source, None).is_ok(), "{source}");
+        assert!(parse(source, Some(nodes)).is_ok(), "{source}");
+
+        let error = parse(source, Some(nodes - 1)).expect_err(source);
+        assert!(
+            error.to_string().starts_with("AST node limit exceeded"),
+            "{source}: {error}"
+        );
+    }
+}
+
+/// Parses `source` as a script on a new thread with `stack_size` bytes of stack.
+fn parse_with_stack(
+    source: String,
+    max_expression_depth: Option<usize>,
+    stack_size: usize,
+) -> Result<(), String> {
+    std::thread::Builder::new()
+        .stack_size(stack_size)
+        .spawn(move || {
+            let mut parser = Parser::new(Source::from_bytes(&source));
+            if let Some(max_expression_depth) = max_expression_depth {
+                parser.set_max_expression_depth(max_expression_depth);
+            }
+            parser
+                .parse_script(&Scope::new_global(), &mut Interner::default())
+                .map(|_| ())
+                .map_err(|error| error.to_string())
+        })
+        .unwrap()
+        .join()
+        .unwrap()
+}
+
+#[test]
+fn max_expression_depth() {
+    let parse = |source: &str, max_expression_depth: Option<usize>| {
+        parse_with_stack(source.to_owned(), max_expression_depth, 64 * 1024 * 1024)
+    };
+    let nested = format!("{}1{};", "(".repeat(40), ")".repeat(40));
+
+    assert_eq!(parse(&nested, None), Ok(()));
+    assert_eq!(parse(&nested, Some(41)), Ok(()));
+
+    assert_eq!(
+        parse(&nested, Some(32)),
+        Err("maximum expression nesting depth exceeded at line 1, col 33".to_owned())
+    );
+
+    // Function bodies add to the depth of the enclosing code.
+    let in_function = format!("(function() {{ return {nested} }});");
+    assert_eq!(parse(&in_function, Some(43)), Ok(()));
+    assert!(parse(&in_function, Some(41)).is_err());
+
+    // Valid deeply nested code parses with the default limit.
+    let conditionals = format!("{}0;", "a ? b : ".repeat(100));
+    assert_eq!(parse(&conditionals, None), Ok(()));
+    assert!(parse(&conditionals, Some(50)).is_err());
+}
+
+#[test]
+fn default_max_expression_depth() {
+    // Hostile nesting must fail with an error instead of overflowing the stack of a thread with
+    // the default size of spawned threads.
+    for hostile in [
+        format!("{}1{};", "(".repeat(1000), ")".repeat(1000)),
+        format!("{}1{};", "[".repeat(1000), "]".repeat(1000)),
+        format!("({}1{});", "{a: ".repeat(1000), "}".repeat(1000)),
+        format!("{}1{};", "f(".repeat(1000), ")".repeat(1000)),
+        format!(
+            "{}1{};",
+            "(function() { return ".repeat(1000),
+            "; })".repeat(1000)
+        ),
+        format!("{}1{};", "`${".repeat(1000), "}`".repeat(1000)),
+    ] {
+        let error = parse_with_stack(hostile, None, 2 * 1024 * 1024).unwrap_err();
+        assert!(
+            error.starts_with("maximum expression nesting depth exceeded"),
+            "{error}"
+        );
+    }
+}
+
+#[test]
+fn shallow_expression_small_stack() {
+    // The remaining stack is not checked for shallow expressions, so they parse on any thread.
+    assert_eq!(
+        parse_with_stack("let x = 1 + 2;".to_owned(), None, 256 * 1024),
+        Ok(())
+    );
+}
+
+#[test]
+fn deep_json_nesting() {
+    // `JSON.parse` accepts any valid document, no matter how deeply it is nested.
+    let arrays = format!("({}1{});", "[".repeat(300), "]".repeat(300));
+    let objects = format!("({}1{});", "{\"a\":".repeat(300), "}".repeat(300));
+    for source in [arrays, objects] {
+        std::thread::Builder::new()
+            .stack_size(64 * 1024 * 1024)
+            .spawn(move || {
+                let mut parser = Parser::new(Source::from_bytes(&source));
+                parser.set_json_parse();
+                parser
+                    .parse_script(&Scope::new_global(), &mut Interner::default())
+                    .expect("deeply nested JSON should parse");
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+}
@@ -10,7 +10,7 @@ pub(crate) mod function;
 mod tests;
 
 use crate::{
-    Error, Source,
+    Error, Source, Warning,
     error::ParseResult,
     lexer::{Error as LexError, InputElement},
     parser::{
@@ -20,17 +20,24 @@ use crate::{
     source::ReadChar,
 };
 use boa_ast::{
-    Position, StatementList,
-    function::{FormalParameterList, FunctionBody},
+    Expression, Position, Span, StatementList,
+    expression::{Identifier, literal::ObjectMethodDefinition},
+    function::{
+        AsyncFunctionDeclaration, AsyncFunctionExpression, AsyncGeneratorDeclaration,
+        AsyncGeneratorExpression, ClassElement, ClassElementName, FormalParameterList,
+        FunctionBody, FunctionDeclaration, FunctionExpression, GeneratorDeclaration,
+        GeneratorExpression,
+    },
     operations::{
         ContainsSymbol, all_private_identifiers_valid, check_labels, contains,
         contains_invalid_object_literal, lexically_declared_names, var_declared_names,
     },
     scope::Scope,
+    visitor::{NodeRef, VisitWith, Visitor},
 };
 use boa_interner::{Interner, Sym};
 use rustc_hash::FxHashSet;
-use std::path::Path;
+use std::{ops::ControlFlow, path::Path};
 
 use self::statement::ModuleItemList;
 
@@ -290,6 +297,88 @@ impl<R> Parser<'_, R> {
         self.cursor.set_json_parse(true);
     }
 
+    /// Set the parser to reject `yield*` delegation inside async generator declarations.
+    ///
+    /// This is useful for hosts targeting runtimes without support for delegating
+    /// to another async iterator. By default, `yield*` is accepted.
+    pub fn set_reject_yield_delegation(&mut self)
+    where
+        R: ReadChar,
+    {
+        self.cursor.set_reject_yield_delegation(true);
+    }
+
+    /// Set if the parser allows the [Annex B][spec] function declarations in `if` statement
+    /// clauses (`if (x) function f() {}`) in non-strict code.
+    ///
+    /// This allows a single build to parse both web-compatible and spec-only sources. By default,
+    /// this is enabled only if the `annex-b` feature is enabled.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-functiondeclarations-in-ifstatement-statement-clauses
+    pub fn set_annex_b(&mut self, annex_b: bool)
+    where
+        R: ReadChar,
+    {
+        self.cursor.set_annex_b(annex_b);
+    }
+
+    /// Set if the parser rejects legacy octal literals (`0777`) and decimal literals with leading
+    /// zeros (`08`) even in non-strict code.
+    ///
+    /// This allows hosts to only accept modern numeric literals independently of the
+    /// `"use strict"` directive. By default, these literals are only rejected in strict mode code.
+    pub fn set_no_legacy_octal(&mut self, no_legacy_octal: bool)
+    where
+        R: ReadChar,
+    {
+        self.cursor.set_no_legacy_octal(no_legacy_octal);
+    }
+
+    /// Set the maximum number of AST nodes the parser may construct.
+    ///
+    /// Every statement, declaration, primary expression (literals, identifiers, parenthesized
+    /// expressions, function and class expressions, ...) and binary or comma operation counts as
+    /// one node, no matter how many grammar productions it is parsed through.
+    ///
+    /// Once the limit is crossed, parsing aborts with an "AST node limit exceeded" error. This
+    /// bounds the resources used by the parser independently of the input length, which is
+    /// useful for hosts parsing untrusted input. By default, there is no limit.
+    pub fn set_max_nodes(&mut self, max_nodes: Option<usize>)
+    where
+        R: ReadChar,
+    {
+        self.cursor.set_max_nodes(max_nodes);
+    }
+
+    /// Set the maximum nesting depth of expressions.
+    ///
+    /// Parsing fails with a "maximum expression nesting depth exceeded" error when expressions
+    /// are nested deeper than this limit, like in `((((...))))`. Expressions inside nested
+    /// function bodies add to the depth of the enclosing code.
+    ///
+    /// The default limit is 4096. Independently of this limit, expressions nested more than 8
+    /// levels deep fail with the same error instead of overflowing the stack when less than 256
+    /// kilobytes of stack remain, so hosts running the parser on a small stack may reach this
+    /// error earlier. The limit doesn't apply when parsing JSON with [`Parser::set_json_parse`].
+    pub fn set_max_expression_depth(&mut self, max_expression_depth: usize)
+    where
+        R: ReadChar,
+    {
+        self.cursor.set_max_expression_depth(max_expression_depth);
+    }
+
+    /// Gets the warnings produced while parsing.
+    ///
+    /// Warnings point at valid source code that may not behave as intended, like integer
+    /// literals too big to be represented as a finite number.
+    #[must_use]
+    pub fn warnings(&self) -> &[Warning]
+    where
+        R: ReadChar,
+    {
+        self.cursor.warnings()
+    }
+
     /// Set the unique identifier for the parser.
     pub fn set_identifier(&mut self, identifier: u32)
     where
@@ -581,6 +670,119 @@ fn name_in_lexically_declared_names(
     Ok(())
 }
 
+/// Returns the span that `find` returns for the first expression or identifier of `node` it
+/// matches, without searching the parameters and bodies of nested functions and methods.
+///
+/// The computed names of methods are still searched, since they are evaluated by the enclosing
+/// code, and so are arrow functions, which inherit the `[Yield]` and `[Await]` parameters of the
+/// enclosing code.
+fn find_outside_functions<'a, N, F>(node: &'a N, find: F) -> Option<Span>
+where
+    N: VisitWith,
+    F: FnMut(NodeRef<'a>) -> Option<Span>,
+{
+    struct Finder<F>(F);
+
+    impl<'a, F> Visitor<'a> for Finder<F>
+    where
+        F: FnMut(NodeRef<'a>) -> Option<Span>,
+    {
+        type BreakTy = Span;
+
+        fn visit_expression(&mut self, node: &'a Expression) -> ControlFlow<Self::BreakTy> {
+            if let Some(span) = (self.0)(node.into()) {
+                return ControlFlow::Break(span);
+            }
+            node.visit_with(self)
+        }
+
+        fn visit_identifier(&mut self, node: &'a Identifier) -> ControlFlow<Self::BreakTy> {
+            match (self.0)(node.into()) {
+                Some(span) => ControlFlow::Break(span),
+                None => ControlFlow::Continue(()),
+            }
+        }
+
+        fn visit_function_expression(
+            &mut self,
+            _: &'a FunctionExpression,
+        ) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Continue(())
+        }
+
+        fn visit_function_declaration(
+            &mut self,
+            _: &'a FunctionDeclaration,
+        ) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Continue(())
+        }
+
+        fn visit_async_function_expression(
+            &mut self,
+            _: &'a AsyncFunctionExpression,
+        ) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Continue(())
+        }
+
+        fn visit_async_function_declaration(
+            &mut self,
+            _: &'a AsyncFunctionDeclaration,
+        ) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Continue(())
+        }
+
+        fn visit_generator_expression(
+            &mut self,
+            _: &'a GeneratorExpression,
+        ) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Continue(())
+        }
+
+        fn visit_generator_declaration(
+            &mut self,
+            _: &'a GeneratorDeclaration,
+        ) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Continue(())
+        }
+
+        fn visit_async_generator_expression(
+            &mut self,
+            _: &'a AsyncGeneratorExpression,
+        ) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Continue(())
+        }
+
+        fn visit_async_generator_declaration(
+            &mut self,
+            _: &'a AsyncGeneratorDeclaration,
+        ) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Continue(())
+        }
+
+        fn visit_object_method_definition(
+            &mut self,
+            node: &'a ObjectMethodDefinition,
+        ) -> ControlFlow<Self::BreakTy> {
+            self.visit_property_name(node.name())
+        }
+
+        fn visit_class_element(&mut self, node: &'a ClassElement) -> ControlFlow<Self::BreakTy> {
+            match node {
+                ClassElement::MethodDefinition(method) => match method.name() {
+                    ClassElementName::PropertyName(name) => self.visit_property_name(name),
+                    ClassElementName::PrivateName(_) => ControlFlow::Continue(()),
+                },
+                _ => node.visit_with(self),
+            }
+        }
+    }
+
+    match node.visit_with(&mut Finder(find)) {
+        ControlFlow::Break(span) => Some(span),
+        ControlFlow::Continue(()) => None,
+    }
+}
+
 /// Trait to reduce boilerplate in the parser.
 trait OrAbrupt<T> {
     /// Will convert an `Ok(None)` to an [`Error::AbruptEnd`] or return the inner type if not.
@@ -7,14 +7,18 @@
 mod tests;
 
 use crate::{
+    Error,
     parser::{
         AllowAwait, AllowDefault, AllowYield, Cursor, ParseResult, TokenParser,
+        find_outside_functions,
         statement::declaration::hoistable::{CallableDeclaration, parse_callable_declaration},
     },
     source::ReadChar,
 };
 use boa_ast::{
-    Keyword, Punctuator, function::AsyncGeneratorDeclaration as AsyncGeneratorDeclarationNode,
+    Expression, Keyword, Punctuator, Span, Spanned,
+    function::{AsyncGeneratorDeclaration as AsyncGeneratorDeclarationNode, FunctionBody},
+    visitor::NodeRef,
 };
 use boa_interner::Interner;
 
@@ -110,6 +114,16 @@ where
         cursor.expect(Punctuator::Mul, "async generator declaration", interner)?;
 
         let result = parse_callable_declaration(&self, cursor, interner)?;
+
+        if cursor.reject_yield_delegation()
+            && let Some(span) = find_yield_delegation(&result.2)
+        {
+            return Err(Error::general(
+                "yield delegation (`yield*`) is not allowed in async generator declarations",
+                span.start(),
+            ));
+        }
+
         let span = start_linear_span.union(result.2.linear_pos_end());
 
         Ok(AsyncGeneratorDeclarationNode::new(
@@ -117,3 +131,14 @@ where
         ))
     }
 }
+
+/// Returns the span of the first `yield*` expression in `body`.
+///
+/// Nested functions are not searched, since their `yield` expressions do not belong to the
+/// async generator being parsed.
+fn find_yield_delegation(body: &FunctionBody) -> Option<Span> {
+    find_outside_functions(body, |node| match node {
+        NodeRef::Expression(Expression::Yield(node)) if node.delegate() => Some(node.span()),
+        _ => None,
+    })
+}
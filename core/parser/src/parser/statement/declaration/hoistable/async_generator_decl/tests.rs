@@ -1,8 +1,9 @@
-use crate::parser::tests::check_script_parser;
+use crate::{Parser, Source, parser::tests::check_script_parser};
 use boa_ast::{
     Declaration, LinearPosition, LinearSpan, Span, StatementList,
     expression::Identifier,
     function::{AsyncGeneratorDeclaration, FormalParameterList, FunctionBody},
+    scope::Scope,
 };
 use boa_interner::Interner;
 use boa_macros::utf16;
@@ -27,3 +28,51 @@ fn async_generator_function_declaration() {
         interner,
     );
 }
+
+#[test]
+fn async_generator_yield_delegation_rejected() {
+    let mut parser = Parser::new(Source::from_bytes(
+        "async function* gen() {\n    yield 1;\n    yield* other();\n}",
+    ));
+    parser.set_reject_yield_delegation();
+
+    let error = parser
+        .parse_script(&Scope::new_global(), &mut Interner::default())
+        .expect_err("`yield*` should be rejected");
+
+    assert_eq!(
+        error.to_string(),
+        "yield delegation (`yield*`) is not allowed in async generator declarations at line 3, col 5"
+    );
+}
+
+#[test]
+fn async_generator_yield_delegation_in_nested_generator() {
+    let mut parser = Parser::new(Source::from_bytes(
+        "async function* gen() { function* inner() { yield* other(); } yield 1; }",
+    ));
+    parser.set_reject_yield_delegation();
+
+    assert!(
+        parser
+            .parse_script(&Scope::new_global(), &mut Interner::default())
+            .is_ok()
+    );
+}
+
+#[test]
+fn async_generator_yield_delegation_in_computed_class_member_name() {
+    let mut parser = Parser::new(Source::from_bytes(
+        "async function* g() { class C { [yield* x]() {} } }",
+    ));
+    parser.set_reject_yield_delegation();
+
+    let error = parser
+        .parse_script(&Scope::new_global(), &mut Interner::default())
+        .expect_err("`yield*` should be rejected");
+
+    assert_eq!(
+        error.to_string(),
+        "yield delegation (`yield*`) is not allowed in async generator declarations at line 1, col 34"
+    );
+}
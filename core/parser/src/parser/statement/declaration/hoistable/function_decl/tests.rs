@@ -1,10 +1,11 @@
-use crate::parser::tests::check_script_parser;
+use crate::{Parser, Source, parser::tests::check_script_parser};
 use boa_ast::{
-    Declaration, Span, StatementList,
+    Declaration, Expression, Span, Statement, StatementList, StatementListItem,
     expression::Identifier,
     function::{FormalParameterList, FunctionBody, FunctionDeclaration},
+    scope::Scope,
 };
-use boa_interner::Interner;
+use boa_interner::{Interner, ToIndentedString};
 use boa_macros::utf16;
 
 const PSEUDO_LINEAR_POS: boa_ast::LinearPosition = boa_ast::LinearPosition::new(0);
@@ -145,3 +146,125 @@ fn function_declaration_keywords() {
     );
     check_script_parser("function target() {}", ast, interner);
 }
+
+/// Renaming a parsed function declaration.
+#[test]
+fn function_declaration_rename() {
+    let interner = &mut Interner::default();
+    let script = Parser::new(Source::from_bytes("function hello(a) { return eval(a); }"))
+        .parse_script(&Scope::new_global(), interner)
+        .expect("failed to parse");
+
+    let Some(StatementListItem::Declaration(declaration)) = script.statements().first() else {
+        panic!("expected a declaration");
+    };
+    let Declaration::FunctionDeclaration(function) = declaration.as_ref() else {
+        panic!("expected a function declaration");
+    };
+
+    let mut renamed = function.clone();
+    renamed.rename(Identifier::new(
+        interner.get_or_intern_static("goodbye", utf16!("goodbye")),
+        Span::new((1, 10), (1, 15)),
+    ));
+
+    assert_eq!(
+        renamed.to_indented_string(interner, 0),
+        "function goodbye(a) {\n    return eval(a);\n}"
+    );
+    assert!(renamed.contains_direct_eval());
+    assert_eq!(renamed.scopes(), function.scopes());
+    assert_eq!(renamed.parameters(), function.parameters());
+    assert_eq!(renamed.body(), function.body());
+}
+
+/// Checks the expected argument count of function declarations and expressions.
+#[test]
+fn function_expected_arg_count() {
+    let cases = [
+        ("(a, b)", 2),
+        ("(a, b = 1)", 1),
+        ("(a, b = 1, c)", 1),
+        ("(...rest)", 0),
+        ("(a, ...rest)", 1),
+        ("({ a }, [b])", 2),
+    ];
+
+    for (params, expected) in cases {
+        let interner = &mut Interner::default();
+        let source = format!("function f{params} {{}} (async function* g{params} {{}});");
+        let script = Parser::new(Source::from_bytes(&source))
+            .parse_script(&Scope::new_global(), interner)
+            .expect("failed to parse");
+
+        let [
+            StatementListItem::Declaration(declaration),
+            StatementListItem::Statement(statement),
+        ] = script.statements().statements()
+        else {
+            panic!("expected a declaration and a statement");
+        };
+        let Declaration::FunctionDeclaration(function) = declaration.as_ref() else {
+            panic!("expected a function declaration");
+        };
+        assert_eq!(function.expected_arg_count(), expected, "{source}");
+
+        let Statement::Expression(Expression::Parenthesized(expression)) = statement.as_ref()
+        else {
+            panic!("expected a parenthesized expression");
+        };
+        let Expression::AsyncGeneratorExpression(generator) = expression.expression() else {
+            panic!("expected an async generator expression");
+        };
+        assert_eq!(generator.expected_arg_count(), expected, "{source}");
+    }
+}
+
+/// Enumerates the bindings of the scopes of a function declaration.
+#[test]
+fn function_declaration_binding_names() {
+    let cases = [
+        (
+            "function f(a, b) { var c; let d; const e = 1; }",
+            &["arguments", "a", "b", "c"][..],
+            &["d", "e"][..],
+        ),
+        (
+            "function f(a, b = 1) { var c; let d; }",
+            &["arguments", "a", "b"][..],
+            &["c", "d"][..],
+        ),
+        (
+            "function f(a) { var b; }",
+            &["arguments", "a", "b"][..],
+            &[][..],
+        ),
+    ];
+
+    for (source, parameters, body) in cases {
+        let interner = &mut Interner::default();
+        let script = Parser::new(Source::from_bytes(source))
+            .parse_script(&Scope::new_global(), interner)
+            .expect("failed to parse");
+
+        let Some(StatementListItem::Declaration(declaration)) = script.statements().first() else {
+            panic!("expected a declaration");
+        };
+        let Declaration::FunctionDeclaration(function) = declaration.as_ref() else {
+            panic!("expected a function declaration");
+        };
+
+        let names = |syms: Vec<_>| {
+            syms.into_iter()
+                .map(|sym| interner.resolve_expect(sym).to_string())
+                .collect::<Vec<_>>()
+        };
+        let scopes = function.scopes();
+        assert_eq!(
+            names(scopes.parameter_binding_names(interner)),
+            parameters,
+            "{source}"
+        );
+        assert_eq!(names(scopes.body_binding_names(interner)), body, "{source}");
+    }
+}
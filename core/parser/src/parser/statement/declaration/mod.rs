@@ -65,6 +65,8 @@ where
     type Output = ast::Declaration;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let position = cursor.peek(0, interner).or_abrupt()?.span().start();
+        cursor.count_node(position)?;
         let tok = cursor.peek(0, interner).or_abrupt()?;
 
         match tok.kind() {
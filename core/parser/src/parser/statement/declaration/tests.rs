@@ -2,7 +2,8 @@ use crate::parser::tests::{check_invalid_script, check_module_parser, check_scri
 use boa_ast::{
     Declaration, ModuleItem, Span, Statement,
     declaration::{
-        ExportDeclaration, ExportSpecifier, LexicalDeclaration, VarDeclaration, Variable,
+        ExportDeclaration, ExportSpecifier, ImportDeclaration, ImportKind, ImportSpecifier,
+        LexicalDeclaration, ModuleSpecifier, VarDeclaration, Variable,
     },
     expression::{
         Identifier,
@@ -486,3 +487,28 @@ fn module_export_reserved() {
         interner,
     );
 }
+
+/// Checks module imports with a default and a named binding.
+#[test]
+fn module_import_default_and_named() {
+    let interner = &mut Interner::default();
+    let foo = interner.get_or_intern_static("foo", utf16!("foo"));
+    let bar = interner.get_or_intern_static("bar", utf16!("bar"));
+    let baz = interner.get_or_intern_static("baz", utf16!("baz"));
+    let module = interner.get_or_intern_static("mod", utf16!("mod"));
+    check_module_parser(
+        r#"import foo, { bar as baz } from "mod""#,
+        vec![ModuleItem::ImportDeclaration(ImportDeclaration::new(
+            Some(Identifier::new(foo, Span::new((1, 8), (1, 11)))),
+            ImportKind::Named {
+                names: vec![ImportSpecifier::new(
+                    Identifier::new(baz, Span::new((1, 22), (1, 25))),
+                    bar,
+                )]
+                .into(),
+            },
+            ModuleSpecifier::new(module),
+        ))],
+        interner,
+    );
+}
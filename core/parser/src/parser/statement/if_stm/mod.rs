@@ -3,6 +3,7 @@ mod tests;
 
 use crate::{
     Error,
+    error::ErrorContext,
     lexer::TokenKind,
     parser::{
         AllowAwait, AllowReturn, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
@@ -57,11 +58,14 @@ where
     type Output = If;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
-        cursor.expect((Keyword::If, false), "if statement", interner)?;
+        let keyword_span = cursor
+            .expect((Keyword::If, false), "if statement", interner)?
+            .span();
         cursor.expect(Punctuator::OpenParen, "if statement", interner)?;
 
-        let condition =
-            Expression::new(true, self.allow_yield, self.allow_await).parse(cursor, interner)?;
+        let condition = Expression::new(true, self.allow_yield, self.allow_await)
+            .parse(cursor, interner)
+            .enclosed_in("if statement condition")?;
 
         let position = cursor
             .expect(Punctuator::CloseParen, "if statement", interner)?
@@ -74,7 +78,7 @@ where
             TokenKind::Keyword((Keyword::Function, _)) => {
                 // FunctionDeclarations in IfStatement Statement Clauses
                 // https://tc39.es/ecma262/#sec-functiondeclarations-in-ifstatement-statement-clauses
-                if cfg!(not(feature = "annex-b")) || strict {
+                if !cursor.annex_b() || strict {
                     return Err(Error::misplaced_function_declaration(position, strict));
                 }
                 // Source text matched by this production is processed as if each matching
@@ -84,7 +88,8 @@ where
                     vec![StatementListItem::Declaration(
                         Declaration::FunctionDeclaration(
                             FunctionDeclaration::new(self.allow_yield, self.allow_await, false)
-                                .parse(cursor, interner)?,
+                                .parse(cursor, interner)
+                                .enclosed_in("if statement body")?,
                         )
                         .into(),
                     )],
@@ -93,7 +98,8 @@ where
                 .into()
             }
             _ => Statement::new(self.allow_yield, self.allow_await, self.allow_return)
-                .parse(cursor, interner)?,
+                .parse(cursor, interner)
+                .enclosed_in("if statement body")?,
         };
 
         // Early Error: It is a Syntax Error if IsLabelledFunction(the first Statement) is true.
@@ -119,7 +125,7 @@ where
                         TokenKind::Keyword((Keyword::Function, _)) => {
                             // FunctionDeclarations in IfStatement Statement Clauses
                             // https://tc39.es/ecma262/#sec-functiondeclarations-in-ifstatement-statement-clauses
-                            if cfg!(not(feature = "annex-b")) || strict {
+                            if !cursor.annex_b() || strict {
                                 return Err(Error::misplaced_function_declaration(
                                     position, strict,
                                 ));
@@ -136,7 +142,8 @@ where
                                             self.allow_await,
                                             false,
                                         )
-                                        .parse(cursor, interner)?,
+                                        .parse(cursor, interner)
+                                        .enclosed_in("else clause")?,
                                     )
                                     .into(),
                                 )],
@@ -145,7 +152,8 @@ where
                             .into()
                         }
                         _ => Statement::new(self.allow_yield, self.allow_await, self.allow_return)
-                            .parse(cursor, interner)?,
+                            .parse(cursor, interner)
+                            .enclosed_in("else clause")?,
                     };
 
                     // Early Error: It is a Syntax Error if IsLabelledFunction(the second Statement) is true.
@@ -161,6 +169,7 @@ where
             None
         };
 
-        Ok(If::new(condition, then_node, else_stmt))
+        let span = keyword_span.encompass(cursor.last_pos());
+        Ok(If::new(condition, then_node, else_stmt, span))
     }
 }
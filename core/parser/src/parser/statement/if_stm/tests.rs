@@ -1,10 +1,14 @@
-use crate::parser::tests::check_script_parser;
+use crate::{
+    Error, Parser, Source,
+    parser::tests::{check_roundtrip, check_script_parser},
+};
 use boa_ast::{
-    Span, Statement,
+    Span, Spanned, Statement, StatementListItem,
     expression::literal::Literal,
+    scope::Scope,
     statement::{Block, If},
 };
-use boa_interner::Interner;
+use boa_interner::{Interner, ToIndentedString};
 
 const PSEUDO_LINEAR_POS: boa_ast::LinearPosition = boa_ast::LinearPosition::new(0);
 
@@ -17,6 +21,7 @@ fn if_without_else_block() {
                 Literal::new(true, Span::new((1, 5), (1, 9))).into(),
                 Block::from((Vec::new(), PSEUDO_LINEAR_POS)).into(),
                 None,
+                Span::new((1, 1), (1, 13)),
             ))
             .into(),
         ],
@@ -33,9 +38,151 @@ fn if_without_else_block_with_trailing_newline() {
                 Literal::new(true, Span::new((1, 5), (1, 9))).into(),
                 Block::from((Vec::new(), PSEUDO_LINEAR_POS)).into(),
                 None,
+                Span::new((1, 1), (1, 13)),
             ))
             .into(),
         ],
         &mut Interner::default(),
     );
 }
+
+#[test]
+fn if_span() {
+    let span = |source: &str| {
+        let script = Parser::new(Source::from_bytes(source))
+            .parse_script(&Scope::new_global(), &mut Interner::default())
+            .expect("failed to parse");
+        let Some(Statement::If(node)) = script.statements().first().and_then(|item| match item {
+            StatementListItem::Statement(statement) => Some(statement.as_ref()),
+            StatementListItem::Declaration(_) => None,
+        }) else {
+            panic!("expected an if statement");
+        };
+        node.span()
+    };
+
+    assert_eq!(span("if (a) b();"), Span::new((1, 1), (1, 12)));
+    assert_eq!(span("if (a) b()\nfoo;"), Span::new((1, 1), (1, 11)));
+    assert_eq!(
+        span("if (a) {\n  b();\n} else c();"),
+        Span::new((1, 1), (3, 12))
+    );
+    assert_eq!(
+        span("  if (a) b(); else if (c) { d(); }"),
+        Span::new((1, 3), (1, 35))
+    );
+}
+
+#[test]
+fn if_roundtrip() {
+    check_roundtrip("if (true) {}");
+    check_roundtrip("if (a) b(); else if (c) { d(); } else e();");
+    check_roundtrip("if (a) if (b) c(); else d();");
+}
+
+#[test]
+fn annex_b_function_declaration_toggle() {
+    let parse = |source: &str, annex_b: bool| {
+        let mut parser = Parser::new(Source::from_bytes(source));
+        parser.set_annex_b(annex_b);
+        parser.parse_script(&Scope::new_global(), &mut Interner::default())
+    };
+
+    for source in ["if (x) function f(){}", "if (x) {} else function f(){}"] {
+        assert!(parse(source, true).is_ok(), "{source}");
+
+        let error = parse(source, false).expect_err(source);
+        assert!(
+            error
+                .to_string()
+                .starts_with("functions can only be declared at the top level or inside a block."),
+            "{source}: {error}"
+        );
+
+        let strict_source = format!("'use strict'; {source}");
+        assert!(parse(&strict_source, true).is_err(), "{strict_source}");
+    }
+}
+
+#[test]
+fn if_nested_error_enclosing() {
+    let parse = |source: &str| {
+        Parser::new(Source::from_bytes(source))
+            .parse_script(&Scope::new_global(), &mut Interner::default())
+            .expect_err(source)
+    };
+
+    let error = parse("if (a) {\n    x = ;\n}");
+    assert_eq!(error.enclosing(), ["if statement body"]);
+    assert!(matches!(error.innermost(), Error::Unexpected { .. }));
+    // The enclosing productions are part of the message, before the position.
+    assert_eq!(
+        error.to_string(),
+        "unexpected token ';', primary expression in if statement body at line 2, col 9"
+    );
+
+    let error = parse("if (a) if (b +) {}");
+    assert_eq!(
+        error.enclosing(),
+        ["if statement condition", "if statement body"]
+    );
+
+    // Every enclosing production is recorded, so the depth of the error is known.
+    let error = parse("if (a) if (b) if (c) { x = ; }");
+    assert_eq!(
+        error.enclosing(),
+        [
+            "if statement body",
+            "if statement body",
+            "if statement body"
+        ]
+    );
+
+    let error = parse("if (a) {} else { x = ; }");
+    assert_eq!(error.enclosing(), ["else clause"]);
+
+    // Errors in the `if` statement itself are not nested.
+    let error = parse("if (a {}");
+    assert!(error.enclosing().is_empty(), "{error}");
+
+    // The kind of a nested error is still found through the innermost error.
+    let error = parse("if (a) {");
+    assert_eq!(error.enclosing(), ["if statement body"]);
+    assert!(matches!(error.innermost(), Error::AbruptEnd));
+}
+
+#[test]
+fn if_normalized_string() {
+    let normalize = |source: &str| {
+        let interner = &mut Interner::default();
+        let script = Parser::new(Source::from_bytes(source))
+            .parse_script(&Scope::new_global(), interner)
+            .expect("failed to parse");
+        let [StatementListItem::Statement(stmt)] = script.statements().statements() else {
+            panic!("expected a single statement");
+        };
+        let Statement::If(if_stm) = stmt.as_ref() else {
+            panic!("expected an if statement");
+        };
+        (
+            if_stm.to_indented_string(interner, 0),
+            if_stm.to_normalized_string(interner, 0),
+        )
+    };
+
+    let (plain, normalized) = normalize("if (x) y();");
+    assert_eq!(plain, "if (x) y();");
+    assert_eq!(normalized, "if (x) {\n    y();\n}");
+
+    let (_, normalized) = normalize("if (a) b(); else if (c) { d(); } else e();");
+    assert_eq!(
+        normalized,
+        "if (a) {\n    b();\n} else if (c) {\n    d();\n} else {\n    e();\n}"
+    );
+
+    let (_, normalized) = normalize("if (a) if (b) c(); else d();");
+    assert_eq!(
+        normalized,
+        "if (a) {\n    if (b) {\n        c();\n    } else {\n        d();\n    }\n}"
+    );
+}
@@ -1,4 +1,7 @@
-use crate::parser::tests::{check_invalid_script, check_script_parser};
+use crate::{
+    Parser, Source,
+    parser::tests::{check_invalid_script_msg, check_roundtrip, check_script_parser},
+};
 use boa_ast::{
     Expression, Span, Statement, StatementListItem,
     declaration::{VarDeclaration, Variable},
@@ -13,9 +16,11 @@ use boa_ast::{
             update::{UpdateOp, UpdateTarget},
         },
     },
+    operations::while_to_for,
+    scope::Scope,
     statement::{Block, Break, DoWhileLoop, WhileLoop},
 };
-use boa_interner::Interner;
+use boa_interner::{Interner, ToIndentedString};
 use boa_macros::utf16;
 use indoc::indoc;
 
@@ -348,14 +353,97 @@ fn do_while_spaces() {
     );
 }
 
+/// Checks that printed do-while statements parse back to the same statements.
+#[test]
+fn do_while_roundtrip() {
+    check_roundtrip("do { a += 1; } while (true)");
+    check_roundtrip(
+        "var i = 0; do { console.log(\"hello\"); } while (i++ < 10) console.log(\"end\");",
+    );
+    check_roundtrip("do break; while (true)");
+}
+
 /// Checks rejection of const bindings without init in for loops
 #[test]
 fn reject_const_no_init_for_loop() {
-    check_invalid_script("for (const h;;);");
+    check_invalid_script_msg(
+        "for (const h;;);",
+        "Expected initializer for const declaration at line 1, col 13",
+    );
 }
 
 /// Checks rejection of for await .. in loops
 #[test]
 fn reject_for_await_in_loop() {
-    check_invalid_script("for await (x in [1,2,3]);");
+    check_invalid_script_msg(
+        "for await (x in [1,2,3]);",
+        "for await...of is only valid in async functions or async generators",
+    );
+}
+
+/// Checks that `for await .. of` loops are accepted inside async functions and generators.
+#[test]
+fn for_await_of_in_async_function() {
+    check_roundtrip("async function f() { for await (const x of asyncIterable) { g(x); } }");
+    check_roundtrip("async function* f() { for await (let x of asyncIterable) yield x; }");
+    check_roundtrip("async function f() { let x; for await (x of asyncIterable); }");
+    check_roundtrip("var f = async () => { for await (var x of asyncIterable); };");
+}
+
+/// Checks rejection of `for await` loops that are not `for .. of` loops inside async functions.
+#[test]
+fn reject_for_await_in_async_function() {
+    check_invalid_script_msg(
+        "async function f() { for await (const x in obj); }",
+        "`await` can only be used in a `for await .. of` loop at line 1, col 41",
+    );
+    check_invalid_script_msg(
+        "async function f() { for await (x in obj); }",
+        "`await` can only be used in a `for await .. of` loop at line 1, col 35",
+    );
+    check_invalid_script_msg(
+        "async function f() { for await (let i = 0; i < 1; i++); }",
+        "`await` can only be used in a `for await .. of` loop",
+    );
+    check_invalid_script_msg(
+        "async function f() { for await (;;); }",
+        "`await` can only be used in a `for await .. of` loop",
+    );
+}
+
+/// Checks that `while` loops rewritten as `for` loops print back to equivalent code.
+#[test]
+fn while_to_for_roundtrip() {
+    for (source, expected) in [
+        ("while (i < 3) { i++; }", "for (; i < 3; ) {\n    i++;\n}\n"),
+        ("while (i < 3) i++;", "for (; i < 3; ) i++;\n"),
+    ] {
+        let interner = &mut Interner::default();
+        let script = Parser::new(Source::from_bytes(source))
+            .parse_script(&Scope::new_global(), interner)
+            .expect("failed to parse");
+        let [StatementListItem::Statement(stmt)] = script.statements().statements() else {
+            panic!("expected a single statement");
+        };
+        let Statement::WhileLoop(while_loop) = stmt.as_ref() else {
+            panic!("expected a while loop");
+        };
+
+        let for_loop = Statement::from(while_to_for(while_loop));
+        let printed = for_loop.to_indented_string(interner, 0) + "\n";
+        assert_eq!(printed, expected);
+        check_roundtrip(&printed);
+
+        let reparsed = Parser::new(Source::from_bytes(&printed))
+            .parse_script(&Scope::new_global(), interner)
+            .expect("failed to parse the printed loop");
+        let [StatementListItem::Statement(reparsed)] = reparsed.statements().statements() else {
+            panic!("expected a single statement");
+        };
+        let Statement::ForLoop(reparsed) = reparsed.as_ref() else {
+            panic!("expected a for loop");
+        };
+        assert!(reparsed.init().is_none() && reparsed.final_expr().is_none());
+        assert_eq!(reparsed.condition(), Some(while_loop.condition()));
+    }
 }
@@ -117,6 +117,8 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         // TODO: add BreakableStatement and divide Whiles, fors and so on to another place.
+        let position = cursor.peek(0, interner).or_abrupt()?.span().start();
+        cursor.count_node(position)?;
         let tok = cursor.peek(0, interner).or_abrupt()?;
 
         match tok.kind() {
@@ -7,7 +7,7 @@ use std::convert::TryInto;
 use crate::{Parser, Source};
 use boa_ast::{
     Expression, LinearPosition, LinearSpan, Module, ModuleItem, ModuleItemList, Script, Span,
-    Statement, StatementList, StatementListItem,
+    Spanned, Statement, StatementList, StatementListItem,
     declaration::{Declaration, LexicalDeclaration, VarDeclaration, Variable},
     expression::{
         Call, Identifier, New, Parenthesized,
@@ -27,7 +27,7 @@ use boa_ast::{
     scope::Scope,
     statement::{Block, If, Return, With},
 };
-use boa_interner::Interner;
+use boa_interner::{Interner, ToInternedString};
 use boa_macros::utf16;
 use indoc::indoc;
 
@@ -68,6 +68,67 @@ where
     );
 }
 
+/// Checks that printing the AST of the given script and parsing the result gives back the same
+/// AST.
+///
+/// Spans depend on the layout of the source, so the original and reparsed ASTs are compared
+/// structurally through their debug representations, with every position left out.
+#[track_caller]
+pub(super) fn check_roundtrip(js: &str) {
+    let interner = &mut Interner::default();
+    let parse = |source: &str, interner: &mut Interner| {
+        Parser::new(Source::from_bytes(source))
+            .parse_script(&Scope::new_global(), interner)
+            .unwrap_or_else(|e| panic!("failed to parse `{source}`: {e}"))
+    };
+
+    let script = parse(js, interner);
+    let printed = script.to_interned_string(interner);
+    let reparsed = parse(&printed, interner);
+    assert_eq!(
+        without_positions(&format!("{reparsed:#?}")),
+        without_positions(&format!("{script:#?}")),
+        "printed script `{printed}` does not round-trip"
+    );
+}
+
+/// Replaces the spans and positions in the debug representation of an AST by `_`.
+fn without_positions(debug: &str) -> String {
+    const POSITIONS: [(&str, char, char); 4] = [
+        ("Span(", '(', ')'),
+        ("LinearSpan {", '{', '}'),
+        ("LinearPosition {", '{', '}'),
+        ("Position {", '{', '}'),
+    ];
+
+    let mut result = String::with_capacity(debug.len());
+    let mut rest = debug;
+    'outer: while !rest.is_empty() {
+        let at_word_start = !result.ends_with(|c: char| c.is_alphanumeric() || c == '_');
+        for (prefix, open, close) in POSITIONS {
+            if at_word_start && rest.starts_with(prefix) {
+                let mut depth = 0;
+                for (i, c) in rest.char_indices() {
+                    if c == open {
+                        depth += 1;
+                    } else if c == close {
+                        depth -= 1;
+                        if depth == 0 {
+                            result.push('_');
+                            rest = &rest[i + 1..];
+                            continue 'outer;
+                        }
+                    }
+                }
+            }
+        }
+        let mut chars = rest.chars();
+        result.extend(chars.next());
+        rest = chars.as_str();
+    }
+    result
+}
+
 /// Checks that the given javascript string creates a parse error.
 #[track_caller]
 pub(super) fn check_invalid_script(js: &str) {
@@ -78,6 +139,20 @@ pub(super) fn check_invalid_script(js: &str) {
     );
 }
 
+/// Checks that the given javascript string creates a parse error whose message contains
+/// `expected`.
+#[track_caller]
+pub(super) fn check_invalid_script_msg(js: &str, expected: &str) {
+    let error = Parser::new(Source::from_bytes(js))
+        .parse_script(&Scope::new_global(), &mut Interner::default())
+        .expect_err("expected a parse error");
+    let message = error.to_string();
+    assert!(
+        message.contains(expected),
+        "error message `{message}` does not contain `{expected}`"
+    );
+}
+
 /// Should be parsed as `new Class().method()` instead of `new (Class().method())`
 #[test]
 fn check_construct_call_precedence() {
@@ -700,6 +775,7 @@ fn empty_statement() {
                 Identifier::new(a, Span::new((2, 4), (2, 5))).into(),
                 Statement::Empty,
                 None,
+                Span::new((2, 1), (2, 8)),
             ))
             .into(),
         ],
@@ -799,3 +875,121 @@ fn stress_test_operations() {
             .is_ok()
     );
 }
+
+/// Checks that positions start after a stripped byte order mark.
+#[test]
+fn bom_stripped_positions() {
+    fn parse<R: crate::source::ReadChar>(source: Source<'_, R>) -> Span {
+        let interner = &mut Interner::default();
+        let script = Parser::new(source)
+            .parse_script(&Scope::new_global(), interner)
+            .expect("failed to parse");
+        let Some(StatementListItem::Statement(statement)) = script.statements().first() else {
+            panic!("expected a statement");
+        };
+        let Statement::Expression(expression) = statement.as_ref() else {
+            panic!("expected an expression statement");
+        };
+        expression.span()
+    }
+
+    let with_bom = "\u{FEFF}answer;";
+    assert_eq!(
+        parse(Source::from_bytes(with_bom).strip_bom()),
+        Span::new((1, 1), (1, 7))
+    );
+    assert_eq!(
+        parse(Source::from_bytes("answer;").strip_bom()),
+        Span::new((1, 1), (1, 7))
+    );
+
+    // Without stripping, the byte order mark is whitespace.
+    assert_eq!(
+        parse(Source::from_bytes(with_bom)),
+        Span::new((1, 2), (1, 8))
+    );
+}
+
+/// Checks that parsed nodes are not synthetic, unlike nodes built with a synthetic span.
+#[test]
+fn parsed_nodes_are_not_synthetic() {
+    let interner = &mut Interner::default();
+    let script = Parser::new(Source::from_bytes("if (a) b;"))
+        .parse_script(&Scope::new_global(), interner)
+        .expect("failed to parse");
+    let Some(StatementListItem::Statement(statement)) = script.statements().first() else {
+        panic!("expected a statement");
+    };
+    let Statement::If(parsed) = statement.as_ref() else {
+        panic!("expected an if statement");
+    };
+    assert!(!parsed.is_synthetic());
+    assert!(!parsed.cond().is_synthetic());
+
+    let constructed = If::new(
+        parsed.cond().clone(),
+        parsed.body().clone(),
+        None,
+        Span::synthetic(),
+    );
+    assert!(constructed.is_synthetic());
+    assert!(!constructed.cond().is_synthetic());
+}
+
+/// Checks that the globals used by a script are the names it never declares.
+#[test]
+fn used_globals() {
+    let interner = &mut Interner::default();
+    let script = Parser::new(Source::from_bytes(indoc! {"
+        function max(values) {
+            let result = Math.max(...values);
+            return result;
+        }
+        var values = [1, 2];
+        const total = max(values);
+        { function helper() {} }
+        helper();
+        console.log(total, undeclared);
+    "}))
+    .parse_script(&Scope::new_global(), interner)
+    .expect("failed to parse");
+
+    let mut globals = boa_ast::operations::used_globals(&script, interner)
+        .into_iter()
+        .map(|sym| interner.resolve_expect(sym).to_string())
+        .collect::<Vec<_>>();
+    globals.sort();
+    assert_eq!(globals, ["Math", "console", "undeclared"]);
+}
+
+/// Checks that legacy octal literals can be rejected in non-strict code.
+#[test]
+fn no_legacy_octal() {
+    let parse = |source: &str, no_legacy_octal: bool| {
+        let mut parser = Parser::new(Source::from_bytes(source));
+        parser.set_no_legacy_octal(no_legacy_octal);
+        parser.parse_script(&Scope::new_global(), &mut Interner::default())
+    };
+
+    assert!(parse("0777", false).is_ok());
+    assert!(parse("08", false).is_ok());
+
+    let err = parse("0777", true).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "implicit octal literals are not allowed at line 1, col 1"
+    );
+    let err = parse("let x = 08;", true).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "leading 0's are not allowed at line 1, col 9"
+    );
+
+    // Strict code keeps reporting strict mode as the reason.
+    let err = parse("'use strict'; 0777", true).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "implicit octal literals are not allowed in strict mode at line 1, col 15"
+    );
+    assert!(parse("0o777; 0; 0.5; 8", true).is_ok());
+}
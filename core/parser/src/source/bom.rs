@@ -0,0 +1,45 @@
+use super::ReadChar;
+use std::io;
+
+/// The byte order mark, `U+FEFF`.
+const BOM: u32 = 0xFEFF;
+
+/// Input adapter that skips a leading byte order mark (`U+FEFF`) of the wrapped input.
+///
+/// Only a byte order mark at the very start of the input is removed, so all the positions
+/// reported by the lexer and parser are relative to the first character after it. Any other
+/// `U+FEFF` is kept and processed as whitespace, like the specification requires.
+#[derive(Debug)]
+pub struct BomStripped<R> {
+    inner: R,
+    started: bool,
+}
+
+impl<R: ReadChar> BomStripped<R> {
+    /// Creates a new `BomStripped` wrapping the given input.
+    pub const fn new(inner: R) -> Self {
+        Self {
+            inner,
+            started: false,
+        }
+    }
+
+    /// Consumes the adapter, returning the wrapped input.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: ReadChar> ReadChar for BomStripped<R> {
+    fn next_char(&mut self) -> io::Result<Option<u32>> {
+        if self.started {
+            return self.inner.next_char();
+        }
+
+        self.started = true;
+        match self.inner.next_char()? {
+            Some(BOM) => self.inner.next_char(),
+            ch => Ok(ch),
+        }
+    }
+}
@@ -6,9 +6,13 @@ use std::{
     path::Path,
 };
 
+pub use bom::BomStripped;
+pub use source_map::SourceMap;
 pub use utf8::UTF8Input;
 pub use utf16::UTF16Input;
 
+mod bom;
+mod source_map;
 mod utf16;
 mod utf8;
 
@@ -132,6 +136,27 @@ impl<'path, R> Source<'path, R> {
     pub fn path(&self) -> Option<&'path Path> {
         self.path
     }
+
+    /// Skips the byte order mark (`U+FEFF`) at the start of this [`Source`], if present.
+    ///
+    /// Positions reported while parsing the returned [`Source`] start at the first character
+    /// after the byte order mark.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_parser::Source;
+    /// let source = Source::from_bytes("\u{FEFF}let x = 1;").strip_bom();
+    /// ```
+    pub fn strip_bom(self) -> Source<'path, BomStripped<R>>
+    where
+        R: ReadChar,
+    {
+        Source {
+            reader: BomStripped::new(self.reader),
+            path: self.path,
+        }
+    }
 }
 
 /// This trait is used to abstract over the different types of input readers.
@@ -150,6 +175,30 @@ mod tests {
 
     use super::*;
 
+    fn read_all<R: ReadChar>(reader: &mut R) -> String {
+        let mut content = String::new();
+        while let Some(c) = reader.next_char().unwrap() {
+            content.push(char::from_u32(c).unwrap());
+        }
+        content
+    }
+
+    #[test]
+    fn strip_bom() {
+        let mut source = Source::from_bytes("\u{FEFF}'Hello';\u{FEFF}").strip_bom();
+        assert_eq!(read_all(&mut source.reader), "'Hello';\u{FEFF}");
+
+        let mut source = Source::from_bytes("'Hello';").strip_bom();
+        assert_eq!(read_all(&mut source.reader), "'Hello';");
+
+        let utf16: Vec<u16> = "\u{FEFF}\u{FEFF}'Hello';".encode_utf16().collect();
+        let mut source = Source::from_utf16(&utf16).strip_bom();
+        assert_eq!(read_all(&mut source.reader), "\u{FEFF}'Hello';");
+
+        let mut source = Source::from_bytes("").strip_bom();
+        assert_eq!(read_all(&mut source.reader), "");
+    }
+
     #[test]
     fn from_bytes() {
         let mut source = Source::from_bytes("'Hello' + 'World';");
@@ -207,4 +256,44 @@ mod tests {
 
         assert_eq!(content, "'Hello' + 'World';");
     }
+
+    #[test]
+    fn source_map_byte_offsets() {
+        use boa_ast::Position;
+
+        // `é` takes two bytes and `𝒳` four, but both span a single column.
+        let map = SourceMap::new("let é = 1;\r\nlet 𝒳 = é;\u{2028}x\n");
+        assert_eq!(map.line_count(), 4);
+        assert_eq!(map.byte_offset(Position::new(1, 1)), Some(0));
+        assert_eq!(map.byte_offset(Position::new(1, 5)), Some(4));
+        assert_eq!(map.byte_offset(Position::new(1, 7)), Some(7));
+        assert_eq!(map.byte_offset(Position::new(1, 11)), Some(11));
+        assert_eq!(map.byte_offset(Position::new(1, 12)), None);
+        assert_eq!(map.byte_offset(Position::new(2, 1)), Some(13));
+        assert_eq!(map.byte_offset(Position::new(2, 7)), Some(22));
+        assert_eq!(map.byte_offset(Position::new(2, 9)), Some(24));
+        assert_eq!(map.byte_offset(Position::new(3, 1)), Some(30));
+        assert_eq!(map.byte_offset(Position::new(4, 1)), Some(32));
+        assert_eq!(map.byte_offset(Position::new(5, 1)), None);
+    }
+
+    #[test]
+    fn source_map_lexer_positions() {
+        use crate::lexer::Lexer;
+        use boa_ast::Spanned;
+        use boa_interner::Interner;
+
+        let text = "'日本語' + \n  ünïcödé;";
+        let map = SourceMap::new(text);
+        let mut lexer = Lexer::new(Source::from_bytes(text).reader);
+        let interner = &mut Interner::default();
+
+        let mut starts = Vec::new();
+        while let Some(token) = lexer.next(interner).unwrap() {
+            starts.push(map.byte_offset(token.span().start()).unwrap());
+        }
+
+        assert_eq!(starts, [0, 12, 14, 17, 28]);
+        assert_eq!(&text[starts[3]..starts[4]], "ünïcödé");
+    }
 }
@@ -0,0 +1,92 @@
+use boa_ast::Position;
+
+/// Maps line/column [`Position`]s reported by the parser to byte offsets into a UTF-8 source.
+///
+/// The start of every line is computed once when the map is created, so each lookup only needs to
+/// walk the characters of a single line. This is useful for editors and language servers, which
+/// usually address the source by byte offset instead of by line and column.
+///
+/// Line terminators are recognized the same way as the lexer does: `\n`, `\r`, `\r\n`, `U+2028`
+/// and `U+2029`. Columns count Unicode code points, starting at `1`.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_parser::source::SourceMap;
+/// # use boa_ast::Position;
+/// let map = SourceMap::new("let é = 1;\nlet x = é;");
+/// assert_eq!(map.byte_offset(Position::new(1, 9)), Some(9));
+/// assert_eq!(map.byte_offset(Position::new(2, 9)), Some(20));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SourceMap<'src> {
+    source: &'src str,
+    line_starts: Box<[usize]>,
+}
+
+impl<'src> SourceMap<'src> {
+    /// Creates a new `SourceMap` for the given source text.
+    #[must_use]
+    pub fn new(source: &'src str) -> Self {
+        let mut line_starts = vec![0];
+        let mut chars = source.char_indices().peekable();
+        while let Some((index, ch)) = chars.next() {
+            match ch {
+                '\r' => {
+                    if let Some((_, '\n')) = chars.peek() {
+                        chars.next();
+                        line_starts.push(index + 2);
+                    } else {
+                        line_starts.push(index + 1);
+                    }
+                }
+                ch if is_line_terminator(ch) => line_starts.push(index + ch.len_utf8()),
+                _ => {}
+            }
+        }
+
+        Self {
+            source,
+            line_starts: line_starts.into_boxed_slice(),
+        }
+    }
+
+    /// Gets the number of lines in the source.
+    #[must_use]
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Gets the byte offset of the start of the given line, if it exists.
+    #[must_use]
+    pub fn line_start(&self, line: u32) -> Option<usize> {
+        let index = usize::try_from(line).ok()?.checked_sub(1)?;
+        self.line_starts.get(index).copied()
+    }
+
+    /// Gets the byte offset of the given position into the source.
+    ///
+    /// A position just past the last character of a line maps to the offset of its line
+    /// terminator (or to the length of the source on the last line). Returns `None` if the
+    /// position lies outside of the source.
+    #[must_use]
+    pub fn byte_offset<P: Into<Position>>(&self, position: P) -> Option<usize> {
+        let position = position.into();
+        let line = position.line_number();
+        let start = self.line_start(line)?;
+        let text = &self.source[start..];
+        let text = &text[..text.find(is_line_terminator).unwrap_or(text.len())];
+
+        let column = usize::try_from(position.column_number() - 1).ok()?;
+        text.char_indices()
+            .map(|(index, _)| index)
+            .chain(Some(text.len()))
+            .nth(column)
+            .map(|offset| start + offset)
+    }
+}
+
+/// Checks if the character is a line terminator, as defined by the specification.
+const fn is_line_terminator(ch: char) -> bool {
+    matches!(ch, '\n' | '\r' | '\u{2028}' | '\u{2029}')
+}